@@ -0,0 +1,53 @@
+use std::{
+  env,
+  fs,
+  path::{
+    Path,
+    PathBuf
+  }
+};
+
+fn main() {
+  let manifest_directory = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+  let output_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded_assets.rs");
+  let asset_root = manifest_directory.join("res");
+
+  println!("cargo:rerun-if-changed=build.rs");
+
+  let mut asset_paths = Vec::new();
+  if env::var_os("CARGO_FEATURE_EMBEDDED_ASSETS").is_some() {
+    println!("cargo:rerun-if-changed={}", asset_root.display());
+    collect_files(&asset_root, &mut asset_paths);
+    asset_paths.sort();
+  }
+
+  let mut table = String::from("pub const EMBEDDED_ASSETS: &[(&str, &[u8])] = &[\n");
+
+  for asset_path in asset_paths {
+    let asset_id: Vec<String> = asset_path
+      .strip_prefix(&asset_root)
+      .unwrap()
+      .components()
+      .map(| component | component.as_os_str().to_string_lossy().to_string())
+      .collect();
+
+    println!("cargo:rerun-if-changed={}", asset_path.display());
+    table.push_str(&format!("  ({:?}, include_bytes!({:?})),\n", asset_id.join("/"), asset_path.display().to_string()));
+  }
+
+  table.push_str("];\n");
+  fs::write(output_path, table).unwrap();
+}
+
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) {
+  for entry in fs::read_dir(directory).unwrap().flatten() {
+    let path = entry.path();
+
+    if path.is_dir() {
+      println!("cargo:rerun-if-changed={}", path.display());
+      collect_files(&path, files);
+    } else {
+      files.push(path);
+    }
+  }
+}
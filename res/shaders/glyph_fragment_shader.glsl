@@ -5,7 +5,8 @@ in vec2 textureCoordinates;
 out vec4 color;
 
 uniform sampler2D textureSampler;
+uniform vec4 tint;
 
 void main() {
-  color = texture(textureSampler, textureCoordinates);
+  color = texture(textureSampler, textureCoordinates) * tint;
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+#version 330 core
+
+in vec2 textureCoordinates;
+in vec4 particleColor;
+
+out vec4 color;
+
+uniform sampler2D textureSampler;
+
+void main() {
+  color = texture(textureSampler, textureCoordinates) * particleColor;
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+#version 330 core
+
+layout(location = 0) in vec2 position;
+layout(location = 1) in vec2 texturePosition;
+layout(location = 2) in vec4 vertexColor;
+
+uniform mat4 model;
+uniform mat4 view;
+uniform mat4 projection;
+
+out vec2 textureCoordinates;
+out vec4 particleColor;
+
+void main() {
+  gl_Position = projection * view * model * vec4(position, 0.0, 1.0);
+  textureCoordinates = texturePosition;
+  particleColor = vertexColor;
+}
\ No newline at end of file
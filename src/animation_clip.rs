@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use crate::{
+  AnimationFrame,
+  AnimationMode
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct AnimationClip {
+  pub name: String,
+  pub mode: AnimationMode,
+  pub next: Option<String>,
+  pub frames: Vec<AnimationFrame>
+}
+
+impl AnimationClip {
+  pub fn new(name: &str, mode: AnimationMode, next: Option<String>) -> Self {
+    Self {
+      name: name.to_string(),
+      mode,
+      next,
+      frames: Vec::new()
+    }
+  }
+
+  pub fn total_duration(&self) -> Duration {
+    self.sequence().iter().map(| index | self.frames[*index].duration).sum()
+  }
+
+  pub fn is_finished(&self, played_duration: Duration) -> bool {
+    self.mode == AnimationMode::Once && played_duration >= self.total_duration()
+  }
+
+  pub fn frame_at(&self, played_duration: Duration) -> &AnimationFrame {
+    let sequence = self.sequence();
+    let total_duration = self.total_duration();
+
+    if total_duration.is_zero() || self.is_finished(played_duration) {
+      return &self.frames[sequence[sequence.len() - 1]];
+    }
+
+    let mut remaining = Duration::from_nanos((played_duration.as_nanos() % total_duration.as_nanos()) as u64);
+    for index in &sequence {
+      let frame = &self.frames[*index];
+      if remaining < frame.duration { return frame }
+      remaining -= frame.duration;
+    }
+
+    &self.frames[sequence[sequence.len() - 1]]
+  }
+
+  fn sequence(&self) -> Vec<usize> {
+    let mut sequence: Vec<usize> = (0..self.frames.len()).collect();
+
+    if self.mode == AnimationMode::PingPong && self.frames.len() > 2 {
+      sequence.extend((1..self.frames.len() - 1).rev());
+    }
+
+    sequence
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::{
+    AnimationFrame,
+    AnimationMode
+  };
+
+  use super::AnimationClip;
+
+  fn clip(mode: AnimationMode) -> AnimationClip {
+    let mut clip = AnimationClip::new("test", mode, None);
+
+    for (index, milliseconds) in [100, 200, 100].iter().enumerate() {
+      clip.frames.push(AnimationFrame::new(index as u32 * 32, 0, 32, 32, Duration::from_millis(*milliseconds)));
+    }
+
+    clip
+  }
+
+  #[test]
+  fn uses_per_frame_durations() {
+    let clip = clip(AnimationMode::Loop);
+
+    assert_eq!(clip.frame_at(Duration::from_millis(0)).x, 0);
+    assert_eq!(clip.frame_at(Duration::from_millis(99)).x, 0);
+    assert_eq!(clip.frame_at(Duration::from_millis(100)).x, 32);
+    assert_eq!(clip.frame_at(Duration::from_millis(299)).x, 32);
+    assert_eq!(clip.frame_at(Duration::from_millis(300)).x, 64);
+  }
+
+  #[test]
+  fn loops() {
+    let clip = clip(AnimationMode::Loop);
+
+    assert_eq!(clip.total_duration(), Duration::from_millis(400));
+    assert_eq!(clip.frame_at(Duration::from_millis(450)).x, 0);
+    assert_eq!(clip.is_finished(Duration::from_secs(10)), false);
+  }
+
+  #[test]
+  fn plays_once_and_holds_the_last_frame() {
+    let clip = clip(AnimationMode::Once);
+
+    assert_eq!(clip.is_finished(Duration::from_millis(399)), false);
+    assert_eq!(clip.is_finished(Duration::from_millis(400)), true);
+    assert_eq!(clip.frame_at(Duration::from_millis(450)).x, 64);
+  }
+
+  #[test]
+  fn ping_pongs() {
+    let clip = clip(AnimationMode::PingPong);
+
+    assert_eq!(clip.total_duration(), Duration::from_millis(600));
+    assert_eq!(clip.frame_at(Duration::from_millis(350)).x, 64);
+    assert_eq!(clip.frame_at(Duration::from_millis(450)).x, 32);
+    assert_eq!(clip.frame_at(Duration::from_millis(650)).x, 0);
+  }
+}
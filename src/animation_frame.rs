@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AnimationFrame {
+  pub x: u32,
+  pub y: u32,
+  pub width: u32,
+  pub height: u32,
+  pub duration: Duration
+}
+
+impl AnimationFrame {
+  pub fn new(x: u32, y: u32, width: u32, height: u32, duration: Duration) -> Self {
+    Self {
+      x,
+      y,
+      width,
+      height,
+      duration
+    }
+  }
+}
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnimationMode {
+  Loop,
+  Once,
+  PingPong
+}
@@ -0,0 +1,76 @@
+use crate::AnimationClip;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct AnimationSheet {
+  pub texture: String,
+  pub clips: Vec<AnimationClip>
+}
+
+impl AnimationSheet {
+  pub fn new(texture: &str) -> Self {
+    Self {
+      texture: texture.to_string(),
+      clips: Vec::new()
+    }
+  }
+
+  pub fn clip_index(&self, name: &str) -> Option<usize> {
+    self.clips.iter().position(| clip | clip.name == name)
+  }
+
+  pub fn check_bounds(&self, texture_width: u32, texture_height: u32) -> Result<(), String> {
+    for clip in &self.clips {
+      for frame in &clip.frames {
+        if frame.x as u64 + frame.width as u64 > texture_width as u64 || frame.y as u64 + frame.height as u64 > texture_height as u64 {
+          return Err(format!("Frame {},{} {}x{} of clip {} is outside the {}x{} texture", frame.x, frame.y, frame.width, frame.height, clip.name, texture_width, texture_height));
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn require_clips(&self, names: &[&str]) -> Result<(), String> {
+    match names.iter().find(| name | self.clip_index(name).is_none()) {
+      Some(name) => Err(format!("Missing clip: {}", name)),
+      None => Ok(())
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::{
+    AnimationClip,
+    AnimationFrame,
+    AnimationMode
+  };
+
+  use super::AnimationSheet;
+
+  #[test]
+  fn requires_the_named_clips() {
+    let mut sheet = AnimationSheet::new("textures/drake.png");
+    sheet.clips.push(AnimationClip::new("idle", AnimationMode::Loop, None));
+    sheet.clips.push(AnimationClip::new("walk", AnimationMode::Loop, None));
+
+    assert_eq!(sheet.require_clips(&["idle", "walk"]), Ok(()));
+    assert_eq!(sheet.require_clips(&["idle", "hurt"]), Err("Missing clip: hurt".to_string()));
+  }
+
+  #[test]
+  fn keeps_frames_inside_the_texture() {
+    let mut clip = AnimationClip::new("idle", AnimationMode::Loop, None);
+    clip.frames.push(AnimationFrame::new(0, 32, 32, 32, Duration::from_millis(100)));
+    clip.frames.push(AnimationFrame::new(32, 32, 32, 32, Duration::from_millis(100)));
+
+    let mut sheet = AnimationSheet::new("textures/drake.png");
+    sheet.clips.push(clip);
+
+    assert_eq!(sheet.check_bounds(64, 64), Ok(()));
+    assert_eq!(sheet.check_bounds(48, 64), Err("Frame 32,32 32x32 of clip idle is outside the 48x64 texture".to_string()));
+    assert_eq!(sheet.check_bounds(64, 48), Err("Frame 0,32 32x32 of clip idle is outside the 64x48 texture".to_string()));
+  }
+}
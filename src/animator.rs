@@ -0,0 +1,161 @@
+use std::{
+  rc::Rc,
+  time::Duration
+};
+
+use crate::{
+  AnimationSheet,
+  AnimationClip,
+  AnimationFrame,
+  Sprite
+};
+
+pub struct Animator {
+  sheet: Rc<AnimationSheet>,
+  clip_index: usize,
+  played_duration: Duration
+}
+
+impl Animator {
+  pub fn new(sheet: Rc<AnimationSheet>, state: &str) -> Result<Self, String> {
+    let clip_index = match sheet.clip_index(state) {
+      Some(clip_index) => clip_index,
+      None => return Err(format!("Unknown animation state: {}", state))
+    };
+
+    Ok(
+      Self {
+        sheet,
+        clip_index,
+        played_duration: Duration::ZERO
+      }
+    )
+  }
+
+  pub fn state(&self) -> &str {
+    &self.clip().name
+  }
+
+  pub fn play(&mut self, state: &str) -> Result<(), String> {
+    if self.state() == state { return Ok(()) }
+
+    match self.sheet.clip_index(state) {
+      Some(clip_index) => {
+        self.clip_index = clip_index;
+        self.played_duration = Duration::ZERO;
+        Ok(())
+      },
+
+      None => Err(format!("Unknown animation state: {}", state))
+    }
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) {
+    self.played_duration += *deltatime;
+
+    let clip = self.clip();
+    if !clip.is_finished(self.played_duration) { return }
+
+    if let Some(next_index) = clip.next.as_ref().and_then(| next | self.sheet.clip_index(next)) {
+      self.clip_index = next_index;
+      self.played_duration = Duration::ZERO;
+    }
+  }
+
+  pub fn frame(&self) -> &AnimationFrame {
+    self.clip().frame_at(self.played_duration)
+  }
+
+  pub fn apply(&self, sprite: &mut Sprite) {
+    sprite.set_frame(self.frame());
+  }
+
+  fn clip(&self) -> &AnimationClip {
+    &self.sheet.clips[self.clip_index]
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::{
+    rc::Rc,
+    time::Duration
+  };
+
+  use crate::deserialize_animation_sheet;
+
+  use super::Animator;
+
+  fn animator() -> Animator {
+    let sheet_string = "texture=a.png\nclip=idle,loop\nstrip=0,0,32,32,2,100\nclip=walk,loop\nframe=64,0,32,32,50\nclip=hurt,once,idle\nframe=96,0,32,32,300\n".to_string();
+
+    match deserialize_animation_sheet(sheet_string).and_then(| sheet | Animator::new(Rc::new(sheet), "idle")) {
+      Ok(animator) => animator,
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn animates_the_current_state() {
+    let mut animator = animator();
+    assert_eq!(animator.frame().x, 0);
+
+    animator.update(&Duration::from_millis(150));
+    assert_eq!(animator.state(), "idle");
+    assert_eq!(animator.frame().x, 32);
+  }
+
+  #[test]
+  fn switches_states() {
+    let mut animator = animator();
+    animator.update(&Duration::from_millis(150));
+
+    match animator.play("walk") {
+      Ok(()) => {
+        assert_eq!(animator.state(), "walk");
+        assert_eq!(animator.frame().x, 64);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn replaying_the_current_state_keeps_its_time() {
+    let mut animator = animator();
+    animator.update(&Duration::from_millis(150));
+
+    match animator.play("idle") {
+      Ok(()) => assert_eq!(animator.frame().x, 32),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn follows_the_next_state_when_finished() {
+    let mut animator = animator();
+
+    match animator.play("hurt") {
+      Ok(()) => {
+        animator.update(&Duration::from_millis(299));
+        assert_eq!(animator.state(), "hurt");
+
+        animator.update(&Duration::from_millis(1));
+        assert_eq!(animator.state(), "idle");
+        assert_eq!(animator.frame().x, 0);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_state() {
+    let mut animator = animator();
+
+    match animator.play("fly") {
+      Ok(()) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Unknown animation state: fly")
+    }
+  }
+}
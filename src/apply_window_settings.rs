@@ -0,0 +1,23 @@
+use sdl2::video::{
+  FullscreenType,
+  Window
+};
+
+use crate::{
+  Settings,
+  WindowMode
+};
+
+pub fn apply_window_settings(window: &mut Window, settings: &Settings) -> Result<(), String> {
+  window
+    .set_size(settings.resolution.width() as u32, settings.resolution.height() as u32)
+    .map_err(| error | error.to_string())?;
+
+  match settings.window_mode {
+    WindowMode::Fullscreen => window.set_fullscreen(FullscreenType::True)?,
+    WindowMode::Borderless => window.set_fullscreen(FullscreenType::Desktop)?,
+    WindowMode::Windowed => window.set_fullscreen(FullscreenType::Off)?
+  }
+
+  Ok(())
+}
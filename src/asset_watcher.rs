@@ -0,0 +1,115 @@
+use std::{
+  collections::HashMap,
+  path::{
+    Path,
+    PathBuf
+  },
+  time::{
+    Duration,
+    SystemTime
+  }
+};
+
+pub struct AssetWatcher {
+  root: PathBuf,
+  modified_times: HashMap<String, SystemTime>,
+  poll_interval: Duration,
+  time_until_poll: Duration
+}
+
+impl AssetWatcher {
+  pub fn new(root: &Path, poll_interval: Duration) -> Self {
+    Self {
+      root: root.to_path_buf(),
+      modified_times: scan_modified_times(root),
+      poll_interval,
+      time_until_poll: poll_interval
+    }
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) -> Vec<String> {
+    self.time_until_poll = self.time_until_poll.saturating_sub(*deltatime);
+    if !self.time_until_poll.is_zero() { return Vec::new() }
+
+    self.time_until_poll = self.poll_interval;
+    self.poll()
+  }
+
+  pub fn poll(&mut self) -> Vec<String> {
+    let modified_times = scan_modified_times(&self.root);
+
+    let mut changed_assets: Vec<String> = modified_times
+      .iter()
+      .filter(| (asset_id, modified_time) | self.modified_times.get(*asset_id) != Some(*modified_time))
+      .map(| (asset_id, _) | asset_id.clone())
+      .collect();
+
+    changed_assets.sort();
+    self.modified_times = modified_times;
+
+    changed_assets
+  }
+}
+
+fn scan_modified_times(root: &Path) -> HashMap<String, SystemTime> {
+  let mut modified_times = HashMap::new();
+  let mut directories = vec![root.to_path_buf()];
+
+  while let Some(directory) = directories.pop() {
+    let Ok(entries) = std::fs::read_dir(&directory) else { continue };
+
+    for entry in entries.flatten() {
+      let path = entry.path();
+      let Ok(metadata) = entry.metadata() else { continue };
+
+      if metadata.is_dir() {
+        directories.push(path);
+      } else if let (Ok(modified_time), Ok(relative_path)) = (metadata.modified(), path.strip_prefix(root)) {
+        let asset_id: Vec<String> = relative_path.components().map(| component | component.as_os_str().to_string_lossy().to_string()).collect();
+        modified_times.insert(asset_id.join("/"), modified_time);
+      }
+    }
+  }
+
+  modified_times
+}
+
+#[cfg(test)]
+mod testing {
+  use std::{
+    fs::File,
+    path::Path,
+    time::{
+      Duration,
+      SystemTime
+    }
+  };
+
+  use super::AssetWatcher;
+
+  #[test]
+  fn detects_changed_and_added_assets() {
+    let root = Path::new("./detects_changed_and_added_assets_test_assets");
+
+    if let Err(error) = std::fs::create_dir_all(root.join("shaders")) { panic!("Unexpected error: {}", error) }
+    if let Err(error) = std::fs::write(root.join("grass.png"), "grass") { panic!("Unexpected error: {}", error) }
+    if let Err(error) = std::fs::write(root.join("shaders/quad.glsl"), "quad") { panic!("Unexpected error: {}", error) }
+
+    let mut asset_watcher = AssetWatcher::new(root, Duration::from_millis(500));
+    assert_eq!(asset_watcher.poll(), Vec::<String>::new());
+
+    match File::options().write(true).open(root.join("shaders/quad.glsl")) {
+      Ok(file) => if let Err(error) = file.set_modified(SystemTime::now() + Duration::from_secs(10)) { panic!("Unexpected error: {}", error) },
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+
+    assert_eq!(asset_watcher.update(&Duration::from_millis(400)), Vec::<String>::new());
+    assert_eq!(asset_watcher.update(&Duration::from_millis(100)), vec!["shaders/quad.glsl".to_string()]);
+
+    if let Err(error) = std::fs::write(root.join("water.png"), "water") { panic!("Unexpected error: {}", error) }
+    assert_eq!(asset_watcher.poll(), vec!["water.png".to_string()]);
+    assert_eq!(asset_watcher.poll(), Vec::<String>::new());
+
+    if let Err(error) = std::fs::remove_dir_all(root) { panic!("Unexpected error: {}", error) }
+  }
+}
@@ -0,0 +1,44 @@
+use crate::UvRect;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AtlasPlacement {
+  pub page: usize,
+  pub x: u32,
+  pub y: u32,
+  pub width: u32,
+  pub height: u32
+}
+
+impl AtlasPlacement {
+  pub fn uv_rect(&self, page_width: u32, page_height: u32) -> UvRect {
+    let page_width = page_width as f32;
+    let page_height = page_height as f32;
+
+    UvRect {
+      left: self.x as f32 / page_width,
+      top: (page_height - (self.y + self.height) as f32) / page_height,
+      right: (self.x + self.width) as f32 / page_width,
+      bottom: (page_height - self.y as f32) / page_height
+    }
+  }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct AtlasLayout {
+  pub placements: Vec<AtlasPlacement>,
+  pub page_sizes: Vec<(u32, u32)>
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::UvRect;
+
+  use super::AtlasPlacement;
+
+  #[test]
+  fn flips_rows_to_match_uploaded_textures() {
+    let placement = AtlasPlacement { page: 0, x: 32, y: 0, width: 32, height: 16 };
+
+    assert_eq!(placement.uv_rect(64, 64), UvRect { left: 0.5, top: 0.75, right: 1.0, bottom: 1.0 });
+  }
+}
@@ -0,0 +1,26 @@
+pub const ATLAS_TEXTURES: [&str; 24] = [
+  "textures/grass.png",
+  "textures/hints/grass_1.png",
+  "textures/hints/grass_2.png",
+  "textures/hints/grass_3.png",
+  "textures/hints/grass_4.png",
+  "textures/hints/grass_5.png",
+  "textures/hints/grass_6.png",
+  "textures/hints/grass_7.png",
+  "textures/hints/grass_8.png",
+  "textures/shadows/shadow_0.png",
+  "textures/shadows/shadow_1.png",
+  "textures/shadows/shadow_2.png",
+  "textures/shadows/shadow_3.png",
+  "textures/shadows/shadow_4.png",
+  "textures/shadows/shadow_5.png",
+  "textures/emblem_0.png",
+  "textures/emblem_1.png",
+  "textures/drake.png",
+  "textures/snake.png",
+  "textures/nest.png",
+  "textures/stars.png",
+  "textures/water.png",
+  "textures/highlight.png",
+  "textures/particle.png"
+];
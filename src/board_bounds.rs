@@ -0,0 +1,32 @@
+use crate::{
+  Coordinate,
+  MapSize,
+  Vector2,
+  tile_location
+};
+
+pub fn board_bounds(map_size: &MapSize, tile_width: usize, tile_height: usize) -> (Vector2, Vector2) {
+  let first = tile_location(&Coordinate::from(0, 0, map_size), map_size, tile_width, tile_height);
+  let last = tile_location(&Coordinate::from(map_size.width() - 1, map_size.height() - 1, map_size), map_size, tile_width, tile_height);
+
+  (
+    Vector2 { x: first.x - tile_width as f32 / 2.0, y: first.y - tile_height as f32 / 2.0 },
+    Vector2 { x: last.x + tile_width as f32 / 2.0, y: last.y + tile_height as f32 / 2.0 }
+  )
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::MapSize;
+
+  use super::board_bounds;
+
+  #[test]
+  fn spans_every_tile() {
+    let map_size = MapSize::from(8, 16).unwrap();
+    let (board_min, board_max) = board_bounds(&map_size, 32, 32);
+
+    assert_eq!((board_min.x, board_min.y), (-144.0, -272.0));
+    assert_eq!((board_max.x, board_max.y), (112.0, 240.0));
+  }
+}
@@ -0,0 +1,60 @@
+use crate::{
+  Resolution,
+  Viewport
+};
+
+pub fn calculate_viewport(drawable_width: u32, drawable_height: u32, virtual_resolution: Option<Resolution>) -> Viewport {
+  let drawable_width = drawable_width.max(1) as f32;
+  let drawable_height = drawable_height.max(1) as f32;
+
+  let (width, height) = match virtual_resolution {
+    Some(resolution) if resolution.width() > 0 && resolution.height() > 0 => {
+      let scale = f32::min(drawable_width / resolution.width() as f32, drawable_height / resolution.height() as f32);
+      ((resolution.width() as f32 * scale).round(), (resolution.height() as f32 * scale).round())
+    },
+
+    _ => (drawable_width, drawable_height)
+  };
+
+  Viewport::new(
+    ((drawable_width - width) / 2.0).floor() as i32,
+    ((drawable_height - height) / 2.0).floor() as i32,
+    width as i32,
+    height as i32
+  )
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Resolution,
+    Viewport
+  };
+
+  use super::calculate_viewport;
+
+  #[test]
+  fn fills_the_drawable_without_a_virtual_resolution() {
+    assert_eq!(calculate_viewport(1280, 720, None), Viewport::new(0, 0, 1280, 720));
+  }
+
+  #[test]
+  fn matches_an_equal_aspect_ratio() {
+    assert_eq!(calculate_viewport(3200, 1800, Some(Resolution::new(1600, 900))), Viewport::new(0, 0, 3200, 1800));
+  }
+
+  #[test]
+  fn pillarboxes_a_wider_drawable() {
+    assert_eq!(calculate_viewport(2000, 900, Some(Resolution::new(1600, 900))), Viewport::new(200, 0, 1600, 900));
+  }
+
+  #[test]
+  fn letterboxes_a_taller_drawable() {
+    assert_eq!(calculate_viewport(1600, 1200, Some(Resolution::new(1600, 900))), Viewport::new(0, 150, 1600, 900));
+  }
+
+  #[test]
+  fn survives_a_minimized_window() {
+    assert_eq!(calculate_viewport(0, 0, Some(Resolution::new(1600, 900))), Viewport::new(0, 0, 1, 1));
+  }
+}
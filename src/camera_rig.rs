@@ -0,0 +1,198 @@
+use std::time::Duration;
+
+use crate::Vector2;
+
+const ZOOM_LEVELS: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 3.0];
+const DEFAULT_ZOOM_INDEX: usize = 2;
+const DEAD_ZONE: f32 = 48.0;
+const FOLLOW_RATE: f32 = 8.0;
+
+pub struct CameraRig {
+  position: Vector2,
+  zoom_index: usize,
+  is_free_look: bool,
+  needs_snap: bool
+}
+
+impl CameraRig {
+  pub fn new() -> Self {
+    Self {
+      position: Vector2::new(),
+      zoom_index: DEFAULT_ZOOM_INDEX,
+      is_free_look: false,
+      needs_snap: true
+    }
+  }
+
+  pub fn zoom(&self) -> f32 {
+    ZOOM_LEVELS[self.zoom_index]
+  }
+
+  pub fn zoom_in(&mut self) {
+    self.zoom_index = (self.zoom_index + 1).min(ZOOM_LEVELS.len() - 1);
+  }
+
+  pub fn zoom_out(&mut self) {
+    self.zoom_index = self.zoom_index.saturating_sub(1);
+  }
+
+  pub fn pan(&mut self, delta: Vector2) {
+    self.is_free_look = true;
+    self.position.x += delta.x;
+    self.position.y += delta.y;
+  }
+
+  pub fn jump_to(&mut self, position: Vector2) {
+    self.is_free_look = true;
+    self.position = position;
+  }
+
+  pub fn recenter(&mut self) {
+    self.is_free_look = false;
+  }
+
+  pub fn reset(&mut self) {
+    self.is_free_look = false;
+    self.needs_snap = true;
+  }
+
+  pub fn update(&mut self, target: Vector2, half_view_size: Vector2, board_min: Vector2, board_max: Vector2, deltatime: &Duration) -> Vector2 {
+    if self.needs_snap {
+      self.position = target;
+      self.needs_snap = false;
+    } else if !self.is_free_look {
+      let blend = 1.0 - (-FOLLOW_RATE * deltatime.as_secs_f32()).exp();
+      self.position.x += (dead_zone_target(self.position.x, target.x) - self.position.x) * blend;
+      self.position.y += (dead_zone_target(self.position.y, target.y) - self.position.y) * blend;
+    }
+
+    self.position.x = clamp_to_board(self.position.x, half_view_size.x, board_min.x, board_max.x);
+    self.position.y = clamp_to_board(self.position.y, half_view_size.y, board_min.y, board_max.y);
+
+    self.position
+  }
+}
+
+fn dead_zone_target(position: f32, target: f32) -> f32 {
+  let offset = target - position;
+
+  if offset.abs() <= DEAD_ZONE { position } else { target - DEAD_ZONE * offset.signum() }
+}
+
+fn clamp_to_board(position: f32, half_view_size: f32, board_min: f32, board_max: f32) -> f32 {
+  if board_max - board_min <= half_view_size * 2.0 { return (board_min + board_max) / 2.0 }
+
+  position.clamp(board_min + half_view_size, board_max - half_view_size)
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::Vector2;
+
+  use super::CameraRig;
+
+  fn board() -> (Vector2, Vector2) {
+    (Vector2 { x: -1000.0, y: -1000.0 }, Vector2 { x: 1000.0, y: 1000.0 })
+  }
+
+  fn half_view() -> Vector2 {
+    Vector2 { x: 200.0, y: 100.0 }
+  }
+
+  #[test]
+  fn snaps_to_the_target_on_the_first_update() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+
+    let position = camera_rig.update(Vector2 { x: 300.0, y: -200.0 }, half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    assert_eq!((position.x, position.y), (300.0, -200.0));
+  }
+
+  #[test]
+  fn ignores_movement_inside_the_dead_zone() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+    camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    let position = camera_rig.update(Vector2 { x: 32.0, y: -32.0 }, half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    assert_eq!((position.x, position.y), (0.0, 0.0));
+  }
+
+  #[test]
+  fn eases_towards_targets_outside_the_dead_zone() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+    camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    let first = camera_rig.update(Vector2 { x: 148.0, y: 0.0 }, half_view(), board_min, board_max, &Duration::from_millis(16));
+    assert!(first.x > 0.0 && first.x < 100.0);
+
+    let settled = camera_rig.update(Vector2 { x: 148.0, y: 0.0 }, half_view(), board_min, board_max, &Duration::from_secs(10));
+    assert!((settled.x - 100.0).abs() < 0.01);
+  }
+
+  #[test]
+  fn clamps_to_the_board_edges() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+
+    let position = camera_rig.update(Vector2 { x: -990.0, y: 990.0 }, half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    assert_eq!((position.x, position.y), (-800.0, 900.0));
+  }
+
+  #[test]
+  fn centers_boards_narrower_than_the_view() {
+    let mut camera_rig = CameraRig::new();
+
+    let position = camera_rig.update(Vector2 { x: 40.0, y: 40.0 }, half_view(), Vector2 { x: -144.0, y: -144.0 }, Vector2 { x: 112.0, y: 112.0 }, &Duration::from_millis(16));
+
+    assert_eq!((position.x, position.y), (-16.0, 12.0));
+  }
+
+  #[test]
+  fn free_look_pans_until_recentered() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+    camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    camera_rig.pan(Vector2 { x: 250.0, y: 0.0 });
+    let panned = camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_secs(1));
+    assert_eq!(camera_rig.is_free_look, true);
+    assert_eq!(panned.x, 250.0);
+
+    camera_rig.recenter();
+    let followed = camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_secs(10));
+    assert!((followed.x - 48.0).abs() < 0.01);
+  }
+
+  #[test]
+  fn jumps_and_holds_until_recentered() {
+    let mut camera_rig = CameraRig::new();
+    let (board_min, board_max) = board();
+    camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_millis(16));
+
+    camera_rig.jump_to(Vector2 { x: -500.0, y: 600.0 });
+    let jumped = camera_rig.update(Vector2::new(), half_view(), board_min, board_max, &Duration::from_secs(1));
+
+    assert_eq!((jumped.x, jumped.y), (-500.0, 600.0));
+  }
+
+  #[test]
+  fn steps_through_zoom_levels() {
+    let mut camera_rig = CameraRig::new();
+    assert_eq!(camera_rig.zoom(), 1.0);
+
+    camera_rig.zoom_in();
+    camera_rig.zoom_in();
+    camera_rig.zoom_in();
+    assert_eq!(camera_rig.zoom(), 3.0);
+
+    for _ in 0..10 { camera_rig.zoom_out() }
+    assert_eq!(camera_rig.zoom(), 0.25);
+  }
+}
@@ -0,0 +1,7 @@
+use crate::Input;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Command {
+  Input(Input),
+  ToggleMark(usize)
+}
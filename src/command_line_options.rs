@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use crate::{
+  Frontend,
+  LogFilter
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CommandLineOptions {
+  pub seed: Option<u64>,
+  pub size: Option<(usize, usize)>,
+  pub num_snakes: Option<usize>,
+  pub load: Option<String>,
+  pub windowed: bool,
+  pub resolution: Option<(usize, usize)>,
+  pub data_directory: PathBuf,
+  pub asset_directory: PathBuf,
+  pub frontend: Frontend,
+  pub log_filter: LogFilter,
+  pub log_to_file: bool,
+  pub debug: bool,
+  pub dev_mode: bool,
+  pub show_help: bool
+}
+
+impl CommandLineOptions {
+  pub fn new() -> Self {
+    Self {
+      seed: None,
+      size: None,
+      num_snakes: None,
+      load: None,
+      windowed: false,
+      resolution: None,
+      data_directory: PathBuf::from("."),
+      asset_directory: PathBuf::from("./res"),
+      frontend: Frontend::Sdl,
+      log_filter: LogFilter::new(),
+      log_to_file: false,
+      debug: false,
+      dev_mode: false,
+      show_help: false
+    }
+  }
+
+  pub fn requests_game(&self) -> bool {
+    self.seed.is_some() || self.size.is_some() || self.num_snakes.is_some() || self.load.is_some()
+  }
+}
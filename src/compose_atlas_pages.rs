@@ -0,0 +1,72 @@
+use image::RgbaImage;
+
+use crate::{
+  AtlasLayout,
+  extrude_image
+};
+
+pub fn compose_atlas_pages(images: &[RgbaImage], layout: &AtlasLayout, padding: u32) -> Vec<RgbaImage> {
+  let mut pages: Vec<RgbaImage> = layout.page_sizes
+    .iter()
+    .map(| (width, height) | RgbaImage::new(*width, *height))
+    .collect();
+
+  for (image, placement) in images.iter().zip(layout.placements.iter()) {
+    let left = placement.x as i64 - padding as i64;
+    let top = placement.y as i64 - padding as i64;
+
+    image::imageops::replace(&mut pages[placement.page], &extrude_image(image, padding), left, top);
+  }
+
+  pages
+}
+
+#[cfg(test)]
+mod testing {
+  use image::{
+    Rgba,
+    RgbaImage
+  };
+
+  use crate::pack_atlas;
+
+  use super::compose_atlas_pages;
+
+  #[test]
+  fn copies_images_into_their_placements() {
+    let red = RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255]));
+    let blue = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 255, 255]));
+
+    match pack_atlas(&[(2, 2), (2, 2)], 4, 0) {
+      Ok(layout) => {
+        let pages = compose_atlas_pages(&[red, blue], &layout, 0);
+
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].dimensions(), (4, 2));
+        assert_eq!(*pages[0].get_pixel(1, 1), Rgba([255, 0, 0, 255]));
+        assert_eq!(*pages[0].get_pixel(2, 0), Rgba([0, 0, 255, 255]));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn extrudes_edges_into_the_padding() {
+    let mut image = RgbaImage::from_pixel(2, 1, Rgba([0, 255, 0, 255]));
+    image.put_pixel(1, 0, Rgba([255, 255, 0, 255]));
+
+    match pack_atlas(&[(2, 1)], 8, 1) {
+      Ok(layout) => {
+        let pages = compose_atlas_pages(&[image], &layout, 1);
+
+        assert_eq!(pages[0].dimensions(), (4, 3));
+        assert_eq!(*pages[0].get_pixel(0, 0), Rgba([0, 255, 0, 255]));
+        assert_eq!(*pages[0].get_pixel(3, 2), Rgba([255, 255, 0, 255]));
+        assert_eq!(*pages[0].get_pixel(2, 1), Rgba([255, 255, 0, 255]));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use sdl2::controller::{
+  Axis,
+  Button
+};
+
+use crate::{
+  Input,
+  InputRepeat
+};
+
+const STICK_DEAD_ZONE: i16 = 8000;
+
+pub struct ControllerInput {
+  repeat_delay: Duration,
+  repeat_interval: Duration,
+  controllers: Vec<(u32, ControllerState)>
+}
+
+struct ControllerState {
+  stick_x: i16,
+  stick_y: i16,
+  stick_input: Option<Input>,
+  input_repeat: InputRepeat
+}
+
+impl ControllerInput {
+  pub fn new(repeat_delay: Duration, repeat_interval: Duration) -> Self {
+    Self {
+      repeat_delay,
+      repeat_interval,
+      controllers: Vec::new()
+    }
+  }
+
+  pub fn set_repeat_timing(&mut self, repeat_delay: Duration, repeat_interval: Duration) {
+    self.repeat_delay = repeat_delay;
+    self.repeat_interval = repeat_interval;
+
+    for (_, controller) in self.controllers.iter_mut() {
+      controller.input_repeat.set_timing(repeat_delay, repeat_interval);
+    }
+  }
+
+  pub fn button_down(&mut self, which: u32, button: Button) -> Option<Input> {
+    let input = button_input(button)?;
+    if is_directional(input) { self.controller(which).input_repeat.press(input) }
+
+    Some(input)
+  }
+
+  pub fn button_up(&mut self, which: u32, button: Button) {
+    if let Some(input) = button_input(button) { self.controller(which).input_repeat.release(input) }
+  }
+
+  pub fn axis_motion(&mut self, which: u32, axis: Axis, value: i16) -> Option<Input> {
+    let controller = self.controller(which);
+
+    match axis {
+      Axis::LeftX => controller.stick_x = value,
+      Axis::LeftY => controller.stick_y = value,
+      _ => return None
+    }
+
+    let stick_input = stick_direction(controller.stick_x, controller.stick_y);
+    if stick_input == controller.stick_input { return None }
+
+    if let Some(previous_input) = controller.stick_input { controller.input_repeat.release(previous_input) }
+    controller.stick_input = stick_input;
+
+    if let Some(input) = stick_input { controller.input_repeat.press(input) }
+
+    stick_input
+  }
+
+  pub fn disconnected(&mut self, which: u32) {
+    self.controllers.retain(| (instance_id, _) | *instance_id != which);
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) -> Vec<Input> {
+    self.controllers.iter_mut().flat_map(| (_, controller) | controller.input_repeat.update(deltatime)).collect()
+  }
+
+  fn controller(&mut self, which: u32) -> &mut ControllerState {
+    let index = match self.controllers.iter().position(| (instance_id, _) | *instance_id == which) {
+      Some(index) => index,
+
+      None => {
+        self.controllers.push((which, ControllerState {
+          stick_x: 0,
+          stick_y: 0,
+          stick_input: None,
+          input_repeat: InputRepeat::new(self.repeat_delay, self.repeat_interval)
+        }));
+
+        self.controllers.len() - 1
+      }
+    };
+
+    &mut self.controllers[index].1
+  }
+}
+
+fn button_input(button: Button) -> Option<Input> {
+  match button {
+    Button::DPadUp => Some(Input::Up),
+    Button::DPadLeft => Some(Input::Left),
+    Button::DPadRight => Some(Input::Right),
+    Button::DPadDown => Some(Input::Down),
+    Button::A => Some(Input::Confirm),
+    Button::B | Button::Start => Some(Input::Cancel),
+    Button::X => Some(Input::Action),
+    Button::Back => Some(Input::Minimap),
+    _ => None
+  }
+}
+
+fn is_directional(input: Input) -> bool {
+  matches!(input, Input::Up | Input::Left | Input::Right | Input::Down)
+}
+
+fn stick_direction(x: i16, y: i16) -> Option<Input> {
+  let (x, y) = (x as i32, y as i32);
+  if x.abs() < STICK_DEAD_ZONE as i32 && y.abs() < STICK_DEAD_ZONE as i32 { return None }
+
+  if x.abs() > y.abs() {
+    if x > 0 { Some(Input::Right) } else { Some(Input::Left) }
+  } else if y > 0 {
+    Some(Input::Down)
+  } else {
+    Some(Input::Up)
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use sdl2::controller::{
+    Axis,
+    Button
+  };
+
+  use crate::Input;
+  use super::ControllerInput;
+
+  #[test]
+  fn maps_buttons_to_inputs() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    assert_eq!(controller_input.button_down(0, Button::DPadUp), Some(Input::Up));
+    assert_eq!(controller_input.button_down(0, Button::A), Some(Input::Confirm));
+    assert_eq!(controller_input.button_down(0, Button::B), Some(Input::Cancel));
+    assert_eq!(controller_input.button_down(0, Button::X), Some(Input::Action));
+    assert_eq!(controller_input.button_down(0, Button::Guide), None);
+  }
+
+  #[test]
+  fn ignores_stick_inside_dead_zone() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftX, 4000), None);
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftY, -7000), None);
+    assert_eq!(controller_input.update(&Duration::from_secs(2)), vec![]);
+  }
+
+  #[test]
+  fn stick_emits_once_per_direction_then_repeats() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftX, 30000), Some(Input::Right));
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftX, 32000), None);
+    assert_eq!(controller_input.update(&Duration::from_millis(400)), vec![Input::Right]);
+
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftX, 0), None);
+    assert_eq!(controller_input.update(&Duration::from_secs(2)), vec![]);
+  }
+
+  #[test]
+  fn dominant_axis_wins() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    controller_input.axis_motion(0, Axis::LeftX, 12000);
+    assert_eq!(controller_input.axis_motion(0, Axis::LeftY, -30000), Some(Input::Up));
+  }
+
+  #[test]
+  fn held_dpad_repeats_but_face_buttons_do_not() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    controller_input.button_down(0, Button::A);
+    assert_eq!(controller_input.update(&Duration::from_secs(2)), vec![]);
+
+    controller_input.button_down(0, Button::DPadDown);
+    assert_eq!(controller_input.update(&Duration::from_millis(550)), vec![Input::Down, Input::Down]);
+
+    controller_input.button_up(0, Button::DPadDown);
+    assert_eq!(controller_input.update(&Duration::from_secs(2)), vec![]);
+  }
+  #[test]
+  fn disconnecting_keeps_other_controllers_held() {
+    let mut controller_input = ControllerInput::new(Duration::from_millis(400), Duration::from_millis(150));
+
+    assert_eq!(controller_input.axis_motion(1, Axis::LeftX, 30000), Some(Input::Right));
+    assert_eq!(controller_input.button_down(2, Button::DPadDown), Some(Input::Down));
+
+    controller_input.disconnected(2);
+    assert_eq!(controller_input.update(&Duration::from_millis(400)), vec![Input::Right]);
+    assert_eq!(controller_input.axis_motion(1, Axis::LeftX, 32000), None);
+  }
+}
@@ -0,0 +1,38 @@
+pub fn cycle_option<T: PartialEq + Copy>(options: &[T], current: T, forward: bool) -> T {
+  let current_index = match options.iter().position(| option | *option == current) {
+    Some(index) => index,
+    None => return options[0]
+  };
+
+  if forward {
+    options[(current_index + 1) % options.len()]
+  } else {
+    options[(current_index + options.len() - 1) % options.len()]
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::cycle_option;
+
+  #[test]
+  fn cycles_forward_and_wraps() {
+    let options = [1, 2, 3];
+
+    assert_eq!(cycle_option(&options, 1, true), 2);
+    assert_eq!(cycle_option(&options, 3, true), 1);
+  }
+
+  #[test]
+  fn cycles_backward_and_wraps() {
+    let options = [1, 2, 3];
+
+    assert_eq!(cycle_option(&options, 2, false), 1);
+    assert_eq!(cycle_option(&options, 1, false), 3);
+  }
+
+  #[test]
+  fn unknown_value_snaps_to_first_option() {
+    assert_eq!(cycle_option(&[8, 16], 12, true), 8);
+  }
+}
@@ -0,0 +1,24 @@
+use std::path::{
+  Path,
+  PathBuf
+};
+
+pub struct DataPaths {
+  pub settings_file: PathBuf,
+  pub bindings_file: PathBuf,
+  pub saves_directory: PathBuf,
+  pub high_scores_file: PathBuf,
+  pub log_file: PathBuf
+}
+
+impl DataPaths {
+  pub fn new(data_directory: &Path) -> Self {
+    Self {
+      settings_file: data_directory.join("settings.txt"),
+      bindings_file: data_directory.join("input_bindings.txt"),
+      saves_directory: data_directory.join("saves"),
+      high_scores_file: data_directory.join("high_scores.txt"),
+      log_file: data_directory.join("drakes_vs_snakes.log")
+    }
+  }
+}
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use crate::{
+  AnimationSheet,
+  AnimationClip,
+  AnimationFrame,
+  AnimationMode,
+  parse_usize
+};
+
+const MAX_STRIP_FRAMES: usize = 256;
+
+pub fn deserialize_animation_sheet(sheet_string: String) -> Result<AnimationSheet, String> {
+  let mut texture = None;
+  let mut clips: Vec<AnimationClip> = Vec::new();
+
+  for line in sheet_string.lines() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') { continue; }
+
+    let (key, value) = match line.split_once('=') {
+      Some((key, value)) => (key.trim(), value.trim()),
+      None => return Err(format!("Invalid animation line: {}", line))
+    };
+
+    match key {
+      "texture" => texture = Some(value.to_string()),
+
+      "clip" => {
+        let fields: Vec<&str> = value.split(',').map(| field | field.trim()).collect();
+        if fields.len() < 2 || fields.len() > 3 || fields[0].is_empty() { return Err(format!("Invalid clip: {}", value)) }
+        if clips.iter().any(| clip | clip.name == fields[0]) { return Err(format!("Duplicate clip: {}", fields[0])) }
+
+        let mode = match fields[1] {
+          "loop" => AnimationMode::Loop,
+          "once" => AnimationMode::Once,
+          "ping_pong" => AnimationMode::PingPong,
+          _ => return Err(format!("Invalid animation mode: {}", fields[1]))
+        };
+
+        clips.push(AnimationClip::new(fields[0], mode, fields.get(2).map(| next | next.to_string())));
+      },
+
+      "frame" | "strip" => {
+        let numbers = value.split(',').map(| field | parse_usize(field.trim())).collect::<Result<Vec<usize>, String>>()?;
+        let clip = match clips.last_mut() {
+          Some(clip) => clip,
+          None => return Err(format!("Frame outside of a clip: {}", line))
+        };
+
+        let (count, milliseconds) = match (key, numbers.len()) {
+          ("frame", 5) => (1, numbers[4]),
+          ("strip", 6) => (numbers[4], numbers[5]),
+          _ => return Err(format!("Invalid {}: {}", key, value))
+        };
+
+        if milliseconds == 0 { return Err(format!("Frame duration must be above zero: {}", line)) }
+        if count == 0 || count > MAX_STRIP_FRAMES { return Err(format!("Strip must have between 1 and {} frames: {}", MAX_STRIP_FRAMES, line)) }
+        if numbers[2] == 0 || numbers[3] == 0 { return Err(format!("Frame size must be above zero: {}", line)) }
+
+        for index in 0..count {
+          let x = numbers[0] + numbers[2] * index;
+          clip.frames.push(AnimationFrame::new(x as u32, numbers[1] as u32, numbers[2] as u32, numbers[3] as u32, Duration::from_millis(milliseconds as u64)));
+        }
+      },
+
+      _ => return Err(format!("Invalid animation key: {}", key))
+    }
+  }
+
+  let mut sheet = match texture {
+    Some(texture) => AnimationSheet::new(&texture),
+    None => return Err("Animation sheet has no texture".to_string())
+  };
+
+  for clip in &clips {
+    if clip.frames.is_empty() { return Err(format!("Clip has no frames: {}", clip.name)) }
+
+    if let Some(next) = clip.next.as_ref().filter(| next | !clips.iter().any(| other | &other.name == *next)) {
+      return Err(format!("Unknown next clip: {}", next));
+    }
+  }
+
+  sheet.clips = clips;
+
+  Ok(sheet)
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::{
+    AnimationFrame,
+    AnimationMode
+  };
+
+  use super::deserialize_animation_sheet;
+
+  #[test]
+  fn standard_operation() {
+    let sheet_string = "texture=textures/drake.png\nclip=idle,loop\nframe=0,0,32,32,200\nframe=32,0,32,32,100\n\nclip=hurt,once,idle\nframe=64,0,32,32,400\n".to_string();
+
+    match deserialize_animation_sheet(sheet_string) {
+      Ok(sheet) => {
+        assert_eq!(sheet.texture, "textures/drake.png");
+        assert_eq!(sheet.clips.len(), 2);
+        assert_eq!(sheet.clips[0].mode, AnimationMode::Loop);
+        assert_eq!(sheet.clips[0].frames, vec![
+          AnimationFrame::new(0, 0, 32, 32, Duration::from_millis(200)),
+          AnimationFrame::new(32, 0, 32, 32, Duration::from_millis(100))
+        ]);
+        assert_eq!(sheet.clips[1].mode, AnimationMode::Once);
+        assert_eq!(sheet.clips[1].next, Some("idle".to_string()));
+        assert_eq!(sheet.clip_index("hurt"), Some(1));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn expands_strips() {
+    let sheet_string = "texture=textures/water.png\nclip=flow,ping_pong\nstrip=0,32,16,16,3,50\n".to_string();
+
+    match deserialize_animation_sheet(sheet_string) {
+      Ok(sheet) => {
+        assert_eq!(sheet.clips[0].mode, AnimationMode::PingPong);
+        assert_eq!(sheet.clips[0].frames.len(), 3);
+        assert_eq!(sheet.clips[0].frames[2], AnimationFrame::new(32, 32, 16, 16, Duration::from_millis(50)));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_without_texture() {
+    match deserialize_animation_sheet("clip=idle,loop\nframe=0,0,32,32,100\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Animation sheet has no texture")
+    }
+  }
+
+  #[test]
+  fn fails_on_frame_outside_of_a_clip() {
+    match deserialize_animation_sheet("texture=a.png\nframe=0,0,32,32,100\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Frame outside of a clip: frame=0,0,32,32,100")
+    }
+  }
+
+  #[test]
+  fn fails_on_empty_clip() {
+    match deserialize_animation_sheet("texture=a.png\nclip=idle,loop\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Clip has no frames: idle")
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_next_clip() {
+    match deserialize_animation_sheet("texture=a.png\nclip=hurt,once,idle\nframe=0,0,32,32,100\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Unknown next clip: idle")
+    }
+  }
+
+  #[test]
+  fn fails_on_zero_duration() {
+    match deserialize_animation_sheet("texture=a.png\nclip=idle,loop\nframe=0,0,32,32,0\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Frame duration must be above zero: frame=0,0,32,32,0")
+    }
+  }
+
+  #[test]
+  fn fails_on_oversized_strip() {
+    match deserialize_animation_sheet("texture=a.png\nclip=idle,loop\nstrip=0,0,1,1,100000000,50\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Strip must have between 1 and 256 frames: strip=0,0,1,1,100000000,50")
+    }
+  }
+
+  #[test]
+  fn fails_on_empty_frame() {
+    match deserialize_animation_sheet("texture=a.png\nclip=idle,loop\nframe=0,0,0,32,50\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Frame size must be above zero: frame=0,0,0,32,50")
+    }
+  }
+
+  #[test]
+  fn fails_on_invalid_mode() {
+    match deserialize_animation_sheet("texture=a.png\nclip=idle,bounce\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid animation mode: bounce")
+    }
+  }
+}
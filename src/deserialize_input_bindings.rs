@@ -0,0 +1,138 @@
+use sdl2::keyboard::Keycode;
+
+use crate::{
+  InputBindings,
+  Input,
+  input_bindings::{
+    BINDABLE_INPUTS,
+    input_name,
+    input_from_name
+  },
+  key_from_name
+};
+
+pub fn deserialize_input_bindings(bindings_string: String) -> Result<InputBindings, String> {
+  let mut input_bindings = InputBindings::empty();
+  let mut listed_inputs = Vec::new();
+
+  for line in bindings_string.lines() {
+    let line = line.trim();
+    if line.is_empty() { continue; }
+
+    let (name, keys) = match line.split_once('=') {
+      Some((name, keys)) => (name.trim(), keys),
+      None => return Err(format!("Invalid binding line: {}", line))
+    };
+
+    let input = match input_from_name(name) {
+      Some(input) => input,
+      None => return Err(format!("Unknown input: {}", name))
+    };
+
+    listed_inputs.push(input);
+
+    for key in keys.split(',').map(| key | key.trim()).filter(| key | !key.is_empty()) {
+      match key_from_name(key) {
+        Some(keycode) => input_bindings.bind(keycode, input)?,
+        None => return Err(format!("Unknown key: {}", key))
+      }
+    }
+  }
+
+  if input_bindings.input_for(Keycode::Escape).is_none() { input_bindings.bind(Keycode::Escape, Input::Cancel)? }
+
+  let default_bindings = InputBindings::new();
+  for input in BINDABLE_INPUTS.into_iter().filter(| input | !listed_inputs.contains(input)) {
+    for keycode in default_bindings.keys_for(input) {
+      if input_bindings.input_for(keycode).is_none() { input_bindings.bind(keycode, input)? }
+    }
+  }
+
+  for input in BINDABLE_INPUTS {
+    if input_bindings.keys_for(input).is_empty() { return Err(format!("{} needs at least one key", input_name(input))) }
+  }
+
+  Ok(input_bindings)
+}
+
+#[cfg(test)]
+mod testing {
+  use sdl2::keyboard::Keycode;
+
+  use crate::{
+    InputBindings,
+    Input,
+    serialize_input_bindings
+  };
+
+  use super::deserialize_input_bindings;
+
+  #[test]
+  fn round_trip() {
+    let mut input_bindings = InputBindings::new();
+    input_bindings.bind(Keycode::Kp5, Input::Minimap).unwrap();
+
+    match deserialize_input_bindings(serialize_input_bindings(&input_bindings)) {
+      Ok(deserialized) => assert_eq!(deserialized, input_bindings),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_conflicting_keys() {
+    let bindings_string = "Up=W\nLeft=A\nRight=D\nDown=S\nConfirm=Return\nCancel=Escape\nAction=W\n".to_string();
+
+    match deserialize_input_bindings(bindings_string) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "W is already bound to Up")
+    }
+  }
+
+  #[test]
+  fn fails_on_unbound_input() {
+    let bindings_string = "Up=W\nLeft=A\nRight=D\nDown=S\nConfirm=Return\nCancel=Escape\nAction=\n".to_string();
+
+    match deserialize_input_bindings(bindings_string) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Action needs at least one key")
+    }
+  }
+
+  #[test]
+  fn fills_in_defaults_for_unlisted_inputs() {
+    let bindings_string = "Up=W\nLeft=A\nRight=D\nDown=S\nConfirm=Return\nCancel=Escape\nAction=Space\n".to_string();
+
+    match deserialize_input_bindings(bindings_string) {
+      Ok(input_bindings) => assert_eq!(input_bindings.keys_for(Input::Minimap), vec![Keycode::Tab]),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_when_an_unlisted_default_is_taken() {
+    let bindings_string = "Up=W\nLeft=A\nRight=D\nDown=S\nConfirm=Return\nCancel=Escape\nAction=Tab\n".to_string();
+
+    match deserialize_input_bindings(bindings_string) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Minimap needs at least one key")
+    }
+  }
+
+  #[test]
+  fn keeps_escape_on_cancel() {
+    let bindings_string = "Cancel=Q\n".to_string();
+
+    match deserialize_input_bindings(bindings_string) {
+      Ok(input_bindings) => assert_eq!(input_bindings.keys_for(Input::Cancel), vec![Keycode::Q, Keycode::Escape]),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_key() {
+    match deserialize_input_bindings("Up=Hyper\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Unknown key: Hyper")
+    }
+  }
+}
@@ -0,0 +1,177 @@
+use sdl2::pixels::Color;
+
+use crate::{
+  ParticleEffect,
+  ParticleMode,
+  parse_usize
+};
+
+const MAX_BURST_COUNT: usize = 1024;
+const MAX_EMISSION_RATE: f32 = 1000.0;
+
+pub fn deserialize_particle_effect(effect_string: String) -> Result<ParticleEffect, String> {
+  let mut effect = ParticleEffect::new(ParticleMode::Burst);
+
+  for line in effect_string.lines() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') { continue; }
+
+    let (key, value) = match line.split_once('=') {
+      Some((key, value)) => (key.trim(), value.trim()),
+      None => return Err(format!("Invalid particle line: {}", line))
+    };
+
+    match key {
+      "mode" => effect.mode = match value {
+        "burst" => ParticleMode::Burst,
+        "continuous" => ParticleMode::Continuous,
+        _ => return Err(format!("Invalid particle mode: {}", value))
+      },
+
+      "count" => effect.count = parse_usize(value)?,
+      "rate" => effect.rate = parse_f32(value)?,
+
+      "lifetime" => {
+        let (shortest, longest) = parse_range(value)?;
+        effect.lifetime = (shortest / 1000.0, longest / 1000.0);
+      },
+
+      "speed" => effect.speed = parse_range(value)?,
+      "direction" => effect.direction = parse_f32(value)?,
+      "spread" => effect.spread = parse_f32(value)?,
+      "gravity" => effect.gravity = parse_f32(value)?,
+      "size" => effect.size = parse_range(value)?,
+      "start_color" => effect.start_color = parse_color(value)?,
+      "end_color" => effect.end_color = parse_color(value)?,
+
+      _ => return Err(format!("Invalid particle key: {}", key))
+    }
+  }
+
+  if effect.mode == ParticleMode::Burst && effect.count == 0 { return Err("Burst count must be above zero".to_string()) }
+  if effect.mode == ParticleMode::Continuous && effect.rate <= 0.0 { return Err("Emission rate must be above zero".to_string()) }
+  if effect.count > MAX_BURST_COUNT { return Err(format!("Burst count must be at most {}", MAX_BURST_COUNT)) }
+  if effect.rate.is_nan() || effect.rate > MAX_EMISSION_RATE { return Err(format!("Emission rate must be at most {}", MAX_EMISSION_RATE)) }
+  if effect.lifetime.0 <= 0.0 || effect.lifetime.0 > effect.lifetime.1 { return Err("Lifetime must be above zero and ordered shortest first".to_string()) }
+  if effect.speed.0 > effect.speed.1 { return Err("Speed must be ordered slowest first".to_string()) }
+
+  Ok(effect)
+}
+
+fn parse_f32(unparsed: &str) -> Result<f32, String> {
+  unparsed.parse().map_err(| error: std::num::ParseFloatError | format!("Invalid number {}: {}", unparsed, error))
+}
+
+fn parse_range(value: &str) -> Result<(f32, f32), String> {
+  match value.split_once(',') {
+    Some((first, second)) => Ok((parse_f32(first.trim())?, parse_f32(second.trim())?)),
+    None => Err(format!("Invalid range: {}", value))
+  }
+}
+
+fn parse_color(value: &str) -> Result<Color, String> {
+  let channels: Vec<&str> = value.split(',').collect();
+  if channels.len() != 4 { return Err(format!("Invalid particle color: {}", value)) }
+
+  let mut parsed_channels = [0; 4];
+  for (index, channel) in channels.iter().enumerate() {
+    parsed_channels[index] = channel.trim().parse::<u8>().map_err(| error | error.to_string())?;
+  }
+
+  Ok(Color::RGBA(parsed_channels[0], parsed_channels[1], parsed_channels[2], parsed_channels[3]))
+}
+
+#[cfg(test)]
+mod testing {
+  use sdl2::pixels::Color;
+
+  use crate::ParticleMode;
+
+  use super::deserialize_particle_effect;
+
+  #[test]
+  fn standard_operation() {
+    let effect_string = "mode=burst\ncount=8\nlifetime=300,600\nspeed=10,40\nspread=180\ngravity=-20\nsize=6,2\nstart_color=200,180,140,220\nend_color=200,180,140,0\n".to_string();
+
+    match deserialize_particle_effect(effect_string) {
+      Ok(effect) => {
+        assert_eq!(effect.mode, ParticleMode::Burst);
+        assert_eq!(effect.count, 8);
+        assert_eq!(effect.lifetime, (0.3, 0.6));
+        assert_eq!(effect.speed, (10.0, 40.0));
+        assert_eq!(effect.spread, 180.0);
+        assert_eq!(effect.gravity, -20.0);
+        assert_eq!(effect.size, (6.0, 2.0));
+        assert_eq!(effect.start_color, Color::RGBA(200, 180, 140, 220));
+        assert_eq!(effect.end_color, Color::RGBA(200, 180, 140, 0));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn reads_continuous_emitters() {
+    match deserialize_particle_effect("mode=continuous\nrate=12.5\nlifetime=200,200\n".to_string()) {
+      Ok(effect) => {
+        assert_eq!(effect.mode, ParticleMode::Continuous);
+        assert_eq!(effect.rate, 12.5);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_empty_burst() {
+    match deserialize_particle_effect("mode=burst\nlifetime=200,300\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Burst count must be above zero")
+    }
+  }
+
+  #[test]
+  fn fails_on_continuous_without_rate() {
+    match deserialize_particle_effect("mode=continuous\nlifetime=200,300\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Emission rate must be above zero")
+    }
+  }
+
+  #[test]
+  fn fails_on_excessive_counts() {
+    match deserialize_particle_effect("count=100000000\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Burst count must be at most 1024")
+    }
+
+    match deserialize_particle_effect("mode=continuous\nrate=1e9\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Emission rate must be at most 1000")
+    }
+  }
+
+  #[test]
+  fn fails_on_unordered_lifetime() {
+    match deserialize_particle_effect("count=4\nlifetime=300,200\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Lifetime must be above zero and ordered shortest first")
+    }
+  }
+
+  #[test]
+  fn fails_on_invalid_color() {
+    match deserialize_particle_effect("count=4\nstart_color=255,255,255\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid particle color: 255,255,255")
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_key() {
+    match deserialize_particle_effect("count=4\ncolour=1\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid particle key: colour")
+    }
+  }
+}
@@ -0,0 +1,140 @@
+use crate::{
+  Settings,
+  Resolution,
+  WindowMode,
+  parse_usize,
+  parse_dimensions
+};
+
+pub fn deserialize_settings(settings_string: String) -> Result<Settings, String> {
+  let mut settings = Settings::new();
+
+  for line in settings_string.lines() {
+    let line = line.trim();
+    if line.is_empty() { continue; }
+
+    let (key, value) = match line.split_once('=') {
+      Some((key, value)) => (key.trim(), value.trim()),
+      None => return Err(format!("Invalid settings line: {}", line))
+    };
+
+    match key {
+      "resolution" => {
+        let (width, height) = parse_dimensions(value)?;
+        settings.resolution = Resolution::new(width, height);
+      },
+
+      "window_mode" => settings.window_mode = match value {
+        "fullscreen" => WindowMode::Fullscreen,
+        "borderless" => WindowMode::Borderless,
+        "windowed" => WindowMode::Windowed,
+        _ => return Err(format!("Invalid window mode: {}", value))
+      },
+
+      "letterbox" => settings.letterbox = match value {
+        "true" => true,
+        "false" => false,
+        _ => return Err(format!("Invalid letterbox setting: {}", value))
+      },
+
+      "fps_cap" => settings.fps_cap = parse_usize(value)? as u64,
+      "ui_scale" => settings.ui_scale_percent = parse_usize(value)?,
+
+      "text_color" => {
+        let channels: Vec<&str> = value.split(',').collect();
+        if channels.len() != 3 { return Err(format!("Invalid text color: {}", value)) }
+
+        let mut parsed_channels = [0; 3];
+        for (index, channel) in channels.iter().enumerate() {
+          parsed_channels[index] = channel.trim().parse::<u8>().map_err(| error | error.to_string())?;
+        }
+
+        settings.text_color = (parsed_channels[0], parsed_channels[1], parsed_channels[2]);
+      },
+
+      "default_width" => settings.default_width = parse_usize(value)?,
+      "default_height" => settings.default_height = parse_usize(value)?,
+      "default_num_snakes" => settings.default_num_snakes = parse_usize(value)?,
+      "repeat_delay" => settings.repeat_delay_ms = parse_usize(value)? as u64,
+      "repeat_interval" => settings.repeat_interval_ms = parse_usize(value)? as u64,
+
+      _ => {}
+    }
+  }
+
+  if settings.fps_cap == 0 { return Err("FPS cap must be above zero".to_string()) }
+  if settings.ui_scale_percent == 0 { return Err("UI scale must be above zero".to_string()) }
+  if settings.repeat_interval_ms == 0 { return Err("Repeat interval must be above zero".to_string()) }
+
+  Ok(settings)
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Settings,
+    Resolution,
+    WindowMode,
+    serialize_settings
+  };
+
+  use super::deserialize_settings;
+
+  #[test]
+  fn round_trip() {
+    let mut settings = Settings::new();
+    settings.resolution = Resolution::new(1280, 720);
+    settings.window_mode = WindowMode::Borderless;
+    settings.letterbox = false;
+    settings.fps_cap = 144;
+    settings.ui_scale_percent = 150;
+    settings.text_color = (255, 200, 64);
+    settings.default_width = 32;
+    settings.default_height = 16;
+    settings.default_num_snakes = 64;
+    settings.repeat_delay_ms = 500;
+    settings.repeat_interval_ms = 50;
+
+    match deserialize_settings(serialize_settings(&settings)) {
+      Ok(deserialized) => assert_eq!(deserialized, settings),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn missing_keys_use_defaults() {
+    match deserialize_settings("fps_cap=30\nunknown=1\n".to_string()) {
+      Ok(settings) => {
+        assert_eq!(settings.fps_cap, 30);
+        assert_eq!(settings.resolution, Resolution::new(1600, 900));
+        assert_eq!(settings.window_mode, WindowMode::Fullscreen);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_invalid_window_mode() {
+    match deserialize_settings("window_mode=sideways\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid window mode: sideways")
+    }
+  }
+
+  #[test]
+  fn fails_on_invalid_text_color() {
+    match deserialize_settings("text_color=1,2\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid text color: 1,2")
+    }
+  }
+
+  #[test]
+  fn fails_on_zero_fps_cap() {
+    match deserialize_settings("fps_cap=0\n".to_string()) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "FPS cap must be above zero")
+    }
+  }
+}
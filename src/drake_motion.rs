@@ -0,0 +1,336 @@
+use std::{
+  collections::VecDeque,
+  time::Duration
+};
+
+use crate::{
+  Coordinate,
+  Direction,
+  GameEvent,
+  MapSize,
+  Message,
+  Subscriber,
+  Vector2,
+  tile_location
+};
+
+const STEP_DURATION: Duration = Duration::from_millis(120);
+const BUMP_DURATION: Duration = Duration::from_millis(160);
+const BUMP_DISTANCE: f32 = 0.2;
+const MAX_QUEUED_STEPS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Step {
+  Move(usize, usize),
+  Bump(usize, Direction)
+}
+
+impl Step {
+  fn duration(&self) -> Duration {
+    match self {
+      Step::Move( .. ) => STEP_DURATION,
+      Step::Bump( .. ) => BUMP_DURATION
+    }
+  }
+
+  fn direction(&self) -> Direction {
+    match *self {
+      Step::Move(from, to) => {
+        if to == from + 1 { Direction::East }
+        else if to + 1 == from { Direction::West }
+        else if to > from { Direction::South }
+        else { Direction::North }
+      },
+
+      Step::Bump(_, direction) => direction
+    }
+  }
+
+  fn destination(&self) -> usize {
+    match *self {
+      Step::Move(_, to) => to,
+      Step::Bump(at, _) => at
+    }
+  }
+}
+
+pub struct DrakeMotion {
+  steps: VecDeque<Step>,
+  elapsed: Duration,
+  facing: Direction,
+  resting_index: Option<usize>
+}
+
+impl DrakeMotion {
+  pub fn new() -> Self {
+    Self {
+      steps: VecDeque::new(),
+      elapsed: Duration::ZERO,
+      facing: Direction::North,
+      resting_index: None
+    }
+  }
+
+  pub fn reset(&mut self) {
+    self.steps.clear();
+    self.elapsed = Duration::ZERO;
+    self.facing = Direction::North;
+    self.resting_index = None;
+  }
+
+  pub fn is_moving(&self) -> bool {
+    matches!(self.steps.front(), Some(Step::Move( .. )))
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) {
+    self.elapsed += *deltatime * self.steps.len().max(1) as u32;
+
+    while let Some(step) = self.steps.front() {
+      if self.elapsed < step.duration() { break }
+
+      self.elapsed -= step.duration();
+      self.facing = step.direction();
+      self.steps.pop_front();
+    }
+
+    if self.steps.is_empty() {
+      self.elapsed = Duration::ZERO;
+    }
+  }
+
+  pub fn location(&self, player_index: usize, map_size: &MapSize, tile_width: usize, tile_height: usize) -> Vector2 {
+    let location_of = | index: usize | tile_location(&Coordinate::from_index(index, map_size), map_size, tile_width, tile_height);
+
+    match self.steps.front() {
+      Some(&Step::Move(from, to)) => {
+        let from = location_of(from);
+        let to = location_of(to);
+        let progress = ease(self.progress());
+
+        Vector2 {
+          x: from.x + (to.x - from.x) * progress,
+          y: from.y + (to.y - from.y) * progress
+        }
+      },
+
+      Some(&Step::Bump(at, direction)) => {
+        let mut location = location_of(at);
+        let distance = BUMP_DISTANCE * (std::f32::consts::PI * self.progress()).sin();
+
+        match direction {
+          Direction::North => location.y -= distance * tile_height as f32,
+          Direction::West => location.x -= distance * tile_width as f32,
+          Direction::East => location.x += distance * tile_width as f32,
+          Direction::South => location.y += distance * tile_height as f32
+        }
+
+        location
+      },
+
+      None => location_of(self.resting_index.unwrap_or(player_index))
+    }
+  }
+
+  pub fn rotation(&self) -> f32 {
+    let direction = self.steps.front().map_or(self.facing, Step::direction);
+
+    match direction {
+      Direction::North => 0.0,
+      Direction::East => 90.0,
+      Direction::South => 180.0,
+      Direction::West => 270.0
+    }
+  }
+
+  fn progress(&self) -> f32 {
+    self.steps.front().map_or(0.0, | step | self.elapsed.as_secs_f32() / step.duration().as_secs_f32())
+  }
+
+  fn push(&mut self, step: Step) {
+    self.resting_index = Some(step.destination());
+    self.steps.push_back(step);
+
+    while self.steps.len() > MAX_QUEUED_STEPS {
+      if let Some(skipped) = self.steps.pop_front() {
+        self.facing = skipped.direction();
+      }
+      self.elapsed = Duration::ZERO;
+    }
+  }
+}
+
+impl Subscriber for DrakeMotion {
+  fn receive(&mut self, message: &Message) {
+    if let Message::GameEvent(event) = message { match *event {
+      GameEvent::PlayerMoved(from, to) => self.push(Step::Move(from, to)),
+      GameEvent::MoveBlocked(at, direction) => self.push(Step::Bump(at, direction)),
+
+      _ => {}
+    }}
+  }
+}
+
+fn ease(progress: f32) -> f32 {
+  progress * progress * (3.0 - 2.0 * progress)
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::{
+    Direction,
+    GameEvent,
+    MapSize,
+    Message,
+    Subscriber
+  };
+
+  use super::DrakeMotion;
+
+  #[test]
+  fn rests_on_the_player_tile() {
+    let motion = DrakeMotion::new();
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        let location = motion.location(5, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-32.0, -32.0));
+        assert_eq!(motion.rotation(), 0.0);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn tweens_between_tiles() {
+    let mut motion = DrakeMotion::new();
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(0, 1)));
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        let location = motion.location(1, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-64.0, -64.0));
+        assert_eq!(motion.rotation(), 90.0);
+        assert_eq!(motion.is_moving(), true);
+
+        motion.update(&Duration::from_millis(60));
+        let location = motion.location(1, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-48.0, -64.0));
+
+        motion.update(&Duration::from_millis(60));
+        let location = motion.location(1, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-32.0, -64.0));
+        assert_eq!(motion.rotation(), 90.0);
+        assert_eq!(motion.is_moving(), false);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn faces_the_direction_of_travel() {
+    let mut motion = DrakeMotion::new();
+
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(5, 9)));
+    motion.update(&Duration::from_millis(120));
+    assert_eq!(motion.rotation(), 180.0);
+
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(9, 8)));
+    motion.update(&Duration::from_millis(120));
+    assert_eq!(motion.rotation(), 270.0);
+
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(8, 4)));
+    motion.update(&Duration::from_millis(120));
+    assert_eq!(motion.rotation(), 0.0);
+  }
+
+  #[test]
+  fn bumps_back_to_the_same_tile() {
+    let mut motion = DrakeMotion::new();
+    motion.receive(&Message::GameEvent(GameEvent::MoveBlocked(0, Direction::West)));
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        motion.update(&Duration::from_millis(80));
+        let location = motion.location(0, &map_size, 32, 32);
+        assert!(location.x < -64.0);
+        assert_eq!(location.y, -64.0);
+        assert_eq!(motion.rotation(), 270.0);
+
+        motion.update(&Duration::from_millis(80));
+        let location = motion.location(0, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-64.0, -64.0));
+        assert_eq!(motion.rotation(), 270.0);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn catches_up_when_steps_queue() {
+    let mut motion = DrakeMotion::new();
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(0, 1)));
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(1, 2)));
+
+    motion.update(&Duration::from_millis(60));
+    motion.update(&Duration::from_millis(120));
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        let location = motion.location(2, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (0.0, -64.0));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn drops_steps_beyond_the_queue_limit() {
+    let mut motion = DrakeMotion::new();
+
+    for index in 0..6 {
+      motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(index, index + 1)));
+    }
+
+    assert_eq!(motion.steps.len(), 4);
+    assert_eq!(motion.steps.front(), Some(&super::Step::Move(2, 3)));
+  }
+
+  #[test]
+  fn waits_for_the_move_event() {
+    let mut motion = DrakeMotion::new();
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(0, 1)));
+    motion.update(&Duration::from_millis(120));
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        let location = motion.location(2, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (-32.0, -64.0));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn reset_clears_pending_steps() {
+    let mut motion = DrakeMotion::new();
+    motion.receive(&Message::GameEvent(GameEvent::PlayerMoved(0, 1)));
+    motion.reset();
+
+    match MapSize::from(4, 4) {
+      Ok(map_size) => {
+        let location = motion.location(6, &map_size, 32, 32);
+        assert_eq!((location.x, location.y), (0.0, -32.0));
+        assert_eq!(motion.rotation(), 0.0);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
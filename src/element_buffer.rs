@@ -25,6 +25,21 @@ impl ElementBuffer {
     }
   }
 
+  pub fn upload(&self, element_data: &[u32]) {
+    unsafe {
+      gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, self.id);
+
+      gl::BufferData(
+        gl::ELEMENT_ARRAY_BUFFER,
+        std::mem::size_of_val(element_data) as gl::types::GLsizeiptr,
+        element_data.as_ptr() as *const gl::types::GLvoid,
+        gl::DYNAMIC_DRAW
+      );
+
+      gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, 0);
+    }
+  }
+
   pub fn id(&self) -> gl::types::GLuint {
     self.id
   }
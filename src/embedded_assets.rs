@@ -0,0 +1,26 @@
+include!(concat!(env!("OUT_DIR"), "/embedded_assets.rs"));
+
+pub fn embedded_asset(asset_id: &str) -> Option<&'static [u8]> {
+  EMBEDDED_ASSETS
+    .iter()
+    .find(| (embedded_id, _) | *embedded_id == asset_id)
+    .map(| (_, bytes) | *bytes)
+}
+
+#[cfg(test)]
+mod testing {
+  use super::embedded_asset;
+
+  #[test]
+  fn unknown_assets_are_not_embedded() {
+    assert_eq!(embedded_asset("textures/missing.png"), None);
+  }
+
+  #[cfg(feature = "embedded-assets")]
+  #[test]
+  fn embeds_every_asset_under_res() {
+    assert_eq!(embedded_asset("shaders/quad_vertex_shader.glsl").map(| bytes | bytes.to_vec()), std::fs::read("./res/shaders/quad_vertex_shader.glsl").ok());
+    assert!(embedded_asset("fonts/RasterForgeRegular.ttf").is_some());
+    assert!(embedded_asset("textures/hints/grass_8.png").is_some());
+  }
+}
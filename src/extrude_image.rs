@@ -0,0 +1,34 @@
+use image::RgbaImage;
+
+pub fn extrude_image(image: &RgbaImage, padding: u32) -> RgbaImage {
+  RgbaImage::from_fn(image.width() + padding * 2, image.height() + padding * 2, | x, y | {
+    let source_x = (x as i64 - padding as i64).clamp(0, image.width() as i64 - 1) as u32;
+    let source_y = (y as i64 - padding as i64).clamp(0, image.height() as i64 - 1) as u32;
+
+    *image.get_pixel(source_x, source_y)
+  })
+}
+
+#[cfg(test)]
+mod testing {
+  use image::{
+    Rgba,
+    RgbaImage
+  };
+
+  use super::extrude_image;
+
+  #[test]
+  fn repeats_the_edges_into_the_border() {
+    let mut image = RgbaImage::from_pixel(2, 1, Rgba([0, 255, 0, 255]));
+    image.put_pixel(1, 0, Rgba([255, 255, 0, 255]));
+
+    let extruded = extrude_image(&image, 2);
+
+    assert_eq!(extruded.dimensions(), (6, 5));
+    assert_eq!(*extruded.get_pixel(0, 0), Rgba([0, 255, 0, 255]));
+    assert_eq!(*extruded.get_pixel(2, 2), Rgba([0, 255, 0, 255]));
+    assert_eq!(*extruded.get_pixel(3, 2), Rgba([255, 255, 0, 255]));
+    assert_eq!(*extruded.get_pixel(5, 4), Rgba([255, 255, 0, 255]));
+  }
+}
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+
+use crate::{
+  Map,
+  Coordinate,
+  Direction,
+  get_direct_neighbors
+};
+
+pub fn find_route(map: &Map, destination: &Coordinate) -> Option<Vec<Direction>> {
+  let start = map.player_location.array_index();
+  let target = destination.array_index();
+
+  if target >= map.size.array_length() || map.is_marked[target] { return None }
+  if target == start { return Some(Vec::new()) }
+
+  let mut previous: Vec<Option<usize>> = vec![None; map.size.array_length()];
+  let mut visited = vec![false; map.size.array_length()];
+  let mut queue = VecDeque::new();
+
+  visited[start] = true;
+  queue.push_back(start);
+
+  while let Some(index) = queue.pop_front() {
+    if index == target { break; }
+
+    let is_walkable = index == start || (map.is_explored[index] && !map.is_marked[index]);
+    if !is_walkable { continue; }
+
+    for neighbor in get_direct_neighbors(&Coordinate::from_index(index, &map.size), &map.size) {
+      let neighbor_index = neighbor.array_index();
+      if visited[neighbor_index] || map.is_marked[neighbor_index] { continue; }
+      if !map.is_explored[neighbor_index] && neighbor_index != target { continue; }
+
+      visited[neighbor_index] = true;
+      previous[neighbor_index] = Some(index);
+      queue.push_back(neighbor_index);
+    }
+  }
+
+  if !visited[target] { return None }
+
+  let mut route = Vec::new();
+  let mut current = target;
+
+  while let Some(before) = previous[current] {
+    let width = map.size.width();
+
+    route.push(
+      if current + width == before { Direction::North }
+      else if current == before + width { Direction::South }
+      else if current + 1 == before { Direction::West }
+      else { Direction::East }
+    );
+
+    current = before;
+  }
+
+  route.reverse();
+  Some(route)
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Map,
+    Coordinate,
+    Direction
+  };
+
+  use super::find_route;
+
+  fn explored_map(explored: &[usize]) -> Map {
+    let mut map = Map::new();
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+
+    for index in explored { map.is_explored[*index] = true }
+
+    map
+  }
+
+  #[test]
+  fn walks_through_explored_tiles() {
+    let map = explored_map(&[0, 1, 2, 6]);
+
+    assert_eq!(find_route(&map, &Coordinate::from_index(6, &map.size)), Some(vec![Direction::East, Direction::East, Direction::South]));
+  }
+
+  #[test]
+  fn steps_onto_an_unexplored_neighbor_of_the_route() {
+    let map = explored_map(&[0, 1]);
+
+    assert_eq!(find_route(&map, &Coordinate::from_index(5, &map.size)), Some(vec![Direction::East, Direction::South]));
+  }
+
+  #[test]
+  fn never_crosses_unexplored_tiles() {
+    let map = explored_map(&[0]);
+
+    assert_eq!(find_route(&map, &Coordinate::from_index(2, &map.size)), None);
+  }
+
+  #[test]
+  fn avoids_marked_tiles() {
+    let mut map = explored_map(&[0, 1, 2, 4, 5, 6]);
+    map.is_marked[1] = true;
+
+    assert_eq!(find_route(&map, &Coordinate::from_index(2, &map.size)), Some(vec![Direction::South, Direction::East, Direction::East, Direction::North]));
+    assert_eq!(find_route(&map, &Coordinate::from_index(1, &map.size)), None);
+  }
+}
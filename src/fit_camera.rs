@@ -0,0 +1,27 @@
+use sdl2::video::Window;
+
+use crate::{
+  Camera,
+  Settings,
+  calculate_viewport
+};
+
+pub fn fit_camera(camera: &mut Camera, window: &Window, settings: &Settings) {
+  let (drawable_width, drawable_height) = window.drawable_size();
+  let (window_width, window_height) = window.size();
+  let pixel_ratio = drawable_width as f32 / window_width.max(1) as f32;
+
+  let (view_width, view_height, viewport) = if settings.letterbox {
+    let viewport = calculate_viewport(drawable_width, drawable_height, Some(settings.resolution));
+    (settings.resolution.width() as f32, settings.resolution.height() as f32, viewport)
+  } else {
+    let viewport = calculate_viewport(drawable_width, drawable_height, None);
+    (window_width.max(1) as f32, window_height.max(1) as f32, viewport)
+  };
+
+  unsafe {
+    gl::Viewport(viewport.x, viewport.y, viewport.width, viewport.height);
+  }
+
+  camera.resize(view_width / settings.ui_scale(), view_height / settings.ui_scale(), viewport, pixel_ratio);
+}
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Frontend {
+  Sdl,
+  Terminal,
+  Headless
+}
@@ -0,0 +1,12 @@
+use crate::Direction;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameEvent {
+  PlayerMoved(usize, usize),
+  MoveBlocked(usize, Direction),
+  TileRevealed(usize),
+  MarkToggled(usize, bool),
+  ScoreChanged(usize),
+  SnakeBitten(usize),
+  GoalReached(usize)
+}
@@ -0,0 +1,58 @@
+use std::{
+  sync::Mutex,
+  time::Instant
+};
+
+use log::{
+  Log,
+  Metadata,
+  Record
+};
+
+use crate::{
+  LogFilter,
+  RotatingLogFile
+};
+
+pub struct GameLogger {
+  filter: LogFilter,
+  writes_to_console: bool,
+  log_file: Option<Mutex<RotatingLogFile>>,
+  start: Instant
+}
+
+impl GameLogger {
+  pub fn new(filter: LogFilter, writes_to_console: bool, log_file: Option<RotatingLogFile>) -> Self {
+    Self {
+      filter,
+      writes_to_console,
+      log_file: log_file.map(Mutex::new),
+      start: Instant::now()
+    }
+  }
+}
+
+impl Log for GameLogger {
+  fn enabled(&self, metadata: &Metadata) -> bool {
+    metadata.level() <= self.filter.level_for(short_target(metadata.target()))
+  }
+
+  fn log(&self, record: &Record) {
+    if !self.enabled(record.metadata()) { return }
+
+    let elapsed = self.start.elapsed().as_secs_f32();
+    let line = format!("[{:>9.3} {:<5} {}] {}", elapsed, record.level(), short_target(record.target()), record.args());
+
+    if self.writes_to_console { eprintln!("{}", line) }
+
+    if let Some(Ok(mut log_file)) = self.log_file.as_ref().map(| log_file | log_file.lock()) {
+      let _ = log_file.write_line(&line);
+    }
+  }
+
+  fn flush(&self) {}
+}
+
+fn short_target(target: &str) -> &str {
+  target.strip_prefix(concat!(env!("CARGO_CRATE_NAME"), "::")).unwrap_or(target)
+}
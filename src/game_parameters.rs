@@ -0,0 +1,16 @@
+use crate::MapSize;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GameParameters {
+  pub size: MapSize,
+  pub num_snakes: usize
+}
+
+impl GameParameters {
+  pub fn new(size: MapSize, num_snakes: usize) -> Self {
+    Self {
+      size,
+      num_snakes
+    }
+  }
+}
@@ -0,0 +1,341 @@
+use rand::{
+  Rng,
+  SeedableRng,
+  rngs::StdRng
+};
+
+use crate::{
+  GameParameters,
+  GameSnapshot,
+  PlayfieldState,
+  Map,
+  Input,
+  Command,
+  Direction,
+  GameEvent,
+  Outcome,
+  MapValidation,
+  generate_map,
+  max_snakes,
+  handle_directional_input,
+  toggle_mark,
+  validate_map
+};
+
+pub struct GameSession {
+  parameters: GameParameters,
+  seed: u64,
+  rng: StdRng,
+  playfield_state: PlayfieldState,
+  history: Vec<Command>,
+  validation: MapValidation
+}
+
+impl GameSession {
+  pub fn new(parameters: GameParameters, seed: u64) -> Result<Self, String> {
+    if parameters.num_snakes > max_snakes(&parameters.size) {
+      return Err(format!("Too many snakes for a {}x{} board", parameters.size.width(), parameters.size.height()));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut playfield_state = PlayfieldState::new();
+    playfield_state.map = generate_map(parameters.size, parameters.num_snakes, &mut rng)?;
+
+    Ok(
+      Self {
+        parameters,
+        seed,
+        rng,
+        playfield_state,
+        history: Vec::new(),
+        validation: MapValidation::Valid
+      }
+    )
+  }
+
+  pub fn from_map(map: Map, seed: u64) -> Result<Self, String> {
+    let num_snakes = map.is_snake.iter().filter(| is_snake | **is_snake).count();
+    let parameters = GameParameters::new(map.size, num_snakes);
+    let validation = validate_map(&map)?;
+
+    let mut playfield_state = PlayfieldState::new();
+    playfield_state.map = map;
+
+    Ok(
+      Self {
+        parameters,
+        seed,
+        rng: StdRng::seed_from_u64(seed),
+        playfield_state,
+        history: Vec::new(),
+        validation
+      }
+    )
+  }
+
+  pub fn empty(seed: u64) -> Self {
+    let map = Map::new();
+
+    Self {
+      parameters: GameParameters::new(map.size, 0),
+      seed,
+      rng: StdRng::seed_from_u64(seed),
+      playfield_state: PlayfieldState::new(),
+      history: Vec::new(),
+      validation: MapValidation::Valid
+    }
+  }
+
+  pub fn apply(&mut self, input: Input) -> Result<Outcome, String> {
+    self.execute(Command::Input(input))
+  }
+
+  pub fn execute(&mut self, command: Command) -> Result<Outcome, String> {
+    if self.validation != MapValidation::Valid { return Ok(Outcome::new(Vec::new(), self.validation)) }
+
+    let step = match command {
+      Command::Input(Input::Up) => self.step(Direction::North),
+      Command::Input(Input::Left) => self.step(Direction::West),
+      Command::Input(Input::Right) => self.step(Direction::East),
+      Command::Input(Input::Down) => self.step(Direction::South),
+      Command::Input(Input::Action) => {
+        self.playfield_state.is_interacting = !self.playfield_state.is_interacting;
+        Ok(Vec::new())
+      },
+
+      Command::ToggleMark(index) => toggle_mark(&mut self.playfield_state.map, index).map(|event| {
+        self.playfield_state.is_interacting = false;
+        vec![event]
+      }),
+      Command::Input(_) => Ok(Vec::new())
+    };
+
+    let mut events = match step {
+      Ok(events) => events,
+      Err(error) => {
+        log::debug!("Rejected {:?}: {}", command, error);
+        return Ok(Outcome::new(Vec::new(), self.validation));
+      }
+    };
+
+    self.validation = validate_map(&self.playfield_state.map)?;
+    self.history.push(command);
+
+    let player_index = self.playfield_state.map.player_location.array_index();
+    match self.validation {
+      MapValidation::Valid => {},
+      MapValidation::Won => events.push(GameEvent::GoalReached(player_index)),
+      MapValidation::Lost => events.push(GameEvent::SnakeBitten(player_index))
+    }
+
+    Ok(Outcome::new(events, self.validation))
+  }
+
+  fn step(&mut self, direction: Direction) -> Result<Vec<GameEvent>, String> {
+    handle_directional_input(&mut self.playfield_state, direction)
+  }
+
+  pub fn snapshot(&self) -> GameSnapshot {
+    GameSnapshot {
+      parameters: self.parameters,
+      seed: self.seed,
+      history: self.history.clone(),
+      map: self.playfield_state.map.clone(),
+      is_interacting: self.playfield_state.is_interacting,
+      validation: self.validation
+    }
+  }
+
+  pub fn parameters(&self) -> GameParameters {
+    self.parameters
+  }
+
+  pub fn seed(&self) -> u64 {
+    self.seed
+  }
+
+  pub fn next_seed(&mut self) -> u64 {
+    self.rng.random()
+  }
+
+  pub fn validation(&self) -> MapValidation {
+    self.validation
+  }
+
+  pub fn playfield_state(&self) -> &PlayfieldState {
+    &self.playfield_state
+  }
+
+  pub fn map(&self) -> &Map {
+    &self.playfield_state.map
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    GameParameters,
+    MapSize,
+    Map,
+    Input,
+    Command,
+    GameEvent,
+    MapValidation
+  };
+
+  use super::GameSession;
+
+  fn open_map() -> Map {
+    let mut map = Map::new();
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+    map.is_explored[0] = true;
+
+    map
+  }
+
+  #[test]
+  fn same_seed_generates_same_map() {
+    let parameters = GameParameters::new(MapSize::from(8, 8).unwrap(), 4);
+
+    match (GameSession::new(parameters, 42), GameSession::new(parameters, 42)) {
+      (Ok(first), Ok(second)) => assert_eq!(first.snapshot(), second.snapshot()),
+      _ => panic!("Expected both sessions to generate")
+    }
+  }
+
+  #[test]
+  fn applies_movement() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    let outcome = game_session.apply(Input::Right).unwrap();
+
+    assert_eq!(outcome.events, vec![
+      GameEvent::PlayerMoved(0, 1),
+      GameEvent::TileRevealed(1)
+    ]);
+
+    assert_eq!(outcome.validation, MapValidation::Valid);
+    assert_eq!(game_session.map().player_location.array_index(), 1);
+    assert_eq!(game_session.snapshot().history, vec![Command::Input(Input::Right)]);
+  }
+
+  #[test]
+  fn applies_marking() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    game_session.apply(Input::Action).unwrap();
+    assert_eq!(game_session.playfield_state().is_interacting, true);
+
+    let outcome = game_session.apply(Input::Down).unwrap();
+
+    assert_eq!(outcome.events, vec![GameEvent::MarkToggled(4, true)]);
+    assert_eq!(game_session.playfield_state().is_interacting, false);
+    assert_eq!(game_session.map().is_marked[4], true);
+  }
+
+  #[test]
+  fn toggles_marks_directly() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    let outcome = game_session.execute(Command::ToggleMark(10)).unwrap();
+
+    assert_eq!(outcome.events, vec![GameEvent::MarkToggled(10, true)]);
+    assert_eq!(game_session.map().is_marked[10], true);
+    assert_eq!(game_session.execute(Command::ToggleMark(0)).unwrap().events, vec![]);
+  }
+
+  #[test]
+  fn marking_directly_ends_interaction() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    game_session.apply(Input::Action).unwrap();
+    game_session.execute(Command::ToggleMark(10)).unwrap();
+
+    assert!(!game_session.playfield_state().is_interacting);
+  }
+
+  #[test]
+  fn ignores_marking_into_a_wall() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    game_session.apply(Input::Action).unwrap();
+    let outcome = game_session.apply(Input::Up).unwrap();
+
+    assert_eq!(outcome.events, vec![]);
+    assert_eq!(outcome.validation, MapValidation::Valid);
+    assert_eq!(game_session.snapshot().history, vec![Command::Input(Input::Action)]);
+  }
+
+  #[test]
+  fn rejects_invalid_maps() {
+    match GameSession::from_map(Map::new(), 0) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Uninitialized goal")
+    }
+  }
+
+  #[test]
+  fn reports_loss_and_stops() {
+    let mut map = open_map();
+    map.is_snake[1] = true;
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    let outcome = game_session.apply(Input::Right).unwrap();
+
+    assert_eq!(outcome.validation, MapValidation::Lost);
+    assert_eq!(outcome.events.last(), Some(&GameEvent::SnakeBitten(1)));
+
+    let outcome = game_session.apply(Input::Down).unwrap();
+
+    assert_eq!(outcome.events, vec![]);
+    assert_eq!(game_session.map().player_location.array_index(), 1);
+    assert_eq!(game_session.snapshot().history.len(), 1);
+  }
+
+  #[test]
+  fn reports_win() {
+    let mut map = open_map();
+    map.goal_location.set_array_index(1, &map.size);
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    let outcome = game_session.apply(Input::Right).unwrap();
+
+    assert_eq!(outcome.validation, MapValidation::Won);
+    assert_eq!(outcome.events.last(), Some(&GameEvent::GoalReached(1)));
+  }
+
+  #[test]
+  fn replays_history() {
+    let parameters = GameParameters::new(MapSize::from(8, 8).unwrap(), 4);
+
+    match GameSession::new(parameters, 7) {
+      Ok(mut game_session) => {
+        game_session.apply(Input::Right).unwrap();
+        game_session.apply(Input::Down).unwrap();
+        game_session.apply(Input::Action).unwrap();
+        game_session.apply(Input::Left).unwrap();
+        game_session.execute(Command::ToggleMark(20)).unwrap();
+
+        match GameSession::new(parameters, 7) {
+          Ok(mut replayed_session) => {
+            for command in game_session.snapshot().history {
+              replayed_session.execute(command).unwrap();
+            }
+
+            assert_eq!(replayed_session.snapshot(), game_session.snapshot());
+          },
+
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
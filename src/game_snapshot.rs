@@ -0,0 +1,16 @@
+use crate::{
+  GameParameters,
+  Command,
+  Map,
+  MapValidation
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GameSnapshot {
+  pub parameters: GameParameters,
+  pub seed: u64,
+  pub history: Vec<Command>,
+  pub map: Map,
+  pub is_interacting: bool,
+  pub validation: MapValidation
+}
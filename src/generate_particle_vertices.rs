@@ -0,0 +1,43 @@
+use crate::{
+  Vector2,
+  UvRect
+};
+
+pub fn generate_particle_vertices(position: Vector2, size: f32, uv_rect: &UvRect, color: [f32; 4]) -> [f32; 32] {
+  let half_size = size / 2.0;
+  let corners = [
+    (position.x - half_size, position.y + half_size, uv_rect.left,  uv_rect.top),
+    (position.x + half_size, position.y + half_size, uv_rect.right, uv_rect.top),
+    (position.x + half_size, position.y - half_size, uv_rect.right, uv_rect.bottom),
+    (position.x - half_size, position.y - half_size, uv_rect.left,  uv_rect.bottom)
+  ];
+
+  let mut vertices = [0.0; 32];
+  for (index, (x, y, u, v)) in corners.into_iter().enumerate() {
+    vertices[index * 8..index * 8 + 8].copy_from_slice(&[x, y, u, v, color[0], color[1], color[2], color[3]]);
+  }
+
+  vertices
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Vector2,
+    UvRect
+  };
+
+  use super::generate_particle_vertices;
+
+  #[test]
+  fn centers_a_coloured_quad_on_the_particle() {
+    let vertices = generate_particle_vertices(Vector2 { x: 10.0, y: -4.0 }, 4.0, &UvRect::full(), [1.0, 0.5, 0.25, 0.75]);
+
+    assert_eq!(vertices, [
+      8.0, -2.0, 0.0, 0.0, 1.0, 0.5, 0.25, 0.75,
+      12.0, -2.0, 1.0, 0.0, 1.0, 0.5, 0.25, 0.75,
+      12.0, -6.0, 1.0, 1.0, 1.0, 0.5, 0.25, 0.75,
+      8.0, -6.0, 0.0, 1.0, 1.0, 0.5, 0.25, 0.75
+    ]);
+  }
+}
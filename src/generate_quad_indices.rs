@@ -0,0 +1,24 @@
+pub fn generate_quad_indices(quad_count: usize) -> Vec<u32> {
+  let mut indices = Vec::with_capacity(quad_count * 6);
+
+  for quad_index in 0..quad_count as u32 {
+    let first_vertex = quad_index * 4;
+    indices.extend_from_slice(&[
+      first_vertex, first_vertex + 1, first_vertex + 2,
+      first_vertex, first_vertex + 2, first_vertex + 3
+    ]);
+  }
+
+  indices
+}
+
+#[cfg(test)]
+mod testing {
+  use super::generate_quad_indices;
+
+  #[test]
+  fn generates_two_triangles_per_quad() {
+    assert_eq!(generate_quad_indices(0), Vec::<u32>::new());
+    assert_eq!(generate_quad_indices(2), vec![0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]);
+  }
+}
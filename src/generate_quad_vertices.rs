@@ -0,0 +1,73 @@
+use crate::{
+  Matrix4,
+  Vector4,
+  UvRect,
+  multiply_matrix_vector
+};
+
+pub fn generate_quad_vertices(model_matrix: &Matrix4, width: f32, height: f32, uv_rect: &UvRect) -> [f32; 16] {
+  let corners = [
+    (-(width / 2.0),  (height / 2.0), uv_rect.left,  uv_rect.top),
+    ( (width / 2.0),  (height / 2.0), uv_rect.right, uv_rect.top),
+    ( (width / 2.0), -(height / 2.0), uv_rect.right, uv_rect.bottom),
+    (-(width / 2.0), -(height / 2.0), uv_rect.left,  uv_rect.bottom)
+  ];
+
+  let mut vertices = [0.0; 16];
+  for (index, (x, y, u, v)) in corners.into_iter().enumerate() {
+    let position = multiply_matrix_vector(model_matrix, &Vector4::new(x, y, 0.0, 1.0));
+    vertices[index * 4] = position.x;
+    vertices[index * 4 + 1] = position.y;
+    vertices[index * 4 + 2] = u;
+    vertices[index * 4 + 3] = v;
+  }
+
+  vertices
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Matrix4,
+    UvRect,
+    generate_vertex_data
+  };
+
+  use super::generate_quad_vertices;
+
+  #[test]
+  fn matches_the_static_quad_at_the_origin() {
+    let vertices = generate_quad_vertices(&Matrix4::identity(), 64.0, 32.0, &UvRect::full());
+    assert_eq!(vertices.to_vec(), generate_vertex_data(64, 32, &UvRect::full()));
+  }
+
+  #[test]
+  fn applies_the_model_translation() {
+    let mut model_matrix = Matrix4::identity();
+    model_matrix.x.w = 100.0;
+    model_matrix.y.w = -50.0;
+
+    let vertices = generate_quad_vertices(&model_matrix, 64.0, 64.0, &UvRect::full().sub_rect(32, 0, 32, 64, 64, 64));
+
+    assert_eq!(vertices, [
+      68.0, -18.0, 0.5, 0.0,
+      132.0, -18.0, 1.0, 0.0,
+      132.0, -82.0, 1.0, 1.0,
+      68.0, -82.0, 0.5, 1.0
+    ]);
+  }
+
+  #[test]
+  fn applies_the_model_rotation() {
+    let mut model_matrix = Matrix4::identity();
+    model_matrix.x.x = 0.0;
+    model_matrix.x.y = -1.0;
+    model_matrix.y.x = 1.0;
+    model_matrix.y.y = 0.0;
+
+    let vertices = generate_quad_vertices(&model_matrix, 2.0, 2.0, &UvRect::full());
+
+    assert_eq!(&vertices[0..4], &[-1.0, -1.0, 0.0, 0.0]);
+    assert_eq!(&vertices[4..8], &[-1.0, 1.0, 1.0, 0.0]);
+  }
+}
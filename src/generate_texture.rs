@@ -3,6 +3,15 @@ pub fn generate_texture(width: gl::types::GLint, height: gl::types::GLint, pixel
   
   unsafe {
     gl::GenTextures(1, &mut handle);
+  }
+
+  upload_texture(handle, width, height, pixel_data);
+
+  handle
+}
+
+pub fn upload_texture(handle: gl::types::GLuint, width: gl::types::GLint, height: gl::types::GLint, pixel_data: &[u8]) {
+  unsafe {
     gl::BindTexture(gl::TEXTURE_2D, handle);
     
     gl::TexImage2D(
@@ -20,6 +29,38 @@ pub fn generate_texture(width: gl::types::GLint, height: gl::types::GLint, pixel
     gl::GenerateMipmap(gl::TEXTURE_2D);
     gl::BindTexture(gl::TEXTURE_2D, 0);
   }
+}
 
-  handle
-}
\ No newline at end of file
+pub fn upload_texture_region(handle: gl::types::GLuint, x: gl::types::GLint, y: gl::types::GLint, width: gl::types::GLint, height: gl::types::GLint, pixel_data: &[u8]) {
+  unsafe {
+    gl::BindTexture(gl::TEXTURE_2D, handle);
+
+    gl::TexSubImage2D(
+      gl::TEXTURE_2D,
+      0,
+      x,
+      y,
+      width,
+      height,
+      gl::RGBA,
+      gl::UNSIGNED_BYTE,
+      pixel_data.as_ptr() as *const gl::types::GLvoid
+    );
+
+    gl::GenerateMipmap(gl::TEXTURE_2D);
+    gl::BindTexture(gl::TEXTURE_2D, 0);
+  }
+}
+
+pub fn disable_mipmaps(handle: gl::types::GLuint) {
+  unsafe {
+    gl::BindTexture(gl::TEXTURE_2D, handle);
+
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as gl::types::GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as gl::types::GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as gl::types::GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as gl::types::GLint);
+
+    gl::BindTexture(gl::TEXTURE_2D, 0);
+  }
+}
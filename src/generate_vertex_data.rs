@@ -1,17 +1,10 @@
-pub fn generate_vertex_data(width: u32, height: u32) -> Vec<f32> {
-  vec![
-    -(width as f32 / 2.0),  (height as f32 / 2.0), 0.0, 0.0,
-     (width as f32 / 2.0),  (height as f32 / 2.0), 1.0, 0.0,
-     (width as f32 / 2.0), -(height as f32 / 2.0), 1.0, 1.0,
-    -(width as f32 / 2.0), -(height as f32 / 2.0), 0.0, 1.0
-  ]
-}
+use crate::UvRect;
 
-pub fn generate_animation_vertex_data(width: u32, height: u32, num_frames: u32) -> Vec<f32> {
+pub fn generate_vertex_data(width: u32, height: u32, uv_rect: &UvRect) -> Vec<f32> {
   vec![
-    -(width as f32 / 2.0),  (height as f32 / 2.0), 0.0,                     0.0,
-     (width as f32 / 2.0),  (height as f32 / 2.0), 1.0 / num_frames as f32, 0.0,
-     (width as f32 / 2.0), -(height as f32 / 2.0), 1.0 / num_frames as f32, 1.0,
-    -(width as f32 / 2.0), -(height as f32 / 2.0), 0.0,                     1.0
+    -(width as f32 / 2.0),  (height as f32 / 2.0), uv_rect.left,  uv_rect.top,
+     (width as f32 / 2.0),  (height as f32 / 2.0), uv_rect.right, uv_rect.top,
+     (width as f32 / 2.0), -(height as f32 / 2.0), uv_rect.right, uv_rect.bottom,
+    -(width as f32 / 2.0), -(height as f32 / 2.0), uv_rect.left,  uv_rect.bottom
   ]
 }
\ No newline at end of file
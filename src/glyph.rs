@@ -0,0 +1,7 @@
+use crate::AtlasPlacement;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Glyph {
+  pub placement: AtlasPlacement,
+  pub advance: u32
+}
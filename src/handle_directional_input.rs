@@ -1,18 +1,17 @@
 use crate::{
   PlayfieldState,
   Direction,
+  GameEvent,
   interact,
   move_player
 };
 
-pub fn handle_directional_input(playfield_state: &mut PlayfieldState, direction: Direction) -> Result<(), String> {
+pub fn handle_directional_input(playfield_state: &mut PlayfieldState, direction: Direction) -> Result<Vec<GameEvent>, String> {
   if playfield_state.is_interacting {
-    interact(playfield_state, direction)?;
+    interact(playfield_state, direction)
   } else {
-    move_player(&mut playfield_state.map, direction);
+    Ok(move_player(&mut playfield_state.map, direction))
   }
-
-  Ok(())
 }
 
 #[cfg(test)]
@@ -1,27 +1,73 @@
 use crate::{
   MessageQueue,
-  PlayfieldState,
+  GameSession,
   Message,
   Input,
-  handle_directional_input,
+  Command,
   Direction,
-  Scenes
+  PointerButton,
+  Scenes,
+  find_route,
+  is_safe_step,
+  world_to_coordinate,
+  tile_size::TILE_SIZE
 };
 
-pub fn handle_playfield_input(message_queue: &mut MessageQueue, playfield_state: &mut PlayfieldState) -> Result<(), String> {
+pub fn handle_playfield_input(message_queue: &mut MessageQueue, game_session: &mut GameSession) -> Result<(), String> {
   let mut canceled = false;
+  let mut events = Vec::new();
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = message { match input {
-      Input::Up => handle_directional_input(playfield_state, Direction::North)?,
-      Input::Left => handle_directional_input(playfield_state, Direction::West)?,
-      Input::Right => handle_directional_input(playfield_state, Direction::East)?,
-      Input::Down => handle_directional_input(playfield_state, Direction::South)?,
-      Input::Cancel => canceled = true,
-      Input::Action => playfield_state.is_interacting = !playfield_state.is_interacting,
-      
+    match message {
+      Message::PlayerInput(Input::Cancel) => canceled = true,
+      Message::PlayerInput(input) => events.append(&mut game_session.apply(*input)?.events),
+
+      Message::RepeatedInput(input) => {
+        let direction = match input {
+          Input::Up => Direction::North,
+          Input::Left => Direction::West,
+          Input::Right => Direction::East,
+          Input::Down => Direction::South,
+          _ => continue
+        };
+
+        if !game_session.playfield_state().is_interacting && is_safe_step(game_session.map(), direction) {
+          events.append(&mut game_session.apply(*input)?.events);
+        }
+      },
+
+      Message::PointerPressed(world_x, world_y, button) => {
+        let target = match world_to_coordinate(*world_x, *world_y, &game_session.map().size, TILE_SIZE, TILE_SIZE) {
+          Some(target) => target,
+          None => continue
+        };
+
+        match button {
+          PointerButton::Left => if let Some(route) = find_route(game_session.map(), &target) {
+            if game_session.playfield_state().is_interacting { events.append(&mut game_session.apply(Input::Action)?.events) }
+
+            for direction in route {
+              let input = match direction {
+                Direction::North => Input::Up,
+                Direction::West => Input::Left,
+                Direction::East => Input::Right,
+                Direction::South => Input::Down
+              };
+
+              events.append(&mut game_session.apply(input)?.events);
+            }
+          },
+
+          PointerButton::Right => events.append(&mut game_session.execute(Command::ToggleMark(target.array_index()))?.events)
+        }
+      },
+
       _ => {}
-    }}
+    }
+  }
+
+  for event in events {
+    message_queue.post(Message::GameEvent(event));
   }
 
   if canceled { message_queue.post(Message::RequestScene(Scenes::Pause)) }
@@ -33,10 +79,13 @@ pub fn handle_playfield_input(message_queue: &mut MessageQueue, playfield_state:
 mod testing {
   use crate::{
     MessageQueue,
-    PlayfieldState,
+    GameSession,
+    Map,
     Message,
     Input,
-    Scenes
+    Scenes,
+    GameEvent,
+    PointerButton
   };
 
   use super::handle_playfield_input;
@@ -44,19 +93,22 @@ mod testing {
   #[test]
   fn moving() {
     let mut message_queue = MessageQueue::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut map = Map::new();
 
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    match handle_playfield_input(&mut message_queue, &mut playfield_state) {
-      Ok(_) => assert_eq!(playfield_state.map.player_location.array_index(), 1),
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => assert_eq!(game_session.map().player_location.array_index(), 1),
       Err(error) => panic!("Unexpected error: {}", error)
     }
   }
@@ -64,26 +116,27 @@ mod testing {
   #[test]
   fn interacting() {
     let mut message_queue = MessageQueue::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut map = Map::new();
 
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
 
-    playfield_state.is_interacting = true;
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
+    message_queue.post(Message::PlayerInput(Input::Action));
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    assert_eq!(playfield_state.map.is_marked[1], false);
-    assert_eq!(playfield_state.is_interacting, true);
+    assert_eq!(game_session.map().is_marked[1], false);
 
-    match handle_playfield_input(&mut message_queue, &mut playfield_state) {
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
       Ok(_) => {
-        assert_eq!(playfield_state.map.is_marked[1], true);
-        assert_eq!(playfield_state.is_interacting, false);
+        assert_eq!(game_session.map().is_marked[1], true);
+        assert_eq!(game_session.playfield_state().is_interacting, false);
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -93,12 +146,12 @@ mod testing {
   #[test]
   fn open_pause_menu() {
     let mut message_queue = MessageQueue::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut game_session = GameSession::empty(0);
 
     message_queue.post(Message::PlayerInput(Input::Cancel));
     message_queue.swap_buffers();
 
-    match handle_playfield_input(&mut message_queue, &mut playfield_state) {
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
       Ok(_) => {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
@@ -112,14 +165,185 @@ mod testing {
   #[test]
   fn start_interaction() {
     let mut message_queue = MessageQueue::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.is_snake = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     message_queue.post(Message::PlayerInput(Input::Action));
     message_queue.swap_buffers();
 
-    match handle_playfield_input(&mut message_queue, &mut playfield_state) {
-      Ok(_) => assert_eq!(playfield_state.is_interacting, true),
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => assert_eq!(game_session.playfield_state().is_interacting, true),
       Err(error) => panic!("Unexpected error: {}", error)
     }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn posts_gameplay_events() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::PlayerInput(Input::Right));
+    message_queue.post(Message::PlayerInput(Input::Action));
+    message_queue.post(Message::PlayerInput(Input::Down));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![
+          Message::GameEvent(GameEvent::PlayerMoved(0, 1)),
+          Message::GameEvent(GameEvent::TileRevealed(1)),
+          Message::GameEvent(GameEvent::MarkToggled(5, true))
+        ]);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn left_click_walks_an_explored_route() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::PointerPressed(-32, -32, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => assert_eq!(game_session.map().player_location.array_index(), 5),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn left_click_ignores_unreachable_tiles() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_explored[0] = true;
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::PointerPressed(16, 16, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => assert_eq!(game_session.map().player_location.array_index(), 0),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn right_click_toggles_marks() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_explored[0] = true;
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::PointerPressed(32, 32, PointerButton::Right));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => {
+        message_queue.swap_buffers();
+        assert_eq!(game_session.map().is_marked[15], true);
+        assert_eq!(message_queue.messages(), &vec![Message::GameEvent(GameEvent::MarkToggled(15, true))]);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn repeated_moves_stop_before_unexplored_tiles() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![true, true, false, false, false, false, false, false, false, false, false, false, false, false, false, false];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::RepeatedInput(Input::Right));
+    message_queue.post(Message::RepeatedInput(Input::Right));
+    message_queue.post(Message::RepeatedInput(Input::Right));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => {
+        assert_eq!(game_session.map().player_location.array_index(), 1);
+        assert_eq!(game_session.map().is_explored[2], false);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn repeated_moves_stop_before_marked_tiles() {
+    let mut message_queue = MessageQueue::new();
+    let mut map = Map::new();
+
+    map.goal_location.set_array_index(15, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![true; 16];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+    map.is_marked[8] = true;
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
+
+    message_queue.post(Message::RepeatedInput(Input::Down));
+    message_queue.post(Message::RepeatedInput(Input::Down));
+    message_queue.swap_buffers();
+
+    match handle_playfield_input(&mut message_queue, &mut game_session) {
+      Ok(_) => assert_eq!(game_session.map().player_location.array_index(), 4),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use crate::GameSession;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct HudText {
+  pub score: String,
+  pub snakes: String,
+  pub timer: String,
+  pub seed: String,
+  pub is_marking: bool
+}
+
+impl HudText {
+  pub fn from_session(game_session: &GameSession, elapsed: Duration) -> Self {
+    let map = game_session.map();
+    let num_marks = map.is_marked.iter().filter(| is_marked | **is_marked).count();
+    let snakes_remaining = game_session.parameters().num_snakes as isize - num_marks as isize;
+
+    let seconds = elapsed.as_secs();
+    let timer = match seconds / 3600 {
+      0 => format!("Time {:02}:{:02}", seconds / 60, seconds % 60),
+      hours => format!("Time {}:{:02}:{:02}", hours, seconds / 60 % 60, seconds % 60)
+    };
+
+    Self {
+      score: format!("Score {}/{}", map.score.current(), map.score.maximum()),
+      snakes: format!("Snakes {}", snakes_remaining),
+      timer,
+      seed: format!("Seed {}", game_session.seed()),
+      is_marking: game_session.playfield_state().is_interacting
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+
+  use crate::{
+    GameSession,
+    Map,
+    Input
+  };
+
+  use super::HudText;
+
+  fn game_session() -> GameSession {
+    game_session_with_marks(&[4])
+  }
+
+  fn game_session_with_marks(marks: &[usize]) -> GameSession {
+    let mut map = Map::new();
+    map.is_snake = vec![false; 16];
+    map.is_snake[5] = true;
+    map.is_snake[10] = true;
+    map.is_marked = vec![false; 16];
+    for mark in marks {
+      map.is_marked[*mark] = true;
+    }
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.hint = vec![0; 16];
+    map.goal_location.set_array_index(15, &map.size);
+    *map.score.mut_current() = 3;
+    *map.score.mut_maximum() = 12;
+
+    GameSession::from_map(map, 1234).unwrap()
+  }
+
+  #[test]
+  fn describes_the_game() {
+    let hud_text = HudText::from_session(&game_session(), Duration::from_secs(83));
+
+    assert_eq!(hud_text, HudText {
+      score: "Score 3/12".to_string(),
+      snakes: "Snakes 1".to_string(),
+      timer: "Time 01:23".to_string(),
+      seed: "Seed 1234".to_string(),
+      is_marking: false
+    });
+  }
+
+  #[test]
+  fn shows_hours_on_long_games() {
+    let hud_text = HudText::from_session(&game_session(), Duration::from_secs(3723));
+
+    assert_eq!(hud_text.timer, "Time 1:02:03");
+  }
+
+  #[test]
+  fn reports_marking_mode() {
+    let mut game_session = game_session();
+    game_session.apply(Input::Action).unwrap();
+
+    assert_eq!(HudText::from_session(&game_session, Duration::ZERO).is_marking, true);
+  }
+
+  #[test]
+  fn counts_below_zero_when_over_marked() {
+    let game_session = game_session_with_marks(&(0..16).collect::<Vec<usize>>());
+
+    assert_eq!(HudText::from_session(&game_session, Duration::ZERO).snakes, "Snakes -14");
+  }
+}
@@ -0,0 +1,30 @@
+use log::LevelFilter;
+
+use crate::{
+  CommandLineOptions,
+  DataPaths,
+  Frontend,
+  GameLogger,
+  RotatingLogFile
+};
+
+const MAX_LOG_FILE_SIZE: u64 = 1024 * 1024;
+const NUM_LOG_FILE_BACKUPS: usize = 3;
+
+pub fn init_logging(options: &CommandLineOptions, data_paths: &DataPaths) -> Result<(), String> {
+  let mut filter = options.log_filter.clone();
+  if options.debug && !filter.has_target("scenes") { filter.set_target_level("scenes", LevelFilter::Debug) }
+
+  let log_file = match options.log_to_file {
+    true => Some(RotatingLogFile::open(&data_paths.log_file, MAX_LOG_FILE_SIZE, NUM_LOG_FILE_BACKUPS)?),
+    false => None
+  };
+
+  let max_level = filter.max_level();
+  let writes_to_console = options.frontend != Frontend::Terminal;
+
+  log::set_boxed_logger(Box::new(GameLogger::new(filter, writes_to_console, log_file))).map_err(| error | error.to_string())?;
+  log::set_max_level(max_level);
+
+  Ok(())
+}
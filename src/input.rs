@@ -6,5 +6,6 @@ pub enum Input {
   Down,
   Confirm,
   Cancel,
-  Action
+  Action,
+  Minimap
 }
\ No newline at end of file
@@ -0,0 +1,164 @@
+use sdl2::keyboard::Keycode;
+
+use crate::{
+  Input,
+  key_name
+};
+
+pub const BINDABLE_INPUTS: [Input; 8] = [
+  Input::Up,
+  Input::Left,
+  Input::Right,
+  Input::Down,
+  Input::Confirm,
+  Input::Cancel,
+  Input::Action,
+  Input::Minimap
+];
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InputBindings {
+  bindings: Vec<(Keycode, Input)>
+}
+
+impl InputBindings {
+  pub fn new() -> Self {
+    Self {
+      bindings: vec![
+        (Keycode::W, Input::Up),
+        (Keycode::Up, Input::Up),
+        (Keycode::A, Input::Left),
+        (Keycode::Left, Input::Left),
+        (Keycode::D, Input::Right),
+        (Keycode::Right, Input::Right),
+        (Keycode::S, Input::Down),
+        (Keycode::Down, Input::Down),
+        (Keycode::Return, Input::Confirm),
+        (Keycode::Escape, Input::Cancel),
+        (Keycode::Space, Input::Action),
+        (Keycode::Tab, Input::Minimap)
+      ]
+    }
+  }
+
+  pub fn empty() -> Self {
+    Self {
+      bindings: Vec::new()
+    }
+  }
+
+  pub fn input_for(&self, keycode: Keycode) -> Option<Input> {
+    self.bindings.iter().find(| (key, _) | *key == keycode).map(| (_, input) | *input)
+  }
+
+  pub fn keys_for(&self, input: Input) -> Vec<Keycode> {
+    self.bindings.iter().filter(| (_, bound_input) | *bound_input == input).map(| (key, _) | *key).collect()
+  }
+
+  pub fn bind(&mut self, keycode: Keycode, input: Input) -> Result<(), String> {
+    let name = match key_name(keycode) {
+      Some(name) => name,
+      None => return Err("That key cannot be bound".to_string())
+    };
+
+    if keycode == Keycode::Escape && input != Input::Cancel { return Err("Escape is reserved for Cancel".to_string()) }
+
+    match self.input_for(keycode) {
+      Some(bound_input) if bound_input == input => Ok(()),
+      Some(bound_input) => Err(format!("{} is already bound to {}", name, input_name(bound_input))),
+
+      None => {
+        self.bindings.push((keycode, input));
+        Ok(())
+      }
+    }
+  }
+
+  pub fn unbind(&mut self, keycode: Keycode, input: Input) -> Result<(), String> {
+    if keycode == Keycode::Escape && input == Input::Cancel { return Err("Escape is reserved for Cancel".to_string()) }
+    if self.keys_for(input).len() <= 1 { return Err(format!("{} needs at least one key", input_name(input))) }
+
+    self.bindings.retain(| (key, bound_input) | !(*key == keycode && *bound_input == input));
+    Ok(())
+  }
+}
+
+pub fn input_name(input: Input) -> &'static str {
+  match input {
+    Input::Up => "Up",
+    Input::Left => "Left",
+    Input::Right => "Right",
+    Input::Down => "Down",
+    Input::Confirm => "Confirm",
+    Input::Cancel => "Cancel",
+    Input::Action => "Action",
+    Input::Minimap => "Minimap"
+  }
+}
+
+pub fn input_from_name(name: &str) -> Option<Input> {
+  BINDABLE_INPUTS.iter().find(| input | input_name(**input) == name).copied()
+}
+
+#[cfg(test)]
+mod testing {
+  use sdl2::keyboard::Keycode;
+  use crate::Input;
+  use super::InputBindings;
+
+  #[test]
+  fn default_bindings_support_wasd_and_arrows() {
+    let input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.input_for(Keycode::W), Some(Input::Up));
+    assert_eq!(input_bindings.input_for(Keycode::Up), Some(Input::Up));
+    assert_eq!(input_bindings.input_for(Keycode::Q), None);
+    assert_eq!(input_bindings.keys_for(Input::Left), vec![Keycode::A, Keycode::Left]);
+  }
+
+  #[test]
+  fn binds_additional_keys() {
+    let mut input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.bind(Keycode::Kp8, Input::Up), Ok(()));
+    assert_eq!(input_bindings.input_for(Keycode::Kp8), Some(Input::Up));
+    assert_eq!(input_bindings.keys_for(Input::Up).len(), 3);
+  }
+
+  #[test]
+  fn refuses_conflicting_binding() {
+    let mut input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.bind(Keycode::W, Input::Action), Err("W is already bound to Up".to_string()));
+    assert_eq!(input_bindings.input_for(Keycode::W), Some(Input::Up));
+  }
+
+  #[test]
+  fn refuses_unnamed_keys() {
+    let mut input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.bind(Keycode::F12, Input::Action), Err("That key cannot be bound".to_string()));
+  }
+
+  #[test]
+  fn keeps_at_least_one_key_per_input() {
+    let mut input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.unbind(Keycode::W, Input::Up), Ok(()));
+    assert_eq!(input_bindings.input_for(Keycode::W), None);
+    assert_eq!(input_bindings.unbind(Keycode::Up, Input::Up), Err("Up needs at least one key".to_string()));
+    assert_eq!(input_bindings.input_for(Keycode::Up), Some(Input::Up));
+  }
+  #[test]
+  fn reserves_escape_for_cancel() {
+    let mut input_bindings = InputBindings::new();
+
+    assert_eq!(input_bindings.bind(Keycode::Q, Input::Cancel), Ok(()));
+    assert_eq!(input_bindings.unbind(Keycode::Escape, Input::Cancel), Err("Escape is reserved for Cancel".to_string()));
+    assert_eq!(input_bindings.unbind(Keycode::Q, Input::Cancel), Ok(()));
+    assert_eq!(input_bindings.input_for(Keycode::Escape), Some(Input::Cancel));
+
+    let mut input_bindings = InputBindings::empty();
+    assert_eq!(input_bindings.bind(Keycode::Escape, Input::Action), Err("Escape is reserved for Cancel".to_string()));
+  }
+}
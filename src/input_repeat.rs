@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::Input;
+
+pub struct InputRepeat {
+  held_input: Option<Input>,
+  time_until_repeat: Duration,
+  delay: Duration,
+  interval: Duration
+}
+
+impl InputRepeat {
+  pub fn new(delay: Duration, interval: Duration) -> Self {
+    Self {
+      held_input: None,
+      time_until_repeat: delay,
+      delay,
+      interval
+    }
+  }
+
+  pub fn set_timing(&mut self, delay: Duration, interval: Duration) {
+    self.delay = delay;
+    self.interval = interval;
+  }
+
+  pub fn press(&mut self, input: Input) {
+    self.held_input = Some(input);
+    self.time_until_repeat = self.delay;
+  }
+
+  pub fn release(&mut self, input: Input) {
+    if self.held_input == Some(input) { self.held_input = None }
+  }
+
+  pub fn release_all(&mut self) {
+    self.held_input = None;
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) -> Vec<Input> {
+    let mut repeated = Vec::new();
+
+    if let Some(input) = self.held_input {
+      let mut remaining = *deltatime;
+
+      while remaining >= self.time_until_repeat {
+        remaining -= self.time_until_repeat;
+        self.time_until_repeat = self.interval;
+        repeated.push(input);
+      }
+
+      self.time_until_repeat -= remaining;
+    }
+
+    repeated
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::Duration;
+  use crate::Input;
+  use super::InputRepeat;
+
+  #[test]
+  fn repeats_after_delay_then_at_interval() {
+    let mut input_repeat = InputRepeat::new(Duration::from_millis(300), Duration::from_millis(100));
+    input_repeat.press(Input::Up);
+
+    assert_eq!(input_repeat.update(&Duration::from_millis(250)), vec![]);
+    assert_eq!(input_repeat.update(&Duration::from_millis(50)), vec![Input::Up]);
+    assert_eq!(input_repeat.update(&Duration::from_millis(90)), vec![]);
+    assert_eq!(input_repeat.update(&Duration::from_millis(150)), vec![Input::Up, Input::Up]);
+  }
+
+  #[test]
+  fn stops_when_released() {
+    let mut input_repeat = InputRepeat::new(Duration::from_millis(300), Duration::from_millis(100));
+    input_repeat.press(Input::Left);
+    input_repeat.release(Input::Right);
+
+    assert_eq!(input_repeat.update(&Duration::from_millis(300)), vec![Input::Left]);
+
+    input_repeat.release(Input::Left);
+
+    assert_eq!(input_repeat.update(&Duration::from_millis(1000)), vec![]);
+  }
+
+  #[test]
+  fn pressing_again_restarts_the_delay() {
+    let mut input_repeat = InputRepeat::new(Duration::from_millis(300), Duration::from_millis(100));
+    input_repeat.press(Input::Down);
+    input_repeat.update(&Duration::from_millis(200));
+    input_repeat.press(Input::Right);
+
+    assert_eq!(input_repeat.update(&Duration::from_millis(200)), vec![]);
+    assert_eq!(input_repeat.update(&Duration::from_millis(100)), vec![Input::Right]);
+  }
+}
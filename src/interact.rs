@@ -1,10 +1,11 @@
 use crate::{
   PlayfieldState,
   Direction,
-  Coordinate
+  Coordinate,
+  GameEvent
 };
 
-pub fn interact(playfield_state: &mut PlayfieldState, direction: Direction) -> Result<(), String> {
+pub fn interact(playfield_state: &mut PlayfieldState, direction: Direction) -> Result<Vec<GameEvent>, String> {
   if playfield_state.map.is_marked.len() != playfield_state.map.size.array_length() { return Err("Invalid is_marked".to_string()) }
 
   match direction {
@@ -24,14 +25,15 @@ pub fn interact(playfield_state: &mut PlayfieldState, direction: Direction) -> R
   playfield_state.map.is_marked[target.array_index()] = !playfield_state.map.is_marked[target.array_index()];
   playfield_state.is_interacting = false;
 
-  Ok(())
+  Ok(vec![GameEvent::MarkToggled(target.array_index(), playfield_state.map.is_marked[target.array_index()])])
 }
 
 #[cfg(test)]
 mod testing {
   use crate::{
     PlayfieldState,
-    Direction
+    Direction,
+    GameEvent
   };
 
   use super::interact;
@@ -45,7 +47,7 @@ mod testing {
     playfield_state.map.player_location.set_y(1, &playfield_state.map.size);
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => panic!("Expected to fail"),
+      Ok(_) => panic!("Expected to fail"),
       Err(error) => assert_eq!(error, "Invalid is_marked")
     }
   }
@@ -57,7 +59,7 @@ mod testing {
     let direction = Direction::North;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => panic!("Expected to fail"),
+      Ok(_) => panic!("Expected to fail"),
       Err(error) => assert_eq!(error, "Blocked by north wall")
     }
   }
@@ -73,7 +75,9 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(events) => {
+        assert_eq!(events, vec![GameEvent::MarkToggled(1, true)]);
+
         assert_eq!(playfield_state.map.is_marked, vec![
           false,  true, false, false,
           false, false, false, false,
@@ -100,7 +104,9 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(events) => {
+        assert_eq!(events, vec![GameEvent::MarkToggled(1, false)]);
+
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false, false, false,
@@ -123,7 +129,7 @@ mod testing {
     playfield_state.map.is_marked = vec![false; 16];
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => panic!("Expected to fail"),
+      Ok(_) => panic!("Expected to fail"),
       Err(error) => assert_eq!(error, "Blocked by west wall")
     }
   }
@@ -139,7 +145,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
            true, false, false, false,
@@ -166,7 +172,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false, false, false,
@@ -190,7 +196,7 @@ mod testing {
     playfield_state.map.player_location.set_x(3, &playfield_state.map.size);
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => panic!("Expected to fail"),
+      Ok(_) => panic!("Expected to fail"),
       Err(error) => assert_eq!(error, "Blocked by east wall")
     }
   }
@@ -206,7 +212,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false,  true, false,
@@ -233,7 +239,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false, false, false,
@@ -257,7 +263,7 @@ mod testing {
     playfield_state.map.player_location.set_y(3, &playfield_state.map.size);
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => panic!("Expected to fail"),
+      Ok(_) => panic!("Expected to fail"),
       Err(error) => assert_eq!(error, "Blocked by south wall")
     }
   }
@@ -273,7 +279,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false, false, false,
@@ -300,7 +306,7 @@ mod testing {
     playfield_state.is_interacting = true;
 
     match interact(&mut playfield_state, direction) {
-      Ok(()) => {
+      Ok(_) => {
         assert_eq!(playfield_state.map.is_marked, vec![
           false, false, false, false,
           false, false, false, false,
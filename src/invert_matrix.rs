@@ -0,0 +1,91 @@
+use crate::{
+  Matrix4,
+  Vector4
+};
+
+pub fn invert_matrix(matrix: &Matrix4) -> Option<Matrix4> {
+  let mut rows = [
+    [matrix.x.x, matrix.x.y, matrix.x.z, matrix.x.w, 1.0, 0.0, 0.0, 0.0],
+    [matrix.y.x, matrix.y.y, matrix.y.z, matrix.y.w, 0.0, 1.0, 0.0, 0.0],
+    [matrix.z.x, matrix.z.y, matrix.z.z, matrix.z.w, 0.0, 0.0, 1.0, 0.0],
+    [matrix.w.x, matrix.w.y, matrix.w.z, matrix.w.w, 0.0, 0.0, 0.0, 1.0]
+  ];
+
+  for column in 0..4 {
+    let mut pivot = column;
+    for row in column + 1..4 {
+      if rows[row][column].abs() > rows[pivot][column].abs() { pivot = row }
+    }
+
+    if rows[pivot][column].abs() < f32::EPSILON { return None }
+    rows.swap(column, pivot);
+
+    let pivot_value = rows[column][column];
+    for value in rows[column].iter_mut() { *value /= pivot_value }
+
+    for row in 0..4 {
+      if row == column { continue; }
+
+      let factor = rows[row][column];
+      let pivot = rows[column];
+      for (value, pivot_value) in rows[row].iter_mut().zip(pivot) {
+        *value -= factor * pivot_value;
+      }
+    }
+  }
+
+  let row = | values: &[f32; 8] | Vector4::new(values[4], values[5], values[6], values[7]);
+
+  Some(
+    Matrix4 {
+      x: row(&rows[0]),
+      y: row(&rows[1]),
+      z: row(&rows[2]),
+      w: row(&rows[3])
+    }
+  )
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Matrix4,
+    calculate_projection_matrix,
+    multiply_matrices
+  };
+
+  use super::invert_matrix;
+
+  #[test]
+  fn inverts_identity() {
+    assert_eq!(invert_matrix(&Matrix4::identity()), Some(Matrix4::identity()));
+  }
+
+  #[test]
+  fn inverse_undoes_projection() {
+    let projection = calculate_projection_matrix(-800.0, 800.0, 450.0, -450.0, 1.0, -1.0);
+
+    match invert_matrix(&projection) {
+      Some(inverse) => {
+        let product = multiply_matrices(&projection, &inverse);
+        let identity = Matrix4::identity();
+
+        assert!((product.x.x - identity.x.x).abs() < 0.0001);
+        assert!((product.y.y - identity.y.y).abs() < 0.0001);
+        assert!((product.z.z - identity.z.z).abs() < 0.0001);
+        assert!(product.x.w.abs() < 0.0001);
+        assert!(product.y.w.abs() < 0.0001);
+      },
+
+      None => panic!("Expected an inverse")
+    }
+  }
+
+  #[test]
+  fn singular_matrix_has_no_inverse() {
+    let mut matrix = Matrix4::identity();
+    matrix.y.y = 0.0;
+
+    assert_eq!(invert_matrix(&matrix), None);
+  }
+}
@@ -0,0 +1,59 @@
+use crate::{
+  Map,
+  Direction,
+  Coordinate
+};
+
+pub fn is_safe_step(map: &Map, direction: Direction) -> bool {
+  let location = &map.player_location;
+
+  let target = match direction {
+    Direction::North if location.y() > 0 => Coordinate::from(location.x(), location.y() - 1, &map.size),
+    Direction::West if location.x() > 0 => Coordinate::from(location.x() - 1, location.y(), &map.size),
+    Direction::East if location.x() + 1 < map.size.width() => Coordinate::from(location.x() + 1, location.y(), &map.size),
+    Direction::South if location.y() + 1 < map.size.height() => Coordinate::from(location.x(), location.y() + 1, &map.size),
+    _ => return false
+  };
+
+  map.is_explored[target.array_index()] && !map.is_marked[target.array_index()]
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Map,
+    Direction
+  };
+
+  use super::is_safe_step;
+
+  fn test_map() -> Map {
+    let mut map = Map::new();
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_explored[0] = true;
+    map.is_explored[1] = true;
+    map.is_explored[4] = true;
+    map.is_marked[4] = true;
+
+    map
+  }
+
+  #[test]
+  fn explored_tiles_are_safe() {
+    assert!(is_safe_step(&test_map(), Direction::East));
+  }
+
+  #[test]
+  fn unexplored_marked_and_outside_tiles_are_not() {
+    let mut map = test_map();
+
+    assert!(!is_safe_step(&map, Direction::South));
+    assert!(!is_safe_step(&map, Direction::North));
+    assert!(!is_safe_step(&map, Direction::West));
+
+    map.player_location.set_array_index(1, &map.size);
+
+    assert!(!is_safe_step(&map, Direction::East));
+  }
+}
@@ -0,0 +1,114 @@
+use sdl2::keyboard::Keycode;
+
+const KEY_NAMES: [(Keycode, &str); 60] = [
+  (Keycode::A, "A"),
+  (Keycode::B, "B"),
+  (Keycode::C, "C"),
+  (Keycode::D, "D"),
+  (Keycode::E, "E"),
+  (Keycode::F, "F"),
+  (Keycode::G, "G"),
+  (Keycode::H, "H"),
+  (Keycode::I, "I"),
+  (Keycode::J, "J"),
+  (Keycode::K, "K"),
+  (Keycode::L, "L"),
+  (Keycode::M, "M"),
+  (Keycode::N, "N"),
+  (Keycode::O, "O"),
+  (Keycode::P, "P"),
+  (Keycode::Q, "Q"),
+  (Keycode::R, "R"),
+  (Keycode::S, "S"),
+  (Keycode::T, "T"),
+  (Keycode::U, "U"),
+  (Keycode::V, "V"),
+  (Keycode::W, "W"),
+  (Keycode::X, "X"),
+  (Keycode::Y, "Y"),
+  (Keycode::Z, "Z"),
+  (Keycode::Num0, "0"),
+  (Keycode::Num1, "1"),
+  (Keycode::Num2, "2"),
+  (Keycode::Num3, "3"),
+  (Keycode::Num4, "4"),
+  (Keycode::Num5, "5"),
+  (Keycode::Num6, "6"),
+  (Keycode::Num7, "7"),
+  (Keycode::Num8, "8"),
+  (Keycode::Num9, "9"),
+  (Keycode::Up, "Up"),
+  (Keycode::Down, "Down"),
+  (Keycode::Left, "Left"),
+  (Keycode::Right, "Right"),
+  (Keycode::Return, "Return"),
+  (Keycode::Escape, "Escape"),
+  (Keycode::Space, "Space"),
+  (Keycode::Tab, "Tab"),
+  (Keycode::Backspace, "Backspace"),
+  (Keycode::LShift, "Left Shift"),
+  (Keycode::RShift, "Right Shift"),
+  (Keycode::LCtrl, "Left Ctrl"),
+  (Keycode::RCtrl, "Right Ctrl"),
+  (Keycode::Kp0, "Keypad 0"),
+  (Keycode::Kp1, "Keypad 1"),
+  (Keycode::Kp2, "Keypad 2"),
+  (Keycode::Kp3, "Keypad 3"),
+  (Keycode::Kp4, "Keypad 4"),
+  (Keycode::Kp5, "Keypad 5"),
+  (Keycode::Kp6, "Keypad 6"),
+  (Keycode::Kp7, "Keypad 7"),
+  (Keycode::Kp8, "Keypad 8"),
+  (Keycode::Kp9, "Keypad 9"),
+  (Keycode::KpEnter, "Keypad Enter")
+];
+
+pub fn key_name(keycode: Keycode) -> Option<&'static str> {
+  KEY_NAMES.iter().find(| (key, _) | *key == keycode).map(| (_, name) | *name)
+}
+
+pub fn key_from_name(name: &str) -> Option<Keycode> {
+  KEY_NAMES.iter().find(| (_, key_name) | *key_name == name).map(| (key, _) | *key)
+}
+
+pub fn produces_text(keycode: Keycode) -> bool {
+  match key_name(keycode) {
+    Some(name) => name.chars().count() == 1 || name == "Space" || (name.starts_with("Keypad ") && name != "Keypad Enter"),
+    None => false
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use sdl2::keyboard::Keycode;
+
+  use super::{
+    key_name,
+    key_from_name,
+    produces_text
+  };
+
+  #[test]
+  fn names_round_trip() {
+    assert_eq!(key_name(Keycode::W), Some("W"));
+    assert_eq!(key_name(Keycode::Kp8), Some("Keypad 8"));
+    assert_eq!(key_from_name("Left Shift"), Some(Keycode::LShift));
+  }
+
+  #[test]
+  fn unknown_keys_have_no_name() {
+    assert_eq!(key_name(Keycode::F12), None);
+    assert_eq!(key_from_name("Hyper"), None);
+  }
+
+  #[test]
+  fn recognizes_text_keys() {
+    assert!(produces_text(Keycode::Q));
+    assert!(produces_text(Keycode::Num7));
+    assert!(produces_text(Keycode::Space));
+    assert!(produces_text(Keycode::Kp3));
+    assert!(!produces_text(Keycode::Return));
+    assert!(!produces_text(Keycode::KpEnter));
+    assert!(!produces_text(Keycode::Escape));
+  }
+}
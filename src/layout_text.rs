@@ -0,0 +1,132 @@
+use crate::{
+  PlacedGlyph,
+  TextAlignment,
+  TextLayout
+};
+
+pub fn layout_text(text: &str, advance: &dyn Fn(char) -> f32, line_height: f32, alignment: TextAlignment, wrap_width: Option<f32>) -> TextLayout {
+  let width_of = | characters: &[char] | characters.iter().map(| character | advance(*character)).sum::<f32>();
+
+  let mut lines: Vec<Vec<char>> = Vec::new();
+  for paragraph in text.split('\n') {
+    let mut line: Vec<char> = Vec::new();
+
+    for (word_index, word) in paragraph.split(' ').enumerate() {
+      let word: Vec<char> = word.chars().collect();
+
+      if word_index > 0 {
+        let candidate_width = width_of(&line) + advance(' ') + width_of(&word);
+
+        if !line.is_empty() && !word.is_empty() && wrap_width.is_some_and(| wrap_width | candidate_width > wrap_width) {
+          lines.push(std::mem::take(&mut line));
+        } else {
+          line.push(' ');
+        }
+      }
+
+      line.extend(word);
+    }
+
+    lines.push(line);
+  }
+
+  let mut glyphs = Vec::new();
+  let mut width: f32 = 0.0;
+
+  for (line_index, line) in lines.iter().enumerate() {
+    let line_width = width_of(line);
+    width = width.max(line_width);
+
+    let mut x = match alignment {
+      TextAlignment::Left => 0.0,
+      TextAlignment::Center => -line_width / 2.0,
+      TextAlignment::Right => -line_width
+    };
+
+    for character in line {
+      glyphs.push(PlacedGlyph { character: *character, x, y: line_index as f32 * line_height });
+      x += advance(*character);
+    }
+  }
+
+  TextLayout {
+    glyphs,
+    width,
+    height: lines.len() as f32 * line_height
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    PlacedGlyph,
+    TextAlignment
+  };
+
+  use super::layout_text;
+
+  fn advance(character: char) -> f32 {
+    if character == ' ' { 5.0 } else { 10.0 }
+  }
+
+  #[test]
+  fn lays_out_a_single_line() {
+    let layout = layout_text("ab", &advance, 32.0, TextAlignment::Left, None);
+
+    assert_eq!(layout.glyphs, vec![
+      PlacedGlyph { character: 'a', x: 0.0, y: 0.0 },
+      PlacedGlyph { character: 'b', x: 10.0, y: 0.0 }
+    ]);
+    assert_eq!((layout.width, layout.height), (20.0, 32.0));
+  }
+
+  #[test]
+  fn keeps_spaces() {
+    let layout = layout_text("a b", &advance, 32.0, TextAlignment::Left, None);
+
+    assert_eq!(layout.glyphs[2], PlacedGlyph { character: 'b', x: 15.0, y: 0.0 });
+    assert_eq!(layout.width, 25.0);
+  }
+
+  #[test]
+  fn aligns_lines() {
+    let centered = layout_text("ab", &advance, 32.0, TextAlignment::Center, None);
+    assert_eq!(centered.glyphs[0].x, -10.0);
+
+    let right = layout_text("ab", &advance, 32.0, TextAlignment::Right, None);
+    assert_eq!(right.glyphs[0].x, -20.0);
+  }
+
+  #[test]
+  fn breaks_on_newlines() {
+    let layout = layout_text("ab\nc", &advance, 32.0, TextAlignment::Center, None);
+
+    assert_eq!(layout.glyphs[2], PlacedGlyph { character: 'c', x: -5.0, y: 32.0 });
+    assert_eq!((layout.width, layout.height), (20.0, 64.0));
+  }
+
+  #[test]
+  fn wraps_between_words() {
+    let layout = layout_text("ab cd ef", &advance, 32.0, TextAlignment::Left, Some(50.0));
+
+    let lines: Vec<(char, f32)> = layout.glyphs.iter().map(| glyph | (glyph.character, glyph.y)).collect();
+    assert_eq!(lines, vec![('a', 0.0), ('b', 0.0), (' ', 0.0), ('c', 0.0), ('d', 0.0), ('e', 32.0), ('f', 32.0)]);
+    assert_eq!(layout.width, 45.0);
+  }
+
+  #[test]
+  fn keeps_long_words_whole() {
+    let layout = layout_text("abcdef", &advance, 32.0, TextAlignment::Left, Some(20.0));
+
+    assert_eq!(layout.glyphs.len(), 6);
+    assert_eq!(layout.height, 32.0);
+  }
+
+  #[test]
+  fn lays_out_empty_text() {
+    let layout = layout_text("", &advance, 32.0, TextAlignment::Left, None);
+
+    assert_eq!(layout.glyphs, vec![]);
+    assert_eq!((layout.width, layout.height), (0.0, 32.0));
+  }
+}
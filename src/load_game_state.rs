@@ -1,7 +1,8 @@
 pub struct LoadGameState {
   pub saves_list_loaded: bool,
   pub saves: Vec<String>,
-  pub selected_menu_item_index: usize
+  pub selected_menu_item_index: usize,
+  pub item_widths: Vec<f32>
 }
 
 impl LoadGameState {
@@ -9,7 +10,8 @@ impl LoadGameState {
     Self {
       saves_list_loaded: false,
       saves: Vec::new(),
-      selected_menu_item_index: 0
+      selected_menu_item_index: 0,
+      item_widths: Vec::new()
     }
   }
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use crate::{
+  InputBindings,
+  serialize_input_bindings,
+  deserialize_input_bindings
+};
+
+pub fn load_input_bindings(file_path: &Path) -> Result<InputBindings, String> {
+  match std::fs::read_to_string(file_path) {
+    Ok(bindings_string) => deserialize_input_bindings(bindings_string),
+
+    Err(error) => {
+      if error.to_string() == "No such file or directory (os error 2)" {
+        let input_bindings = InputBindings::new();
+        std::fs::write(file_path, serialize_input_bindings(&input_bindings)).map_err(| error | error.to_string())?;
+        Ok(input_bindings)
+      } else {
+        Err(error.to_string())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+  use crate::InputBindings;
+  use super::load_input_bindings;
+
+  #[test]
+  fn creates_default_bindings_file() {
+    let bindings_file_path = Path::new("./creates_default_bindings_file_test_bindings.txt");
+
+    match load_input_bindings(bindings_file_path) {
+      Ok(input_bindings) => {
+        assert_eq!(input_bindings, InputBindings::new());
+
+        match std::fs::read_to_string(bindings_file_path) {
+          Ok(bindings_string) => assert!(bindings_string.contains("Up=W,Up")),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        if let Err(error) = std::fs::remove_file(bindings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
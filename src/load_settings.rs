@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use crate::{
+  Settings,
+  serialize_settings,
+  deserialize_settings
+};
+
+pub fn load_settings(file_path: &Path) -> Result<Settings, String> {
+  match std::fs::read_to_string(file_path) {
+    Ok(settings_string) => deserialize_settings(settings_string),
+
+    Err(error) => {
+      if error.to_string() == "No such file or directory (os error 2)" {
+        let settings = Settings::new();
+        std::fs::write(file_path, serialize_settings(&settings)).map_err(| error | error.to_string())?;
+        Ok(settings)
+      } else {
+        Err(error.to_string())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+  use crate::Settings;
+  use super::load_settings;
+
+  #[test]
+  fn creates_default_settings_file() {
+    let settings_file_path = Path::new("./creates_default_settings_file_test_settings.txt");
+
+    match load_settings(settings_file_path) {
+      Ok(settings) => {
+        assert_eq!(settings, Settings::new());
+
+        match std::fs::read_to_string(settings_file_path) {
+          Ok(settings_string) => assert!(settings_string.contains("resolution=1600x900")),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        if let Err(error) = std::fs::remove_file(settings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
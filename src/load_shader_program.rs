@@ -0,0 +1,16 @@
+use crate::{
+  Resources,
+  VertexShader,
+  FragmentShader,
+  ShaderProgram
+};
+
+pub fn load_shader_program(resources: &Resources, name: &str) -> Result<ShaderProgram, String> {
+  let vertex_shader_id = format!("shaders/{}_vertex_shader.glsl", name);
+  let fragment_shader_id = format!("shaders/{}_fragment_shader.glsl", name);
+
+  let vertex_shader = VertexShader::compile(&resources.read_to_string(&vertex_shader_id)?, &vertex_shader_id)?;
+  let fragment_shader = FragmentShader::compile(&resources.read_to_string(&fragment_shader_id)?, &fragment_shader_id)?;
+
+  ShaderProgram::new(vertex_shader, fragment_shader)
+}
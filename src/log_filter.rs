@@ -0,0 +1,71 @@
+use log::LevelFilter;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LogFilter {
+  pub default_level: LevelFilter,
+  pub target_levels: Vec<(String, LevelFilter)>
+}
+
+impl LogFilter {
+  pub fn new() -> Self {
+    Self {
+      default_level: LevelFilter::Warn,
+      target_levels: Vec::new()
+    }
+  }
+
+  pub fn set_target_level(&mut self, target: &str, level: LevelFilter) {
+    self.target_levels.retain(| (existing_target, _) | existing_target != target);
+    self.target_levels.push((target.to_string(), level));
+  }
+
+  pub fn has_target(&self, target: &str) -> bool {
+    self.target_levels.iter().any(| (existing_target, _) | existing_target == target)
+  }
+
+  pub fn level_for(&self, target: &str) -> LevelFilter {
+    self.target_levels
+      .iter()
+      .filter(| (filter_target, _) | target == filter_target || target.starts_with(&format!("{}::", filter_target)))
+      .max_by_key(| (filter_target, _) | filter_target.len())
+      .map(| (_, level) | *level)
+      .unwrap_or(self.default_level)
+  }
+
+  pub fn max_level(&self) -> LevelFilter {
+    self.target_levels
+      .iter()
+      .map(| (_, level) | *level)
+      .fold(self.default_level, Ord::max)
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use log::LevelFilter;
+
+  use super::LogFilter;
+
+  #[test]
+  fn uses_the_most_specific_target() {
+    let mut log_filter = LogFilter::new();
+    log_filter.set_target_level("run_terminal", LevelFilter::Debug);
+    log_filter.set_target_level("run_terminal::keys", LevelFilter::Trace);
+
+    assert_eq!(log_filter.level_for("main"), LevelFilter::Warn);
+    assert_eq!(log_filter.level_for("run_terminal"), LevelFilter::Debug);
+    assert_eq!(log_filter.level_for("run_terminal::keys"), LevelFilter::Trace);
+    assert_eq!(log_filter.level_for("run_terminals"), LevelFilter::Warn);
+    assert_eq!(log_filter.max_level(), LevelFilter::Trace);
+  }
+
+  #[test]
+  fn replaces_target_levels() {
+    let mut log_filter = LogFilter::new();
+    log_filter.set_target_level("scenes", LevelFilter::Debug);
+    log_filter.set_target_level("scenes", LevelFilter::Off);
+
+    assert_eq!(log_filter.target_levels, vec![("scenes".to_string(), LevelFilter::Off)]);
+    assert_eq!(log_filter.has_target("scenes"), true);
+  }
+}
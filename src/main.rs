@@ -1,14 +1,14 @@
 mod score;
 
 use std::{
-  path::Path,
+  cell::RefCell,
+  rc::Rc,
   time::{
     Duration,
     Instant
   }
 };
 
-use rand::SeedableRng;
 use score::Score;
 
 mod scenes;
@@ -22,7 +22,7 @@ use map::{
   Direction
 };
 
-use sdl2::{event::Event, keyboard::Keycode, pixels::Color, ttf::Font};
+use sdl2::{event::{Event, WindowEvent}, keyboard::Keycode, mouse::MouseButton, pixels::Color, rwops::RWops};
 
 mod input;
 use input::Input;
@@ -33,9 +33,24 @@ use message::Message;
 mod message_queue;
 use message_queue::MessageQueue;
 
+mod game_event;
+use game_event::GameEvent;
+
+mod subscriber;
+use subscriber::Subscriber;
+
+mod session_statistics;
+use session_statistics::SessionStatistics;
+
+mod print_session_statistics;
+use print_session_statistics::print_session_statistics;
+
 mod main_menu;
 use main_menu::update_main_menu;
 
+mod print_main_menu;
+use print_main_menu::print_main_menu;
+
 mod main_menu_state;
 use main_menu_state::MainMenuState;
 
@@ -54,6 +69,9 @@ use get_neighbors::{
 mod generate_snakes;
 use generate_snakes::generate_snakes;
 
+mod max_snakes;
+use max_snakes::max_snakes;
+
 mod find_lowest_value_index;
 use find_lowest_value_index::find_lowest_value_index_avoiding;
 
@@ -81,6 +99,18 @@ use new_game::{
 mod playfield_state;
 use playfield_state::PlayfieldState;
 
+mod game_parameters;
+use game_parameters::GameParameters;
+
+mod outcome;
+use outcome::Outcome;
+
+mod game_snapshot;
+use game_snapshot::GameSnapshot;
+
+mod game_session;
+use game_session::GameSession;
+
 mod move_player;
 use move_player::move_player;
 
@@ -127,10 +157,7 @@ mod print_settings;
 use print_settings::print_settings;
 
 mod generate_vertex_data;
-use generate_vertex_data::{
-  generate_vertex_data,
-  generate_animation_vertex_data
-};
+use generate_vertex_data::generate_vertex_data;
 
 mod calculate_projection_matrix;
 use calculate_projection_matrix::calculate_projection_matrix;
@@ -156,6 +183,9 @@ use load_saves_list::load_saves_list;
 mod print_save_game;
 use print_save_game::print_save_game;
 
+mod print_add_high_score;
+use print_add_high_score::print_add_high_score;
+
 mod validate_high_scores_file;
 use validate_high_scores_file::validate_high_scores_file;
 
@@ -217,19 +247,36 @@ mod shader_program;
 use shader_program::ShaderProgram;
 
 mod generate_texture;
-use generate_texture::generate_texture;
+use generate_texture::{
+  generate_texture,
+  upload_texture,
+  upload_texture_region,
+  disable_mipmaps
+};
 
 mod render_sprite;
 use render_sprite::render_sprite;
 
+mod resolve_asset_path;
+use resolve_asset_path::resolve_asset_path;
+
+mod embedded_assets;
+use embedded_assets::embedded_asset;
+
+mod resources;
+use resources::Resources;
+
+mod asset_watcher;
+use asset_watcher::AssetWatcher;
+
+mod load_shader_program;
+use load_shader_program::load_shader_program;
+
 mod sprite;
 use sprite::Sprite;
 
 mod sprites;
 
-mod typing_status;
-use typing_status::TypingStatus;
-
 mod resolution;
 use resolution::Resolution;
 
@@ -242,23 +289,374 @@ use render_shadow::render_shadow;
 mod render_grass;
 use render_grass::render_grass;
 
+mod uv_rect;
+use uv_rect::UvRect;
+
+mod tile_layer;
+use tile_layer::TileLayer;
+
+mod generate_quad_vertices;
+use generate_quad_vertices::generate_quad_vertices;
+
+mod generate_quad_indices;
+use generate_quad_indices::generate_quad_indices;
+
+mod quad_batch;
+use quad_batch::QuadBatch;
+
+mod sprite_batch;
+use sprite_batch::SpriteBatch;
+
+mod texture_region;
+use texture_region::TextureRegion;
+
+mod atlas_layout;
+use atlas_layout::{
+  AtlasLayout,
+  AtlasPlacement
+};
+
+mod pack_atlas;
+use pack_atlas::pack_atlas;
+
+mod extrude_image;
+use extrude_image::extrude_image;
+
+mod compose_atlas_pages;
+use compose_atlas_pages::compose_atlas_pages;
+
+mod atlas_textures;
+use atlas_textures::ATLAS_TEXTURES;
+
+mod tile_location;
+use tile_location::tile_location;
+
+mod visible_tiles;
+use visible_tiles::visible_tiles;
+
+mod board_bounds;
+use board_bounds::board_bounds;
+
+mod camera_rig;
+use camera_rig::CameraRig;
+
+mod minimap_color;
+use minimap_color::minimap_color;
+
+mod minimap_changes;
+use minimap_changes::MinimapChanges;
+
+mod minimap;
+use minimap::Minimap;
+
+mod drake_motion;
+use drake_motion::DrakeMotion;
+
+mod animation_mode;
+use animation_mode::AnimationMode;
+
+mod animation_frame;
+use animation_frame::AnimationFrame;
+
+mod animation_clip;
+use animation_clip::AnimationClip;
+
+mod animation_sheet;
+use animation_sheet::AnimationSheet;
+
+mod deserialize_animation_sheet;
+use deserialize_animation_sheet::deserialize_animation_sheet;
+
+mod animator;
+use animator::Animator;
+
+mod text_alignment;
+use text_alignment::TextAlignment;
+
+mod placed_glyph;
+use placed_glyph::PlacedGlyph;
+
+mod text_layout;
+use text_layout::TextLayout;
+
+mod layout_text;
+use layout_text::layout_text;
+
+mod text_style;
+use text_style::TextStyle;
+
+mod glyph;
+use glyph::Glyph;
+
+mod shelf_packer;
+use shelf_packer::ShelfPacker;
+
+mod text_renderer;
+use text_renderer::TextRenderer;
+
+mod hud_text;
+use hud_text::HudText;
+
+mod render_hud;
+use render_hud::render_hud;
+
+mod particle_mode;
+use particle_mode::ParticleMode;
+
+mod particle_effect;
+use particle_effect::ParticleEffect;
+
+mod deserialize_particle_effect;
+use deserialize_particle_effect::deserialize_particle_effect;
+
+mod particle;
+use particle::Particle;
+
+mod particle_system;
+use particle_system::ParticleSystem;
+
+mod particle_emitter;
+use particle_emitter::ParticleEmitter;
+
+mod particle_cue;
+use particle_cue::ParticleCue;
+
+mod particle_cues;
+use particle_cues::ParticleCues;
+
+mod particle_effects;
+use particle_effects::ParticleEffects;
+
+mod generate_particle_vertices;
+use generate_particle_vertices::generate_particle_vertices;
+
+mod particle_batch;
+use particle_batch::ParticleBatch;
+
 mod render_main_menu;
 use render_main_menu::render_main_menu;
 
+mod window_mode;
+use window_mode::WindowMode;
+
+mod settings;
+use settings::Settings;
+
+mod settings_state;
+use settings_state::SettingsState;
+
+mod settings_options;
+
+mod new_game_options;
+use new_game_options::{
+  MAP_SIZE_OPTIONS,
+  NUM_SNAKES_OPTIONS
+};
+
+mod cycle_option;
+use cycle_option::cycle_option;
+
+mod parse_dimensions;
+use parse_dimensions::parse_dimensions;
+
+mod serialize_settings;
+use serialize_settings::serialize_settings;
+
+mod deserialize_settings;
+use deserialize_settings::deserialize_settings;
+
+mod load_settings;
+use load_settings::load_settings;
+
+mod save_settings;
+use save_settings::save_settings;
+
+mod settings_menu_lines;
+use settings_menu_lines::settings_menu_lines;
+
+mod apply_window_settings;
+use apply_window_settings::apply_window_settings;
+
+mod merge_window_settings;
+use merge_window_settings::merge_window_settings;
+
+mod viewport;
+use viewport::Viewport;
+
+mod calculate_viewport;
+use calculate_viewport::calculate_viewport;
+
+mod fit_camera;
+use fit_camera::fit_camera;
+
+mod key_names;
+use key_names::{
+  key_name,
+  key_from_name,
+  produces_text
+};
+
+mod input_bindings;
+use input_bindings::InputBindings;
+
+mod serialize_input_bindings;
+use serialize_input_bindings::serialize_input_bindings;
+
+mod deserialize_input_bindings;
+use deserialize_input_bindings::deserialize_input_bindings;
+
+mod load_input_bindings;
+use load_input_bindings::load_input_bindings;
+
+mod save_input_bindings;
+use save_input_bindings::save_input_bindings;
+
+mod input_repeat;
+use input_repeat::InputRepeat;
+
+mod controller_input;
+use controller_input::ControllerInput;
+
+mod multiply_matrices;
+use multiply_matrices::{
+  multiply_matrices,
+  multiply_matrix_vector
+};
+
+mod invert_matrix;
+use invert_matrix::invert_matrix;
+
+mod unproject;
+use unproject::unproject;
+
+mod pointer_button;
+use pointer_button::PointerButton;
+
+mod tile_size;
+
+mod world_to_coordinate;
+use world_to_coordinate::world_to_coordinate;
+
+mod find_route;
+use find_route::find_route;
+
+mod is_safe_step;
+use is_safe_step::is_safe_step;
+
+mod menu_item_at;
+use menu_item_at::menu_item_at;
+
+mod command;
+use command::Command;
+
+mod toggle_mark;
+use toggle_mark::toggle_mark;
+
+mod text_field;
+use text_field::TextField;
+
+mod terminal_keycode;
+use terminal_keycode::terminal_keycode;
+
+mod terminal_screen;
+use terminal_screen::TerminalScreen;
+
+mod terminal_repeat;
+use terminal_repeat::TerminalRepeat;
+
+mod run_terminal;
+use run_terminal::run_terminal;
+
+mod frontend;
+use frontend::Frontend;
+
+mod command_line_options;
+use command_line_options::CommandLineOptions;
+
+mod parse_command_line;
+use parse_command_line::parse_command_line;
+
+mod data_paths;
+use data_paths::DataPaths;
+
+mod start_game_session;
+use start_game_session::start_game_session;
+
+mod play_script;
+use play_script::play_script;
+
+mod run_headless;
+use run_headless::run_headless;
+
+mod log_filter;
+use log_filter::LogFilter;
+
+mod parse_log_filter;
+use parse_log_filter::parse_log_filter;
+
+mod rotating_log_file;
+use rotating_log_file::RotatingLogFile;
+
+mod game_logger;
+use game_logger::GameLogger;
+
+mod init_logging;
+use init_logging::init_logging;
+
+mod scene_dump;
+use scene_dump::SceneDump;
+
+mod update_save_game;
+use update_save_game::update_save_game;
+
+mod update_add_high_score;
+use update_add_high_score::update_add_high_score;
+
 fn main() -> Result<(), String> {
+  let arguments: Vec<String> = std::env::args().skip(1).collect();
+  let options = parse_command_line(&arguments).map_err(| error | format!("{}\n\n{}", error, parse_command_line::USAGE))?;
+
+  if options.show_help {
+    println!("{}", parse_command_line::USAGE);
+    return Ok(());
+  }
+
+  std::fs::create_dir_all(&options.data_directory).map_err(| error | error.to_string())?;
+  let data_paths = DataPaths::new(&options.data_directory);
+  init_logging(&options, &data_paths)?;
+
+  match options.frontend {
+    Frontend::Terminal => return run_terminal(&options, &data_paths),
+    Frontend::Headless => return run_headless(&options, &data_paths),
+    Frontend::Sdl => {}
+  }
+
   let sdl_context = sdl2::init()?;
   let video_subsystem = sdl_context.video()?;
+  let game_controller_subsystem = sdl_context.game_controller()?;
 
   let gl_attr = video_subsystem.gl_attr();
   gl_attr.set_context_profile(sdl2::video::GLProfile::Core);
   gl_attr.set_context_version(3, 3);
 
-  let resolution = Resolution::new(1600, 900);
+  let settings = load_settings(&data_paths.settings_file)?;
+  let input_bindings = load_input_bindings(&data_paths.bindings_file)?;
+
+  let mut saved_settings = settings;
+  let mut window_settings = settings;
+  if options.windowed { window_settings.window_mode = WindowMode::Windowed; }
+  if let Some((width, height)) = options.resolution { window_settings.resolution = Resolution::new(width, height); }
+
+  let mut window_builder = video_subsystem.window("Drakes VS Snakes", window_settings.resolution.width() as u32, window_settings.resolution.height() as u32);
+  window_builder.opengl().resizable().allow_highdpi();
+
+  match window_settings.window_mode {
+    WindowMode::Fullscreen => { window_builder.fullscreen(); },
+    WindowMode::Borderless => { window_builder.fullscreen_desktop(); },
+    WindowMode::Windowed => {}
+  }
 
-  let window = video_subsystem
-    .window("Drakes VS Snakes", resolution.width() as u32, resolution.height() as u32)
-    .fullscreen()
-    .opengl()
+  let mut window = window_builder
     .build()
     .map_err(| error | error.to_string())?;
 
@@ -266,140 +664,380 @@ fn main() -> Result<(), String> {
   let _gl_context = window.gl_create_context();
 
   let ttf_context = sdl2::ttf::init()?;
-  let font = ttf_context.load_font(Path::new("./res/fonts/RasterForgeRegular.ttf"), 32)?;
+  let mut resources = Resources::new(&options.asset_directory);
+  let font_bytes = resources.read("fonts/RasterForgeRegular.ttf")?;
+  let font = ttf_context.load_font_from_rwops(RWops::from_bytes(&font_bytes)?, 32)?;
 
   unsafe {
-    gl::Viewport(0, 0, resolution.width() as gl::types::GLint, resolution.height() as gl::types::GLint);
     gl::Enable(gl::BLEND);
     gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
     gl::ClearColor(0.5, 0.25, 0.25, 1.0);
   }
   
   let mut event_pump = sdl_context.event_pump()?;
-  let mut typing_status = TypingStatus::NotTyping;
-  let mut typing_buffer = String::new();
-  let mut displayed_text = String::new();
+  let mut game_controllers = Vec::new();
+  let mut controller_input = ControllerInput::new(settings.repeat_delay(), settings.repeat_interval());
+  let mut keyboard_repeat = InputRepeat::new(settings.repeat_delay(), settings.repeat_interval());
+  let text_input = video_subsystem.text_input();
+  text_input.stop();
+
+  let mut save_name_field = TextField::new(32, "/\\:*?\"<>|");
+  let mut high_score_name_field = TextField::new(16, ",");
 
   let mut current_scene = Scenes::MainMenu;
+  let mut scene_dump = SceneDump::new(options.debug);
   let mut is_running = true;
   let mut message_queue = MessageQueue::new();
-  let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+  let session_statistics = Rc::new(RefCell::new(SessionStatistics::new()));
+  message_queue.subscribe(&session_statistics);
+
+  let minimap_changes = Rc::new(RefCell::new(MinimapChanges::new()));
+  message_queue.subscribe(&minimap_changes);
+  let mut minimap = Minimap::new();
+  let drake_motion = Rc::new(RefCell::new(DrakeMotion::new()));
+  message_queue.subscribe(&drake_motion);
+  let particle_cues = Rc::new(RefCell::new(ParticleCues::new()));
+  message_queue.subscribe(&particle_cues);
+
   
   let mut main_menu_state = MainMenuState::new();
   let mut new_game_state = NewGameState::new();
-  let mut playfield_state = PlayfieldState::new();
+  apply_new_game_defaults(&mut new_game_state, &settings);
+  let mut game_session = GameSession::empty(1234);
   let mut pause_menu_state = PauseMenuState::new();
   let mut high_scores_state = HighScoresState::new();
   let mut load_game_state = LoadGameState::new();
+  let mut settings_state = SettingsState::new(settings, input_bindings);
 
-  let tile_width = 32;
-  let tile_height = 32;
-  let text_color = Color::RGBA(16, 32, 32, 255);
-
-  let main_menu_sprites = sprites::MainMenu::new(&font, &text_color)?;
-  let mut emblem_sprites = sprites::Emblems::new()?;
-  let new_game_sprites = sprites::NewGame::new(&font, &text_color)?;
-  let number_sprites = sprites::Numbers::new(&font, &text_color)?;
-  let mut grass_sprites = sprites::Grass::new()?;
-  let mut shadow_sprites = sprites::Shadows::new()?;
-  let pause_menu_sprites = sprites::PauseMenu::new(&font, &text_color)?;
+  if let Some(started_game_session) = start_game_session(&options, &settings, &data_paths.saves_directory)? {
+    game_session = started_game_session;
+    current_scene = Scenes::Playfield;
+  }
+
+  let tile_width = tile_size::TILE_SIZE;
+  let tile_height = tile_size::TILE_SIZE;
+  let mut text_color = Color::RGBA(settings.text_color.0, settings.text_color.1, settings.text_color.2, 255);
+
+  let mut main_menu_sprites = sprites::MainMenu::new(&font, &text_color)?;
+  resources.load_atlas(&ATLAS_TEXTURES)?;
+
+  let mut emblem_sprites = sprites::Emblems::new(&mut resources)?;
+  let mut new_game_sprites = sprites::NewGame::new(&font, &text_color)?;
+  let mut grass_sprites = sprites::Grass::new(&mut resources)?;
+  let mut shadow_sprites = sprites::Shadows::new(&mut resources)?;
+  let mut pause_menu_sprites = sprites::PauseMenu::new(&font, &text_color)?;
   
-  let mut drake_sprite = Sprite::load(Path::new("res/textures/drake.png"))?;
-  let mut snake_sprite = Sprite::load(Path::new("res/textures/snake.png"))?;
-  let mut nest_sprite = Sprite::load(Path::new("res/textures/nest.png"))?;
+  let drake_sheet = resources.animation_sheet("animations/drake.anim", &["idle", "walk", "hurt"])?;
+  let mut drake_sprite = resources.sprite(&drake_sheet.texture)?;
+  let mut drake_animator = Animator::new(drake_sheet, "idle")?;
+
+  let snake_sheet = resources.animation_sheet("animations/snake.anim", &["idle", "strike"])?;
+  let mut snake_sprite = resources.sprite(&snake_sheet.texture)?;
+  let mut snake_animator = Animator::new(Rc::clone(&snake_sheet), "idle")?;
+  let mut striking_snake: Option<(usize, Animator)> = None;
+  let mut nest_sprite = resources.sprite("textures/nest.png")?;
+  let mut highlight_sprite = resources.sprite("textures/highlight.png")?;
   
-  let mut save_sprites = Vec::new();
-  let mut displayed_text_sprite = Sprite::print(&" ".to_string(), &font, &text_color)?;
   
-  let mut high_scores_sprites = Vec::new();
+
+  let mut text_renderer = TextRenderer::new(&font);
+  let mut text_style = TextStyle::new(text_color, font.height() as f32);
   
-  let enter_name_sprite = Sprite::print(&"Enter Name".to_string(), &font, &text_color)?;
+  let mut enter_name_sprite = Sprite::print(&"Enter Name".to_string(), &font, &text_color)?;
   
-  let quad_vertex_shader = VertexShader::load(Path::new("./res/shaders/quad_vertex_shader.glsl"))?;
-  let quad_fragment_shader = FragmentShader::load(Path::new("./res/shaders/quad_fragment_shader.glsl"))?;
-  let quad_shader_program = ShaderProgram::new(quad_vertex_shader, quad_fragment_shader)?;
-
-  let text_vertex_shader = VertexShader::load(Path::new("./res/shaders/text_vertex_shader.glsl"))?;
-  let text_fragment_shader = FragmentShader::load(Path::new("./res/shaders/text_fragment_shader.glsl"))?;
-  let text_shader_program = ShaderProgram::new(text_vertex_shader, text_fragment_shader)?;
+  let mut quad_shader_program = load_shader_program(&resources, "quad")?;
+  let mut text_shader_program = load_shader_program(&resources, "text")?;
+  let mut glyph_shader_program = load_shader_program(&resources, "glyph")?;
+  let mut particle_shader_program = load_shader_program(&resources, "particle")?;
 
-  let animation_vertex_shader = VertexShader::load(Path::new("./res/shaders/animation_vertex_shader.glsl"))?;
-  let animation_fragment_shader = FragmentShader::load(Path::new("./res/shaders/animation_fragment_shader.glsl"))?;
-  let animation_shader_program = ShaderProgram::new(animation_vertex_shader, animation_fragment_shader)?;
+  let mut asset_watcher = options.dev_mode.then(|| AssetWatcher::new(&options.asset_directory, Duration::from_millis(500)));
   
-  let mut camera = Camera::new(resolution);
+  let mut camera = Camera::new();
+  let mut overlay_camera = Camera::new();
+  let mut camera_rig = CameraRig::new();
+  let mut playfield_elapsed = Duration::ZERO;
+  fit_camera(&mut camera, &window, &window_settings);
+  fit_camera(&mut overlay_camera, &window, &window_settings);
   
-  let fps_cap = 60;
-  let frame_duration_cap = Duration::from_millis(1000 / fps_cap);
+  let mut frame_duration_cap = Duration::from_millis(1000 / settings.fps_cap);
 
   let mut last_frame = Instant::now();
 
-  let mut stars_animation = Animation::load(Path::new("./res/textures/stars.png"), 24)?;
-  let mut water_animation = Animation::load(Path::new("./res/textures/water.png"), 16)?;
+  let mut sprite_batch = SpriteBatch::new();
+
+  let stars_sheet = resources.animation_sheet("animations/stars.anim", &["idle"])?;
+  let mut stars_sprite = resources.sprite(&stars_sheet.texture)?;
+  let mut stars_animator = Animator::new(stars_sheet, "idle")?;
+
+  let water_sheet = resources.animation_sheet("animations/water.anim", &["idle"])?;
+  let mut water_sprite = resources.sprite(&water_sheet.texture)?;
+  let mut water_animator = Animator::new(water_sheet, "idle")?;
+
+  let particle_effects = ParticleEffects::load(&resources)?;
+  let mut particle_batch = ParticleBatch::new(resources.region("textures/particle.png")?);
+  let mut world_particles = ParticleSystem::new(rand::random());
+  let mut overlay_particles = ParticleSystem::new(rand::random());
+  let mut breath_emitter = ParticleEmitter::new(Rc::clone(&particle_effects.breath));
   
   while is_running {
     let frame_start = Instant::now();
     let deltatime = frame_start - last_frame;
     last_frame = frame_start;
+
+    if let Some(asset_watcher) = &mut asset_watcher {
+      for asset_id in asset_watcher.update(&deltatime) {
+        let reloaded = match asset_id.as_str() {
+          id if id.starts_with("shaders/quad_") => load_shader_program(&resources, "quad").map(| program | { quad_shader_program = program; true }),
+          id if id.starts_with("shaders/text_") => load_shader_program(&resources, "text").map(| program | { text_shader_program = program; true }),
+          id if id.starts_with("shaders/glyph_") => load_shader_program(&resources, "glyph").map(| program | { glyph_shader_program = program; true }),
+          id if id.starts_with("shaders/particle_") => load_shader_program(&resources, "particle").map(| program | { particle_shader_program = program; true }),
+          id => resources.reload_texture(id)
+        };
+
+        match reloaded {
+          Ok(true) => log::info!("Reloaded {}", asset_id),
+          Ok(false) => {},
+          Err(error) => log::error!("Could not reload {}: {}", asset_id, error)
+        }
+      }
+    }
     
     for event in event_pump.poll_iter() {
       match event {
         Event::Quit { .. } => message_queue.post(Message::RequestShutdown),
+
+        Event::Window { win_event: WindowEvent::Resized( .. ) | WindowEvent::SizeChanged( .. ), .. } => {
+          fit_camera(&mut camera, &window, &window_settings);
+          fit_camera(&mut overlay_camera, &window, &window_settings);
+          log::debug!("Window resized to {:?}, drawable {:?}", window.size(), window.drawable_size());
+        },
+
+        Event::MouseMotion { x, y, xrel, yrel, mousestate, .. } => {
+          if current_scene == Scenes::Playfield && mousestate.middle() {
+            if let (Some(previous), Some(current)) = (camera.screen_to_world(x - xrel, y - yrel), camera.screen_to_world(x, y)) {
+              camera_rig.pan(Vector2 { x: previous.x - current.x, y: previous.y - current.y });
+            }
+
+            continue;
+          }
+
+          if let Some(world) = camera.screen_to_world(x, y) {
+            message_queue.post(Message::PointerMoved(world.x as i32, world.y as i32));
+          }
+        },
+
+        Event::MouseWheel { y, .. } if current_scene == Scenes::Playfield => {
+          if y > 0 { camera_rig.zoom_in() }
+          if y < 0 { camera_rig.zoom_out() }
+          camera.zoom_to(camera_rig.zoom());
+        },
+
+        Event::MouseButtonDown { mouse_btn, x, y, .. } => {
+          let button = match mouse_btn {
+            MouseButton::Left => PointerButton::Left,
+            MouseButton::Right => PointerButton::Right,
+            _ => continue
+          };
+
+          if current_scene == Scenes::Playfield && button == PointerButton::Left {
+            let minimap_coordinate = overlay_camera.screen_to_world(x, y).and_then(| world | minimap.coordinate_at(world, &game_session.map().size));
+
+            if let Some(coordinate) = minimap_coordinate {
+              camera_rig.jump_to(tile_location(&coordinate, &game_session.map().size, tile_width, tile_height));
+              continue;
+            }
+          }
+
+          if let Some(world) = camera.screen_to_world(x, y) {
+            message_queue.post(Message::PointerPressed(world.x as i32, world.y as i32, button));
+          }
+        },
+
+        Event::ControllerDeviceAdded { which, .. } => {
+          let game_controller = match game_controller_subsystem.open(which) {
+            Ok(game_controller) => game_controller,
+            Err(error) => {
+              log::warn!("Could not open controller {}: {}", which, error);
+              continue;
+            }
+          };
+
+          log::info!("Controller connected: {}", game_controller.name());
+          game_controllers.push(game_controller);
+        },
+
+        Event::ControllerDeviceRemoved { which, .. } => {
+          game_controllers.retain(| game_controller | game_controller.instance_id() != which);
+          controller_input.disconnected(which);
+          log::info!("Controller disconnected");
+        },
+
+        Event::ControllerButtonDown { which, button, .. } => if let Some(input) = controller_input.button_down(which, button) {
+          message_queue.post(Message::PlayerInput(input));
+        },
+
+        Event::ControllerButtonUp { which, button, .. } => controller_input.button_up(which, button),
+
+        Event::ControllerAxisMotion { which, axis, value, .. } => if let Some(input) = controller_input.axis_motion(which, axis, value) {
+          message_queue.post(Message::PlayerInput(input));
+        },
         
-        Event::KeyDown { keycode: Some(keycode), repeat, .. } => if !repeat {
-          match typing_status {
-            TypingStatus::Typing => {
-              println!("Typing");
-              match keycode {
-                Keycode::A | Keycode::B | Keycode::C | Keycode::D |
-                Keycode::E | Keycode::F | Keycode::G | Keycode::H |
-                Keycode::I | Keycode::J | Keycode::K | Keycode::L |
-                Keycode::M | Keycode::N | Keycode::O | Keycode::P |
-                Keycode::Q | Keycode::R | Keycode::S | Keycode::T |
-                Keycode::U | Keycode::V | Keycode::W | Keycode::X |
-                Keycode::Y | Keycode::Z => typing_buffer.push_str(&keycode.to_string()),
-                
-                Keycode::Return => typing_status = TypingStatus::TypingEnded,
-                Keycode::Backspace => { typing_buffer.pop(); },
-
-                _ => {}
-              }
-            },
+        Event::TextInput { text, .. } => match current_scene {
+          Scenes::SaveGame => save_name_field.insert(&text),
+          Scenes::AddHighScore => high_score_name_field.insert(&text),
+          _ => {}
+        },
+
+        Event::TextEditing { text, .. } => match current_scene {
+          Scenes::SaveGame => save_name_field.set_composition(&text),
+          Scenes::AddHighScore => high_score_name_field.set_composition(&text),
+          _ => {}
+        },
+
+        Event::KeyDown { keycode: Some(keycode), repeat, .. } => {
+          if current_scene == Scenes::Settings && settings_state.is_capturing_key {
+            if !repeat { message_queue.post(Message::KeyCaptured(keycode)) }
+            continue;
+          }
 
-            TypingStatus::NotTyping => {
-              println!("Not Typing");
-              match keycode {
-                Keycode::W => message_queue.post(Message::PlayerInput(Input::Up)),
-                Keycode::A => message_queue.post(Message::PlayerInput(Input::Left)),
-                Keycode::D => message_queue.post(Message::PlayerInput(Input::Right)),
-                Keycode::S => message_queue.post(Message::PlayerInput(Input::Down)),
-                Keycode::Return => message_queue.post(Message::PlayerInput(Input::Confirm)),
-                Keycode::Escape => message_queue.post(Message::PlayerInput(Input::Cancel)),
-                Keycode::Space => message_queue.post(Message::PlayerInput(Input::Action)),
-                _ => {}
+          let active_text_field = match current_scene {
+            Scenes::SaveGame => Some(&mut save_name_field),
+            Scenes::AddHighScore => Some(&mut high_score_name_field),
+            _ => None
+          };
+
+          match active_text_field {
+            Some(text_field) => match keycode {
+              Keycode::Backspace => text_field.backspace(),
+              Keycode::Delete => text_field.delete(),
+              Keycode::Left => text_field.move_left(),
+              Keycode::Right => text_field.move_right(),
+              Keycode::Home => text_field.move_home(),
+              Keycode::End => text_field.move_end(),
+
+              _ => if !repeat && !produces_text(keycode) {
+                match settings_state.input_bindings.input_for(keycode) {
+                  Some(Input::Confirm) => message_queue.post(Message::PlayerInput(Input::Confirm)),
+                  Some(Input::Cancel) => message_queue.post(Message::PlayerInput(Input::Cancel)),
+                  _ => {}
+                }
               }
             },
 
-            TypingStatus::TypingStarted => {
-              println!("Typing Started");
-              typing_buffer = String::new();
-              typing_status = TypingStatus::Typing;
+            None if !repeat => if let Some(input) = settings_state.input_bindings.input_for(keycode) {
+              if matches!(input, Input::Up | Input::Left | Input::Right | Input::Down) { keyboard_repeat.press(input) }
+              message_queue.post(Message::PlayerInput(input));
             },
 
-            TypingStatus::TypingEnded => { println!("Typing Ended") }
+            None => {}
           }
-        }
+        },
+
+        Event::KeyUp { keycode: Some(keycode), .. } => if let Some(input) = settings_state.input_bindings.input_for(keycode) {
+          keyboard_repeat.release(input);
+        },
 
         _ => {}
       }
     }
 
+    let mut repeated_inputs = keyboard_repeat.update(&deltatime);
+    repeated_inputs.append(&mut controller_input.update(&deltatime));
+
+    for input in repeated_inputs {
+      match current_scene {
+        Scenes::Playfield => message_queue.post(Message::RepeatedInput(input)),
+        _ => message_queue.post(Message::PlayerInput(input))
+      }
+    }
+
     message_queue.swap_buffers();
     for message in message_queue.messages() {
       match *message {
         Message::RequestShutdown => is_running = false,
-        Message::RequestScene(new_scene) => current_scene = new_scene,
-        Message::PlayerInput( .. ) => {}
+
+        Message::RequestScene(new_scene) => {
+          keyboard_repeat.release_all();
+
+          match new_scene {
+            Scenes::Playfield => {
+              if current_scene != Scenes::Pause {
+                camera_rig.reset();
+                minimap.invalidate();
+                drake_motion.borrow_mut().reset();
+                drake_animator.play("idle")?;
+                striking_snake = None;
+                playfield_elapsed = Duration::ZERO;
+                world_particles.clear();
+                overlay_particles.clear();
+              }
+              camera.zoom_to(camera_rig.zoom());
+            },
+
+            _ => camera.zoom_to(1.0)
+          }
+
+          match new_scene {
+            Scenes::SaveGame | Scenes::AddHighScore => text_input.start(),
+            _ => text_input.stop()
+          }
+
+          log::debug!("Switching scene from {:?} to {:?}", current_scene, new_scene);
+          current_scene = new_scene;
+        },
+
+        Message::PlayerInput(Input::Minimap) if current_scene == Scenes::Playfield => minimap.toggle(),
+        Message::PlayerInput( .. ) => {},
+        Message::RepeatedInput( .. ) => {},
+        Message::GameEvent(GameEvent::PlayerMoved( .. )) => camera_rig.recenter(),
+
+        Message::GameEvent(GameEvent::SnakeBitten(index)) => {
+          drake_animator.play("hurt")?;
+          striking_snake = Some((index, Animator::new(Rc::clone(&snake_sheet), "strike")?));
+        },
+
+        Message::GameEvent( .. ) => {},
+        Message::KeyCaptured( .. ) => {},
+        Message::PointerMoved( .. ) => {},
+        Message::PointerPressed( .. ) => {},
+
+        Message::SettingsChanged(new_settings) => {
+          log::debug!("Applying settings {:?}", new_settings);
+          let merged_settings = merge_window_settings(&window_settings, &saved_settings, &new_settings);
+          if merged_settings.window_mode != window_settings.window_mode || merged_settings.resolution != window_settings.resolution {
+            apply_window_settings(&mut window, &merged_settings)?;
+          }
+
+          saved_settings = new_settings;
+          window_settings = merged_settings;
+          fit_camera(&mut camera, &window, &window_settings);
+          fit_camera(&mut overlay_camera, &window, &window_settings);
+          frame_duration_cap = Duration::from_millis(1000 / new_settings.fps_cap);
+          keyboard_repeat.set_timing(new_settings.repeat_delay(), new_settings.repeat_interval());
+          controller_input.set_repeat_timing(new_settings.repeat_delay(), new_settings.repeat_interval());
+          apply_new_game_defaults(&mut new_game_state, &new_settings);
+
+          let new_text_color = Color::RGBA(new_settings.text_color.0, new_settings.text_color.1, new_settings.text_color.2, 255);
+          if new_text_color != text_color {
+            text_color = new_text_color;
+            text_style.color = text_color;
+
+            main_menu_sprites = sprites::MainMenu::new(&font, &text_color)?;
+            new_game_sprites = sprites::NewGame::new(&font, &text_color)?;
+            pause_menu_sprites = sprites::PauseMenu::new(&font, &text_color)?;
+            enter_name_sprite = Sprite::print(&"Enter Name".to_string(), &font, &text_color)?;
+          }
+        }
+      }
+    }
+
+    let map = game_session.map();
+    for (particle_cue, index) in particle_cues.borrow_mut().take(map) {
+      let effect = particle_effects.cue(particle_cue);
+
+      match particle_cue {
+        ParticleCue::Bite | ParticleCue::Victory => overlay_particles.burst(effect, Vector2::new(), 0.0),
+        _ => world_particles.burst(effect, tile_location(&Coordinate::from_index(index, &map.size), &map.size, tile_width, tile_height), 0.0)
       }
     }
 
@@ -407,23 +1045,33 @@ fn main() -> Result<(), String> {
 
     match current_scene {
       Scenes::MainMenu => {
-        update_main_menu(&mut message_queue, &mut main_menu_state, &mut camera, &main_menu_sprites, &mut emblem_sprites);
-        render_main_menu(&main_menu_sprites, &emblem_sprites, &camera, &text_shader_program, &quad_shader_program)?;
+        main_menu_state.item_widths = main_menu_sprites.item_widths();
+        update_main_menu(&mut message_queue, &mut main_menu_state);
+        scene_dump.dump(|| print_main_menu(&main_menu_state));
+
+        camera.transform.translate_to(Vector2::new());
+
+        render_main_menu(&main_menu_state, &main_menu_sprites, &mut emblem_sprites, &camera, &text_shader_program, &quad_shader_program)?;
       },
 
       Scenes::NewGame => {
-        update_new_game(&mut new_game_state, &mut playfield_state.map, &mut message_queue, &mut rng)?;
-        print_new_game(&new_game_state);
+        let options = match new_game_state.step {
+          NewGameStep::Width | NewGameStep::Height => MAP_SIZE_OPTIONS.as_slice(),
+          NewGameStep::NumSnakes => NUM_SNAKES_OPTIONS.as_slice()
+        };
+        new_game_state.item_widths = measure_widths(&mut text_renderer, &font, options.iter().map(|option| option.to_string()), &text_style)?;
+        update_new_game(&mut new_game_state, &mut game_session, &mut message_queue)?;
+        scene_dump.dump(|| print_new_game(&new_game_state));
 
         camera.transform.translate_to(Vector2::new());
 
         match new_game_state.step {
           NewGameStep::Width => {
             render_sprite(new_game_sprites.map_width(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.eight(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.sixteen(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.thirty_two(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.sixty_four(), &camera, &text_shader_program)?;
+
+            for (index, option) in MAP_SIZE_OPTIONS.iter().enumerate() {
+              text_renderer.queue(&font, &option.to_string(), Vector2 { x: 0.0, y: index as f32 * 32.0 }, &text_style)?;
+            }
 
             emblem_sprites.mut_snakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
             emblem_sprites.mut_drakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
@@ -434,10 +1082,10 @@ fn main() -> Result<(), String> {
 
           NewGameStep::Height => {
             render_sprite(new_game_sprites.map_height(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.eight(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.sixteen(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.thirty_two(), &camera, &text_shader_program)?;
-            render_sprite(number_sprites.sixty_four(), &camera, &text_shader_program)?;
+
+            for (index, option) in MAP_SIZE_OPTIONS.iter().enumerate() {
+              text_renderer.queue(&font, &option.to_string(), Vector2 { x: 0.0, y: index as f32 * 32.0 }, &text_style)?;
+            }
 
             emblem_sprites.mut_snakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
             emblem_sprites.mut_drakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
@@ -448,10 +1096,10 @@ fn main() -> Result<(), String> {
 
           NewGameStep::NumSnakes => {
             render_sprite(new_game_sprites.num_snakes(), &camera, &text_shader_program)?;
-            render_sprite(&number_sprites.sixteen(), &camera, &text_shader_program)?;
-            render_sprite(&number_sprites.thirty_two(), &camera, &text_shader_program)?;
-            render_sprite(&number_sprites.sixty_four(), &camera, &text_shader_program)?;
-            render_sprite(&number_sprites.one_two_eight(), &camera, &text_shader_program)?;
+
+            for (index, option) in NUM_SNAKES_OPTIONS.iter().enumerate() {
+              text_renderer.queue(&font, &option.to_string(), Vector2 { x: 0.0, y: index as f32 * 32.0 }, &text_style)?;
+            }
 
             emblem_sprites.mut_snakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
             emblem_sprites.mut_drakes().mut_transform().translate_y_to(new_game_state.selected_menu_item_index as f32 * 32.0);
@@ -463,72 +1111,131 @@ fn main() -> Result<(), String> {
       },
 
       Scenes::Playfield => {
-        update_playfield(&mut message_queue, &mut playfield_state)?;
-        print_playfield(&playfield_state);
+        let is_animating = striking_snake.is_some() || drake_animator.state() == "hurt";
+        update_playfield(&mut message_queue, &mut game_session, is_animating)?;
+        scene_dump.dump(|| print_playfield(&game_session.snapshot()));
 
-        stars_animation.update(&deltatime);
-        water_animation.update(&deltatime);
-        
-          for index in 0..playfield_state.map.size.array_length() {
-            let tile_coordinates = Coordinate::from_index(index, &playfield_state.map.size);
-            
-            let mut tile_transform = Transform::new();
-            tile_transform.translate_x_to(tile_coordinates.x() as f32 * tile_width as f32);
-            tile_transform.translate_x(-(playfield_state.map.size.width() as f32 * tile_width as f32 / 2.0));
-            tile_transform.translate_y_to(tile_coordinates.y() as f32 * tile_height as f32);
-            tile_transform.translate_y(-(playfield_state.map.size.height() as f32 * tile_height as f32 / 2.0));
+        if game_session.validation() == MapValidation::Valid {
+          playfield_elapsed += deltatime;
+        }
+
+        stars_animator.update(&deltatime);
+        water_animator.update(&deltatime);
+        snake_animator.update(&deltatime);
+        drake_motion.borrow_mut().update(&deltatime);
+
+        drake_animator.update(&deltatime);
+        if drake_animator.state() != "hurt" {
+          drake_animator.play(if drake_motion.borrow().is_moving() { "walk" } else { "idle" })?;
+        }
 
-            render_grass(playfield_state.map.hint[index], &mut grass_sprites, &camera, &quad_shader_program, tile_transform.location)?;
+        if let Some((_, strike_animator)) = &mut striking_snake {
+          strike_animator.update(&deltatime);
+          if strike_animator.state() != "strike" { striking_snake = None }
+        }
+
+        stars_animator.apply(&mut stars_sprite);
+        water_animator.apply(&mut water_sprite);
+        drake_animator.apply(&mut drake_sprite);
+
+        let map = game_session.map();
+
+        let player_location = drake_motion.borrow().location(map.player_location.array_index(), &map.size, tile_width, tile_height);
+        let (board_min, board_max) = board_bounds(&map.size, tile_width, tile_height);
+        let camera_location = camera_rig.update(player_location, camera.half_view_size, board_min, board_max, &deltatime);
+        camera.transform.translate_to(camera_location);
+
+        world_particles.update(&deltatime);
+        if game_session.validation() == MapValidation::Valid {
+          let facing = drake_motion.borrow().rotation();
+          let snout = Vector2 {
+            x: player_location.x + facing.to_radians().sin() * tile_width as f32 * 0.4,
+            y: player_location.y - facing.to_radians().cos() * tile_height as f32 * 0.4
+          };
+          breath_emitter.update(&mut world_particles, snout, facing, &deltatime);
+        }
+
+        let (view_min, view_max) = camera.visible_bounds();
+        let (visible_columns, visible_rows) = visible_tiles(view_min, view_max, &map.size, tile_width, tile_height);
+
+        for y in visible_rows {
+          for x in visible_columns.clone() {
+            let tile_coordinates = Coordinate::from(x, y, &map.size);
+            let index = tile_coordinates.array_index();
+            let location = tile_location(&tile_coordinates, &map.size, tile_width, tile_height);
+
+            render_grass(map.hint[index], &mut grass_sprites, &mut sprite_batch, location);
             
-            if playfield_state.map.is_snake[index] {
-              snake_sprite.mut_transform().translate_to(tile_transform.location);
-              render_sprite(&snake_sprite, &camera, &quad_shader_program)?;
+            if map.is_snake[index] {
+              match &striking_snake {
+                Some((striking_index, strike_animator)) if *striking_index == index => strike_animator.apply(&mut snake_sprite),
+                _ => snake_animator.apply(&mut snake_sprite)
+              }
+
+              snake_sprite.mut_transform().translate_to(location);
+              sprite_batch.push_sprite(TileLayer::Snake, &snake_sprite);
             }
             
-            if playfield_state.map.is_path[index] {
-              stars_animation.transform.translate_to(tile_transform.location);
-              render_animation(&stars_animation, &camera, &animation_shader_program)?;
+            if map.is_path[index] {
+              stars_sprite.mut_transform().translate_to(location);
+              sprite_batch.push_sprite(TileLayer::Path, &stars_sprite);
             }
 
-            if playfield_state.map.is_water[tile_coordinates.array_index()] {
-              water_animation.transform.translate_to(tile_transform.location);
-              render_animation(&water_animation, &camera, &animation_shader_program)?;
+            if map.is_water[tile_coordinates.array_index()] {
+              water_sprite.mut_transform().translate_to(location);
+              sprite_batch.push_sprite(TileLayer::Water, &water_sprite);
             }
 
-            if playfield_state.map.player_location.array_index() == index {
-              camera.transform.translate_to(tile_transform.location);
-              drake_sprite.mut_transform().translate_to(tile_transform.location);
-              render_sprite(&drake_sprite, &camera, &quad_shader_program)?;
-            }
-            
-            if !playfield_state.map.is_explored[index] {
+            if !map.is_explored[index] {
               let mut shadow_bits = [false, false, false, false];
-              let neighbors = get_direct_neighbors(&tile_coordinates, &playfield_state.map.size);
+              let neighbors = get_direct_neighbors(&tile_coordinates, &map.size);
               for neighbor_coordinate in neighbors {
-                if neighbor_coordinate.y() < tile_coordinates.y() && playfield_state.map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[0] = true };
-                if neighbor_coordinate.x() < tile_coordinates.x() && playfield_state.map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[1] = true };
-                if neighbor_coordinate.x() > tile_coordinates.x() && playfield_state.map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[2] = true };
-                if neighbor_coordinate.y() > tile_coordinates.y() && playfield_state.map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[3] = true };
+                if neighbor_coordinate.y() < tile_coordinates.y() && map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[0] = true };
+                if neighbor_coordinate.x() < tile_coordinates.x() && map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[1] = true };
+                if neighbor_coordinate.x() > tile_coordinates.x() && map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[2] = true };
+                if neighbor_coordinate.y() > tile_coordinates.y() && map.is_explored[neighbor_coordinate.array_index()] { shadow_bits[3] = true };
               }
 
-              render_shadow(&shadow_bits, &mut shadow_sprites, &camera, &quad_shader_program, tile_transform.location)?;
+              render_shadow(&shadow_bits, &mut shadow_sprites, &mut sprite_batch, location);
             }
 
-            if playfield_state.map.is_marked[tile_coordinates.array_index()] {
-              emblem_sprites.mut_snakes().mut_transform().translate_to(tile_transform.location);
-              render_sprite(&emblem_sprites.mut_snakes(), &camera, &quad_shader_program)?;
+            if map.is_marked[tile_coordinates.array_index()] {
+              emblem_sprites.mut_snakes().mut_transform().translate_to(location);
+              sprite_batch.push_sprite(TileLayer::Mark, emblem_sprites.snakes());
             }
 
-            if playfield_state.map.goal_location == tile_coordinates {
-              nest_sprite.mut_transform().translate_to(tile_transform.location);
-              render_sprite(&nest_sprite, &camera, &quad_shader_program)?;
+            if map.goal_location == tile_coordinates {
+              nest_sprite.mut_transform().translate_to(location);
+              sprite_batch.push_sprite(TileLayer::Goal, &nest_sprite);
             }
           }
+        }
+
+        if game_session.playfield_state().is_interacting {
+          for neighbor_coordinate in get_direct_neighbors(&map.player_location, &map.size) {
+            highlight_sprite.mut_transform().translate_to(tile_location(&neighbor_coordinate, &map.size, tile_width, tile_height));
+            sprite_batch.push_sprite(TileLayer::Highlight, &highlight_sprite);
+          }
+        }
+
+        drake_sprite.mut_transform().translate_to(player_location);
+        drake_sprite.mut_transform().rotate_to(drake_motion.borrow().rotation());
+        sprite_batch.push_sprite(TileLayer::Drake, &drake_sprite);
+
+        sprite_batch.draw(&camera, &quad_shader_program)?;
+        particle_batch.draw(&world_particles, &camera, &particle_shader_program)?;
+
+        minimap.sync(map, &minimap_changes.borrow_mut().take());
+        minimap.place(overlay_camera.half_view_size);
+        minimap.render(&overlay_camera, &quad_shader_program)?;
+
+        render_hud(&HudText::from_session(&game_session, playfield_elapsed), &mut text_renderer, &font, &text_style, &overlay_camera, &glyph_shader_program)?;
       },
 
       Scenes::Pause => {
+        pause_menu_state.item_widths = pause_menu_sprites.item_widths();
         update_pause_menu(&mut message_queue, &mut pause_menu_state);
-        print_pause_menu(&pause_menu_state);
+        scene_dump.dump(|| print_pause_menu(&pause_menu_state));
 
         camera.transform.translate_to(Vector2::new());
 
@@ -545,46 +1252,26 @@ fn main() -> Result<(), String> {
       },
 
       Scenes::SaveGame => {
-        update_save_game(&mut message_queue, &playfield_state, &mut typing_status, &typing_buffer)?;
-        print_save_game();
+        update_save_game(&mut message_queue, game_session.playfield_state(), &mut save_name_field, &data_paths.saves_directory)?;
+        scene_dump.dump(|| print_save_game(&save_name_field));
 
         camera.transform.translate_to(Vector2::new());
 
-        if displayed_text != typing_buffer {
-          displayed_text = typing_buffer.clone();
-
-          if displayed_text.is_empty() {
-            displayed_text_sprite = Sprite::print(&" ".to_string(), &font, &text_color)?;
-          } else {
-            displayed_text_sprite = Sprite::print(&displayed_text, &font, &text_color)?;
-          }
-        }
-
         render_sprite(pause_menu_sprites.save_game(), &camera, &text_shader_program)?;
-        render_sprite(&displayed_text_sprite, &camera, &text_shader_program)?;
+        text_renderer.queue(&font, &save_name_field.display_text(), Vector2::new(), &text_style)?;
       },
 
       Scenes::LoadGame => {
-        update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, Path::new("./saves"))?;
-        print_load_game(&load_game_state);
+        load_game_state.item_widths = measure_widths(&mut text_renderer, &font, load_game_state.saves.iter().cloned(), &text_style)?;
+        update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, &data_paths.saves_directory)?;
+        scene_dump.dump(|| print_load_game(&load_game_state));
 
         camera.transform.translate_to(Vector2::new());
 
         render_sprite(main_menu_sprites.load_game(), &camera, &text_shader_program)?;
 
-        let num_saves = load_game_state.saves.len();
-        if num_saves != save_sprites.len() {
-          save_sprites = Vec::new();
-
-          for save_string in &load_game_state.saves {
-            let save_sprite = Sprite::print(&save_string, &font, &text_color)?;
-            save_sprites.push(save_sprite);
-          }
-        }
-        
-        for (index, value) in save_sprites.iter_mut().enumerate() {
-          value.mut_transform().translate_y_to(index as f32 * 32.0 + 64.0);
-          render_sprite(value, &camera, &text_shader_program)?;
+        for (index, save_string) in load_game_state.saves.iter().enumerate() {
+          text_renderer.queue(&font, save_string, Vector2 { x: 0.0, y: index as f32 * 32.0 + 64.0 }, &text_style)?;
 
           if index == load_game_state.selected_menu_item_index {
             emblem_sprites.mut_snakes().mut_transform().translate_y_to(index as f32 * 32.0 + 64.0);
@@ -597,42 +1284,66 @@ fn main() -> Result<(), String> {
       },
 
       Scenes::HighScores => {
-        update_high_scores(&mut message_queue, &mut high_scores_state, Path::new("./high_scores.txt"))?;
-        print_high_scores(&high_scores_state);
+        update_high_scores(&mut message_queue, &mut high_scores_state, &data_paths.high_scores_file)?;
+        scene_dump.dump(|| print_high_scores(&high_scores_state));
 
         camera.transform.translate_to(Vector2::new());
 
-        if high_scores_sprites.len() != high_scores_state.listings.len() {
-          high_scores_sprites = Vec::new();
-
-          for (index, value) in high_scores_state.listings.iter().enumerate() {
-            let mut high_score_string = value.name().clone();
-            high_score_string.push_str(" ");
-            high_score_string.push_str(&value.score().to_string());
-
-            let mut high_score_sprite = Sprite::print(&high_score_string, &font, &text_color)?;
-            high_score_sprite.mut_transform().translate_y_to(index as f32 * 32.0 + 32.0);
-            high_scores_sprites.push(high_score_sprite);
-          }
-        }
-        
         render_sprite(main_menu_sprites.high_scores(), &camera, &text_shader_program)?;
-        
-        for high_score in &high_scores_sprites {
-          render_sprite(&high_score, &camera, &text_shader_program)?;
+
+        for (index, listing) in high_scores_state.listings.iter().enumerate() {
+          let y = index as f32 * 32.0 + 32.0;
+          text_renderer.queue(&font, listing.name(), Vector2 { x: -8.0, y }, &text_style.aligned(TextAlignment::Right))?;
+          text_renderer.queue(&font, &listing.score().to_string(), Vector2 { x: 8.0, y }, &text_style.aligned(TextAlignment::Left))?;
         }
       },
 
       Scenes::AddHighScore => {
-        update_add_high_score(&mut message_queue, &playfield_state, Path::new("./high_scores.txt"), &mut typing_status, &typing_buffer, &mut camera, &mut displayed_text, &mut displayed_text_sprite, &font, &text_color)?;
-        render_add_high_score(&enter_name_sprite, &displayed_text_sprite, &camera, &text_shader_program)?;
+        update_add_high_score(&mut message_queue, game_session.playfield_state(), &mut high_score_name_field, &data_paths.high_scores_file)?;
+        scene_dump.dump(|| print_add_high_score(&high_score_name_field));
+
+        camera.transform.translate_to(Vector2::new());
+
+        render_sprite(&enter_name_sprite, &camera, &text_shader_program)?;
+        text_renderer.queue(&font, &high_score_name_field.display_text(), Vector2::new(), &text_style)?;
       },
 
       Scenes::Settings => {
-        update_settings(&mut message_queue);
-        print_settings();
+        update_settings(&mut message_queue, &mut settings_state, &data_paths.settings_file, &data_paths.bindings_file)?;
+        scene_dump.dump(|| print_settings(&settings_state));
+
+        camera.transform.translate_to(Vector2::new());
+
+        let lines = settings_menu_lines(&settings_state);
+
+        render_sprite(main_menu_sprites.settings(), &camera, &text_shader_program)?;
+
+        for (index, line) in lines.iter().enumerate() {
+          text_renderer.queue(&font, line, Vector2 { x: 0.0, y: index as f32 * 32.0 + 64.0 }, &text_style)?;
+        }
+
+        if let Some(status) = &settings_state.status {
+          let status_location = Vector2 { x: 0.0, y: lines.len() as f32 * 32.0 + 64.0 };
+          text_renderer.queue(&font, status, status_location, &text_style.wrapped(camera.half_view_size.x * 2.0 - 64.0))?;
+        }
+
+        if let Some(selected_line) = lines.get(settings_state.selected_menu_item_index) {
+          let x_offset = text_renderer.measure(&font, selected_line, &text_style)?.width / 2.0 + 32.0;
+          let y_offset = settings_state.selected_menu_item_index as f32 * 32.0 + 64.0;
+
+          emblem_sprites.mut_snakes().mut_transform().translate_to(Vector2 { x: x_offset, y: y_offset });
+          emblem_sprites.mut_drakes().mut_transform().translate_to(Vector2 { x: -x_offset, y: y_offset });
+
+          render_sprite(emblem_sprites.snakes(), &camera, &quad_shader_program)?;
+          render_sprite(emblem_sprites.drakes(), &camera, &quad_shader_program)?;
+        }
       }
     }
+
+    text_renderer.draw(&camera, &glyph_shader_program)?;
+
+    overlay_particles.update(&deltatime);
+    particle_batch.draw(&overlay_particles, &overlay_camera, &particle_shader_program)?;
   
     window.gl_swap_window();
     let frame_duration = Instant::now() - frame_start;
@@ -643,75 +1354,23 @@ fn main() -> Result<(), String> {
     }
   }
 
-  Ok(())
-}
-
-fn update_add_high_score(message_queue: &mut MessageQueue, playfield_state: &PlayfieldState, high_scores_file_path: &Path, typing_status: &mut TypingStatus, typing_buffer: &String, camera: &mut Camera, displayed_text: &mut String, displayed_text_sprite: &mut Sprite, font: &Font, text_color: &Color) -> Result<(), String> {
-  match typing_status {
-    TypingStatus::NotTyping => { *typing_status = TypingStatus::TypingStarted },
-    TypingStatus::TypingStarted => *typing_status = TypingStatus::Typing,
-    TypingStatus::Typing => {},
-
-    TypingStatus::TypingEnded => {
-      let new_score = HighScoresListing::from(typing_buffer.clone(), playfield_state.map.score.current());
-      save_high_score(high_scores_file_path, &new_score)?;
-      *typing_status = TypingStatus::NotTyping;
-      message_queue.post(Message::RequestScene(Scenes::MainMenu));
-    }
-  }
-  
-  camera.transform.translate_to(Vector2::new());
-  
-  if displayed_text != typing_buffer {
-    *displayed_text = typing_buffer.clone();
-    
-    if displayed_text.is_empty() {
-      *displayed_text_sprite = Sprite::print(&" ".to_string(), &font, &text_color)?;
-    } else {
-      *displayed_text_sprite = Sprite::print(&displayed_text, &font, &text_color)?;
-    }
-  }
-
-  Ok(())
-}
+  print!("{}", print_session_statistics(&session_statistics.borrow()));
 
-fn update_save_game(message_queue: &mut MessageQueue, playfield_state: &PlayfieldState, typing_status: &mut TypingStatus, typing_buffer: &String) -> Result<(), String> {
-  match typing_status {
-    TypingStatus::NotTyping => *typing_status = TypingStatus::TypingStarted,
-    TypingStatus::TypingStarted => *typing_status = TypingStatus::Typing,
-
-    TypingStatus::TypingEnded => {
-      let mut path_buffer = std::path::PathBuf::new();
-      path_buffer.push("./saves/");
-      
-      let mut text_input = typing_buffer.clone();
-      text_input.push_str(".txt");
-      path_buffer.push(text_input);
-      
-      validate_saves_directory(Path::new("./saves"))?;
-      let contents = serialize_map(&playfield_state.map);
-      std::fs::write(path_buffer.as_path(), contents).map_err(| error | error.to_string())?;
-
-      *typing_status = TypingStatus::NotTyping;
-      message_queue.post(Message::RequestScene(Scenes::Pause));
-    },
-
-    TypingStatus::Typing => {}
-  }
-  
   Ok(())
 }
 
 struct Transform {
   location: Vector2,
-  rotation: f32
+  rotation: f32,
+  scale: f32
 }
 
 impl Transform {
   fn new() -> Self {
     Self {
       location: Vector2::new(),
-      rotation: 0.0
+      rotation: 0.0,
+      scale: 1.0
     }
   }
 
@@ -720,10 +1379,10 @@ impl Transform {
 
     let rotation_radians = std::f32::consts::PI / 180.0 * self.rotation;
 
-    transform_matrix.x.x = f32::cos(rotation_radians);
-    transform_matrix.x.y = -f32::sin(rotation_radians);
-    transform_matrix.y.x = f32::sin(rotation_radians);
-    transform_matrix.y.y = f32::cos(rotation_radians);
+    transform_matrix.x.x = f32::cos(rotation_radians) * self.scale;
+    transform_matrix.x.y = -f32::sin(rotation_radians) * self.scale;
+    transform_matrix.y.x = f32::sin(rotation_radians) * self.scale;
+    transform_matrix.y.y = f32::cos(rotation_radians) * self.scale;
 
     transform_matrix.x.w += self.location.x;
     transform_matrix.y.w += self.location.y;
@@ -754,112 +1413,96 @@ impl Transform {
   fn translate_to(&mut self, location: Vector2) {
     self.location = location;
   }
+
+  fn scale_to(&mut self, scale: f32) {
+    self.scale = scale;
+  }
 }
 
 struct Camera {
   transform: Transform,
-  projection_matrix: Matrix4
+  projection_matrix: Matrix4,
+  viewport: Viewport,
+  pixel_ratio: f32,
+  base_half_view_size: Vector2,
+  half_view_size: Vector2
 }
 
 impl Camera {
-  fn new(resolution: Resolution) -> Self {
-    let transform = Transform::new();
-    let projection_matrix = calculate_projection_matrix(
-      -(resolution.width() as f32 / 2.0),
-      resolution.width() as f32 / 2.0,
-      resolution.height() as f32 / 2.0,
-      -(resolution.height() as f32 / 2.0),
-      1.0, -1.0
-    );
-
+  fn new() -> Self {
     Self {
-      transform,
-      projection_matrix
+      transform: Transform::new(),
+      projection_matrix: Matrix4::identity(),
+      viewport: Viewport::new(0, 0, 1, 1),
+      pixel_ratio: 1.0,
+      base_half_view_size: Vector2::new(),
+      half_view_size: Vector2::new()
     }
   }
-  
-  fn view_matrix(&self) -> Matrix4 {
-    let mut reversed_transform = Transform::new();
-    reversed_transform.translate_to(-self.transform.location);
 
-    reversed_transform.matrix()
+  fn resize(&mut self, view_width: f32, view_height: f32, viewport: Viewport, pixel_ratio: f32) {
+    self.base_half_view_size = Vector2 { x: view_width / 2.0, y: view_height / 2.0 };
+    self.viewport = viewport;
+    self.pixel_ratio = pixel_ratio;
+    self.rebuild_projection();
   }
 
-  fn projection_matrix(&self) -> &Matrix4 {
-    &self.projection_matrix
+  fn zoom_to(&mut self, zoom: f32) {
+    if self.transform.scale == zoom { return }
+
+    self.transform.scale_to(zoom);
+    self.rebuild_projection();
   }
-}
 
-struct Animation {
-  played_duration: Duration,
-  total_duration: Duration,
-  vertex_array: VertexArray,
-  texture: Texture,
-  frame_index: u32,
-  transform: Transform,
-  frame_count: u32
-}
+  fn rebuild_projection(&mut self) {
+    let half_width = self.base_half_view_size.x / self.transform.scale;
+    let half_height = self.base_half_view_size.y / self.transform.scale;
 
-impl Animation {
-  fn load(file_path: &Path, frame_count: u32) -> Result<Self, String> {
-    let played_duration = Duration::from_secs(0);
+    self.projection_matrix = calculate_projection_matrix(
+      -half_width,
+      half_width,
+      half_height,
+      -half_height,
+      1.0, -1.0
+    );
 
-    let frame_duration = Duration::from_secs(1) / 12;
-    let total_duration = frame_duration * frame_count;
+    self.half_view_size = Vector2 { x: half_width, y: half_height };
+  }
 
-    let texture = Texture::load(file_path)?;
+  fn visible_bounds(&self) -> (Vector2, Vector2) {
+    let location = self.transform.location;
 
-    let vertex_data = generate_animation_vertex_data(texture.width() / frame_count, texture.height(), frame_count);
-    
-    let vertex_buffer = VertexBuffer::new(vertex_data);
-    let element_buffer = ElementBuffer::new(vec![0, 1, 2, 0, 2, 3]);
-    let vertex_array = VertexArray::new(&vertex_buffer, &element_buffer);
-
-    let frame_index = 0;
-
-    let transform = Transform::new();
-
-    Ok(
-      Self {
-        played_duration,
-        total_duration,
-        vertex_array,
-        texture,
-        frame_index,
-        transform,
-        frame_count
-      }
+    (
+      Vector2 { x: location.x - self.half_view_size.x, y: location.y - self.half_view_size.y },
+      Vector2 { x: location.x + self.half_view_size.x, y: location.y + self.half_view_size.y }
     )
   }
+  
+  fn view_matrix(&self) -> Matrix4 {
+    let mut reversed_transform = Transform::new();
+    reversed_transform.translate_to(-self.transform.location);
 
-  fn update(&mut self, deltatime: &Duration) {
-    self.played_duration += *deltatime;
-    
-    while self.played_duration > self.total_duration {
-      self.played_duration -= self.total_duration;
-    }
-    
-    self.frame_index = (self.played_duration.as_millis() * self.frame_count as u128 / self.total_duration.as_millis()) as u32;
+    reversed_transform.matrix()
   }
-}
 
-fn render_animation(stars_animation: &Animation, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
-  shader_program.activate();
-  shader_program.set_model_matrix(&stars_animation.transform.matrix())?;
-  shader_program.set_view_matrix(&camera.view_matrix())?;
-  shader_program.set_projection_matrix(camera.projection_matrix())?;
-  shader_program.set_uniform_uint("frameIndex".to_string(), &stars_animation.frame_index)?;
-  shader_program.set_uniform_uint("frameCount".to_string(), &stars_animation.frame_count)?;
-        
-  unsafe {
-    gl::Uniform1ui(2, 0);
-    gl::BindVertexArray(stars_animation.vertex_array.id());
-    gl::BindTexture(gl::TEXTURE_2D, stars_animation.texture.id());
-    gl::DrawElements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, std::ptr::null());
-    gl::BindVertexArray(0);
+  fn projection_matrix(&self) -> &Matrix4 {
+    &self.projection_matrix
   }
 
-  Ok(())
+  fn screen_to_world(&self, screen_x: i32, screen_y: i32) -> Option<Vector2> {
+    let drawable_x = screen_x as f32 * self.pixel_ratio;
+    let drawable_y = screen_y as f32 * self.pixel_ratio;
+    if !self.viewport.contains(drawable_x, drawable_y) { return None }
+
+    unproject(
+      drawable_x - self.viewport.x as f32,
+      drawable_y - self.viewport.y as f32,
+      self.viewport.width as f32,
+      self.viewport.height as f32,
+      &self.view_matrix(),
+      &self.projection_matrix
+    )
+  }
 }
 
 #[derive(PartialEq, Debug)]
@@ -871,9 +1514,16 @@ enum MainMenuItem {
   Quit
 }
 
-fn render_add_high_score(enter_name_sprite: &Sprite, displayed_text_sprite: &Sprite, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
-  render_sprite(enter_name_sprite, camera, shader_program)?;
-  render_sprite(displayed_text_sprite, camera, shader_program)?;
+fn measure_widths(text_renderer: &mut TextRenderer, font: &sdl2::ttf::Font, texts: impl Iterator<Item = String>, text_style: &TextStyle) -> Result<Vec<f32>, String> {
+  texts.map(|text| text_renderer.measure(font, &text, text_style).map(|layout| layout.width)).collect()
+}
 
-  Ok(())
-}
\ No newline at end of file
+fn apply_new_game_defaults(new_game_state: &mut NewGameState, settings: &Settings) {
+  new_game_state.default_width = settings.default_width;
+  new_game_state.default_height = settings.default_height;
+  new_game_state.default_num_snakes = settings.default_num_snakes;
+
+  if new_game_state.step == NewGameStep::Width {
+    new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
+  }
+}
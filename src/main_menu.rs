@@ -5,36 +5,46 @@ use crate::{
   Scenes,
   MainMenuState,
   MainMenuItem,
-  Vector2,
-  Camera,
-  sprites
+  PointerButton,
+  menu_item_at
 };
 
-pub fn update_main_menu(message_queue: &mut MessageQueue, main_menu_state: &mut MainMenuState, camera: &mut Camera, main_menu_sprites: &sprites::MainMenu, emblem_sprites: &mut sprites::Emblems) {
+pub fn update_main_menu(message_queue: &mut MessageQueue, main_menu_state: &mut MainMenuState) {
   let mut confirmed = false;
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = *message { match input {
-      Input::Up => main_menu_state.selected_menu_item = match main_menu_state.selected_menu_item {
-        MainMenuItem::NewGame => MainMenuItem::NewGame,
-        MainMenuItem::LoadGame => MainMenuItem::NewGame,
-        MainMenuItem::HighScores => MainMenuItem::LoadGame,
-        MainMenuItem::Settings => MainMenuItem::HighScores,
-        MainMenuItem::Quit => MainMenuItem::Settings
-      },
+    match *message {
+      Message::PointerMoved(x, y) => if let Some(index) = menu_item_at(x, y, 0, &main_menu_state.item_widths) { main_menu_state.selected_menu_item = main_menu_item(index) },
 
-      Input::Down => main_menu_state.selected_menu_item = match main_menu_state.selected_menu_item {
-        MainMenuItem::NewGame => MainMenuItem::LoadGame,
-        MainMenuItem::LoadGame => MainMenuItem::HighScores,
-        MainMenuItem::HighScores => MainMenuItem::Settings,
-        MainMenuItem::Settings => MainMenuItem::Quit,
-        MainMenuItem::Quit => MainMenuItem::Quit
+      Message::PointerPressed(x, y, PointerButton::Left) => if let Some(index) = menu_item_at(x, y, 0, &main_menu_state.item_widths) {
+        main_menu_state.selected_menu_item = main_menu_item(index);
+        confirmed = true;
       },
 
-      Input::Confirm => confirmed = true,
+      Message::PlayerInput(input) => match input {
+        Input::Up => main_menu_state.selected_menu_item = match main_menu_state.selected_menu_item {
+          MainMenuItem::NewGame => MainMenuItem::NewGame,
+          MainMenuItem::LoadGame => MainMenuItem::NewGame,
+          MainMenuItem::HighScores => MainMenuItem::LoadGame,
+          MainMenuItem::Settings => MainMenuItem::HighScores,
+          MainMenuItem::Quit => MainMenuItem::Settings
+        },
+
+        Input::Down => main_menu_state.selected_menu_item = match main_menu_state.selected_menu_item {
+          MainMenuItem::NewGame => MainMenuItem::LoadGame,
+          MainMenuItem::LoadGame => MainMenuItem::HighScores,
+          MainMenuItem::HighScores => MainMenuItem::Settings,
+          MainMenuItem::Settings => MainMenuItem::Quit,
+          MainMenuItem::Quit => MainMenuItem::Quit
+        },
+
+        Input::Confirm => confirmed = true,
       
+        _ => {}
+      },
+
       _ => {}
-    }}
+    }
   }
 
   if confirmed {
@@ -46,32 +56,14 @@ pub fn update_main_menu(message_queue: &mut MessageQueue, main_menu_state: &mut
       MainMenuItem::Quit => message_queue.post(Message::RequestShutdown)
     }
   }
-  
-  camera.transform.translate_to(Vector2::new());
-  
-  let x_offset = match main_menu_state.selected_menu_item {
-    MainMenuItem::NewGame => main_menu_sprites.new_game().texture().width() / 2 + 32,
-    MainMenuItem::LoadGame => main_menu_sprites.load_game().texture().width() / 2 + 32,
-    MainMenuItem::HighScores => main_menu_sprites.high_scores().texture().width() / 2 + 32,
-    MainMenuItem::Settings => main_menu_sprites.settings().texture().width() / 2 + 32,
-    MainMenuItem::Quit => main_menu_sprites.quit().texture().width() / 2 + 32,
-  };
-  
-  let y_offset = match main_menu_state.selected_menu_item {
-    MainMenuItem::NewGame => 0,
-    MainMenuItem::LoadGame => 32,
-    MainMenuItem::HighScores => 64,
-    MainMenuItem::Settings => 96,
-    MainMenuItem::Quit => 128,
-  };
-  
-  emblem_sprites.mut_snakes().mut_transform().translate_to(Vector2 {
-    x: x_offset as f32,
-    y: y_offset as f32
-  });
-  
-  emblem_sprites.mut_drakes().mut_transform().translate_to(Vector2 {
-    x: -(x_offset as f32),
-    y: y_offset as f32
-  });
-}
\ No newline at end of file
+}
+
+fn main_menu_item(index: usize) -> MainMenuItem {
+  match index {
+    0 => MainMenuItem::NewGame,
+    1 => MainMenuItem::LoadGame,
+    2 => MainMenuItem::HighScores,
+    3 => MainMenuItem::Settings,
+    _ => MainMenuItem::Quit
+  }
+}
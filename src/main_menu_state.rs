@@ -1,13 +1,15 @@
 use crate::MainMenuItem;
 
 pub struct MainMenuState {
-  pub selected_menu_item: MainMenuItem
+  pub selected_menu_item: MainMenuItem,
+  pub item_widths: Vec<f32>
 }
 
 impl MainMenuState {
   pub fn new() -> Self {
     Self {
-      selected_menu_item: MainMenuItem::NewGame
+      selected_menu_item: MainMenuItem::NewGame,
+      item_widths: Vec::new()
     }
   }
 }
\ No newline at end of file
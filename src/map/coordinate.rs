@@ -1,6 +1,6 @@
 use crate::MapSize;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Coordinate {
   x: usize,
   y: usize,
@@ -1,6 +1,7 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Direction {
   North,
   West,
   East,
   South
-}
\ No newline at end of file
+}
@@ -4,7 +4,7 @@ use crate::{
   Score
 };
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Map {
   pub size: MapSize,
   pub player_location: Coordinate,
@@ -1,5 +1,6 @@
 use crate::Vector4;
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Matrix4 {
   pub x: Vector4,
   pub y: Vector4,
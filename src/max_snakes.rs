@@ -0,0 +1,22 @@
+use crate::MapSize;
+
+pub fn max_snakes(size: &MapSize) -> usize {
+  let snake_tiles = (size.width() - 1) * (size.height() - 1);
+  let start_and_goal_tiles = 18;
+  let path_tiles = size.width().max(size.height());
+
+  snake_tiles.saturating_sub(start_and_goal_tiles + path_tiles)
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::MapSize;
+  use super::max_snakes;
+
+  #[test]
+  fn leaves_room_for_the_start_goal_and_path() {
+    assert_eq!(max_snakes(&MapSize::new()), 0);
+    assert_eq!(max_snakes(&MapSize::from(8, 8).unwrap()), 23);
+    assert_eq!(max_snakes(&MapSize::from(16, 8).unwrap()), 71);
+  }
+}
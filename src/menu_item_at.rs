@@ -0,0 +1,42 @@
+pub fn menu_item_at(world_x: i32, world_y: i32, first_item_y: i32, item_widths: &[f32]) -> Option<usize> {
+  let offset = world_y - first_item_y + 16;
+  if offset < 0 { return None }
+
+  let index = offset as usize / 32;
+  match item_widths.get(index) {
+    Some(width) if (world_x as f32).abs() <= width / 2.0 => Some(index),
+    _ => None
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::menu_item_at;
+
+  const WIDTHS: [f32; 5] = [64.0; 5];
+
+  #[test]
+  fn finds_rows_around_their_center() {
+    assert_eq!(menu_item_at(0, 0, 0, &WIDTHS), Some(0));
+    assert_eq!(menu_item_at(0, -16, 0, &WIDTHS), Some(0));
+    assert_eq!(menu_item_at(0, 16, 0, &WIDTHS), Some(1));
+    assert_eq!(menu_item_at(0, 140, 64, &WIDTHS[..3]), Some(2));
+  }
+
+  #[test]
+  fn ignores_positions_outside_the_menu() {
+    assert_eq!(menu_item_at(0, -17, 0, &WIDTHS), None);
+    assert_eq!(menu_item_at(0, 144, 0, &WIDTHS), None);
+    assert_eq!(menu_item_at(0, 0, 0, &[]), None);
+  }
+
+  #[test]
+  fn only_hits_the_measured_row_width() {
+    let widths = [64.0, 128.0];
+
+    assert_eq!(menu_item_at(-32, 0, 0, &widths), Some(0));
+    assert_eq!(menu_item_at(33, 0, 0, &widths), None);
+    assert_eq!(menu_item_at(60, 32, 0, &widths), Some(1));
+    assert_eq!(menu_item_at(-400, 32, 0, &widths), None);
+  }
+}
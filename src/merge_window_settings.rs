@@ -0,0 +1,53 @@
+use crate::Settings;
+
+pub fn merge_window_settings(window_settings: &Settings, saved_settings: &Settings, new_settings: &Settings) -> Settings {
+  let mut merged_settings = *new_settings;
+
+  if new_settings.window_mode == saved_settings.window_mode { merged_settings.window_mode = window_settings.window_mode; }
+  if new_settings.resolution == saved_settings.resolution { merged_settings.resolution = window_settings.resolution; }
+
+  merged_settings
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Settings,
+    WindowMode,
+    Resolution
+  };
+
+  use super::merge_window_settings;
+
+  #[test]
+  fn keeps_overrides_for_unrelated_changes() {
+    let saved_settings = Settings::new();
+    let mut window_settings = saved_settings;
+    window_settings.window_mode = WindowMode::Windowed;
+    window_settings.resolution = Resolution::new(640, 480);
+
+    let mut new_settings = saved_settings;
+    new_settings.fps_cap = 30;
+
+    let merged_settings = merge_window_settings(&window_settings, &saved_settings, &new_settings);
+
+    assert_eq!(merged_settings.fps_cap, 30);
+    assert_eq!(merged_settings.window_mode, WindowMode::Windowed);
+    assert_eq!(merged_settings.resolution, Resolution::new(640, 480));
+  }
+
+  #[test]
+  fn applies_changed_window_settings() {
+    let saved_settings = Settings::new();
+    let mut window_settings = saved_settings;
+    window_settings.resolution = Resolution::new(640, 480);
+
+    let mut new_settings = saved_settings;
+    new_settings.window_mode = WindowMode::Borderless;
+
+    let merged_settings = merge_window_settings(&window_settings, &saved_settings, &new_settings);
+
+    assert_eq!(merged_settings.window_mode, WindowMode::Borderless);
+    assert_eq!(merged_settings.resolution, Resolution::new(640, 480));
+  }
+}
@@ -1,11 +1,22 @@
+use sdl2::keyboard::Keycode;
+
 use crate::{
   Scenes,
-  Input
+  Input,
+  GameEvent,
+  Settings,
+  PointerButton
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Message {
   RequestShutdown,
   RequestScene(Scenes),
-  PlayerInput(Input)
-}
\ No newline at end of file
+  PlayerInput(Input),
+  RepeatedInput(Input),
+  GameEvent(GameEvent),
+  SettingsChanged(Settings),
+  KeyCaptured(Keycode),
+  PointerMoved(i32, i32),
+  PointerPressed(i32, i32, PointerButton)
+}
@@ -1,15 +1,28 @@
-use crate::Message;
+use std::{
+  cell::RefCell,
+  rc::{
+    Rc,
+    Weak
+  }
+};
+
+use crate::{
+  Message,
+  Subscriber
+};
 
 pub struct MessageQueue {
   messages: Vec<Message>,
-  messages_buffer: Vec<Message>
+  messages_buffer: Vec<Message>,
+  subscribers: Vec<Weak<RefCell<dyn Subscriber>>>
 }
 
 impl MessageQueue {
   pub fn new() -> Self {
     Self {
       messages: Vec::new(),
-      messages_buffer: Vec::new()
+      messages_buffer: Vec::new(),
+      subscribers: Vec::new()
     }
   }
 
@@ -25,6 +38,20 @@ impl MessageQueue {
     }
 
     self.messages_buffer = Vec::new();
+
+    self.subscribers.retain(| subscriber | subscriber.strong_count() > 0);
+    for subscriber in &self.subscribers {
+      if let Some(subscriber) = subscriber.upgrade() {
+        for message in &self.messages {
+          subscriber.borrow_mut().receive(message);
+        }
+      }
+    }
+  }
+
+  pub fn subscribe<T: Subscriber + 'static>(&mut self, subscriber: &Rc<RefCell<T>>) {
+    let subscriber: Rc<RefCell<dyn Subscriber>> = subscriber.clone();
+    self.subscribers.push(Rc::downgrade(&subscriber));
   }
 
   pub fn messages(&self) -> &Vec<Message> {
@@ -34,11 +61,31 @@ impl MessageQueue {
 
 #[cfg(test)]
 mod testing {
+  use std::{
+    cell::RefCell,
+    rc::Rc
+  };
+
+  use crate::{
+    GameEvent,
+    Subscriber
+  };
+
   use super::{
     Message,
     MessageQueue
   };
 
+  struct EventCounter {
+    count: usize
+  }
+
+  impl Subscriber for EventCounter {
+    fn receive(&mut self, message: &Message) {
+      if let Message::GameEvent(_) = message { self.count += 1 }
+    }
+  }
+
   #[test]
   fn posting() {
     let mut message_queue = MessageQueue::new();
@@ -69,4 +116,40 @@ mod testing {
     assert_eq!(message_queue.messages.len(), 1);
     assert_eq!(message_queue.messages_buffer.len(), 0);
   }
+
+  #[test]
+  fn notifies_subscribers_on_swap() {
+    let mut message_queue = MessageQueue::new();
+    let counter = Rc::new(RefCell::new(EventCounter { count: 0 }));
+    message_queue.subscribe(&counter);
+
+    message_queue.post(Message::GameEvent(GameEvent::TileRevealed(3)));
+    message_queue.post(Message::RequestShutdown);
+    message_queue.post(Message::GameEvent(GameEvent::ScoreChanged(2)));
+
+    assert_eq!(counter.borrow().count, 0);
+
+    message_queue.swap_buffers();
+
+    assert_eq!(counter.borrow().count, 2);
+
+    message_queue.swap_buffers();
+
+    assert_eq!(counter.borrow().count, 2);
+  }
+
+  #[test]
+  fn drops_released_subscribers() {
+    let mut message_queue = MessageQueue::new();
+    let counter = Rc::new(RefCell::new(EventCounter { count: 0 }));
+    message_queue.subscribe(&counter);
+
+    assert_eq!(message_queue.subscribers.len(), 1);
+
+    drop(counter);
+    message_queue.post(Message::GameEvent(GameEvent::TileRevealed(3)));
+    message_queue.swap_buffers();
+
+    assert_eq!(message_queue.subscribers.len(), 0);
+  }
 }
\ No newline at end of file
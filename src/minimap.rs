@@ -0,0 +1,124 @@
+use image::RgbaImage;
+
+use crate::{
+  Map,
+  MapSize,
+  Coordinate,
+  Camera,
+  ShaderProgram,
+  Sprite,
+  Texture,
+  Vector2,
+  minimap_color,
+  render_sprite
+};
+
+const MINIMAP_SIZE: u32 = 192;
+const MINIMAP_MARGIN: f32 = 16.0;
+
+pub struct Minimap {
+  is_visible: bool,
+  needs_rebuild: bool,
+  tile_pixels: u32,
+  image: RgbaImage,
+  sprite: Option<Sprite>
+}
+
+impl Minimap {
+  pub fn new() -> Self {
+    Self {
+      is_visible: true,
+      needs_rebuild: true,
+      tile_pixels: 1,
+      image: RgbaImage::new(1, 1),
+      sprite: None
+    }
+  }
+
+  pub fn toggle(&mut self) {
+    self.is_visible = !self.is_visible;
+  }
+
+  pub fn invalidate(&mut self) {
+    self.needs_rebuild = true;
+  }
+
+  pub fn sync(&mut self, map: &Map, changed_tiles: &[usize]) {
+    if self.needs_rebuild || self.sprite.is_none() {
+      self.rebuild(map);
+      return;
+    }
+
+    for index in changed_tiles.iter().filter(| index | **index < map.size.array_length()) {
+      let (x, y) = self.paint_tile(map, *index);
+
+      if let Some(sprite) = &self.sprite {
+        let tile_image = image::imageops::crop_imm(&self.image, x, y, self.tile_pixels, self.tile_pixels).to_image();
+        sprite.texture().update_region(x, y, &tile_image);
+      }
+    }
+  }
+
+  fn rebuild(&mut self, map: &Map) {
+    self.tile_pixels = (MINIMAP_SIZE / map.size.width().max(map.size.height()) as u32).max(1);
+    self.image = RgbaImage::new(map.size.width() as u32 * self.tile_pixels, map.size.height() as u32 * self.tile_pixels);
+
+    for index in 0..map.size.array_length() {
+      self.paint_tile(map, index);
+    }
+
+    self.sprite = Some(Sprite::from_texture(Texture::from_rgba(&self.image)));
+    self.needs_rebuild = false;
+  }
+
+  fn paint_tile(&mut self, map: &Map, index: usize) -> (u32, u32) {
+    let coordinate = Coordinate::from_index(index, &map.size);
+    let left = coordinate.x() as u32 * self.tile_pixels;
+    let top = coordinate.y() as u32 * self.tile_pixels;
+    let color = minimap_color(map, index);
+
+    for y in top..top + self.tile_pixels {
+      for x in left..left + self.tile_pixels {
+        self.image.put_pixel(x, y, color);
+      }
+    }
+
+    (left, top)
+  }
+
+  pub fn place(&mut self, half_view_size: Vector2) {
+    if let Some(sprite) = &mut self.sprite {
+      let location = Vector2 {
+        x: half_view_size.x - MINIMAP_MARGIN - sprite.width() as f32 / 2.0,
+        y: -half_view_size.y + MINIMAP_MARGIN + self.image.height() as f32 / 2.0
+      };
+
+      sprite.mut_transform().translate_to(location);
+    }
+  }
+
+  pub fn coordinate_at(&self, world: Vector2, map_size: &MapSize) -> Option<Coordinate> {
+    if !self.is_visible { return None }
+    let sprite = self.sprite.as_ref()?;
+
+    let left = sprite.transform().location.x - self.image.width() as f32 / 2.0;
+    let top = sprite.transform().location.y - self.image.height() as f32 / 2.0;
+    let local_x = world.x - left;
+    let local_y = world.y - top;
+
+    if local_x < 0.0 || local_y < 0.0 || local_x >= self.image.width() as f32 || local_y >= self.image.height() as f32 { return None }
+
+    let x = (local_x as u32 / self.tile_pixels) as usize;
+    let y = (local_y as u32 / self.tile_pixels) as usize;
+    if x >= map_size.width() || y >= map_size.height() { return None }
+
+    Some(Coordinate::from(x, y, map_size))
+  }
+
+  pub fn render(&self, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
+    match &self.sprite {
+      Some(sprite) if self.is_visible => render_sprite(sprite, camera, shader_program),
+      _ => Ok(())
+    }
+  }
+}
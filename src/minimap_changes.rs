@@ -0,0 +1,57 @@
+use crate::{
+  Message,
+  GameEvent,
+  Subscriber
+};
+
+pub struct MinimapChanges {
+  changed_tiles: Vec<usize>
+}
+
+impl MinimapChanges {
+  pub fn new() -> Self {
+    Self {
+      changed_tiles: Vec::new()
+    }
+  }
+
+  pub fn take(&mut self) -> Vec<usize> {
+    std::mem::take(&mut self.changed_tiles)
+  }
+}
+
+impl Subscriber for MinimapChanges {
+  fn receive(&mut self, message: &Message) {
+    if let Message::GameEvent(event) = message { match event {
+      GameEvent::PlayerMoved(from, to) => self.changed_tiles.extend_from_slice(&[*from, *to]),
+      GameEvent::TileRevealed(index) => self.changed_tiles.push(*index),
+      GameEvent::MarkToggled(index, _) => self.changed_tiles.push(*index),
+
+      _ => {}
+    }}
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Message,
+    GameEvent,
+    Subscriber
+  };
+
+  use super::MinimapChanges;
+
+  #[test]
+  fn collects_changed_tiles() {
+    let mut minimap_changes = MinimapChanges::new();
+
+    minimap_changes.receive(&Message::GameEvent(GameEvent::PlayerMoved(0, 1)));
+    minimap_changes.receive(&Message::GameEvent(GameEvent::TileRevealed(1)));
+    minimap_changes.receive(&Message::GameEvent(GameEvent::ScoreChanged(3)));
+    minimap_changes.receive(&Message::GameEvent(GameEvent::MarkToggled(9, false)));
+
+    assert_eq!(minimap_changes.take(), vec![0, 1, 1, 9]);
+    assert_eq!(minimap_changes.take(), Vec::<usize>::new());
+  }
+}
@@ -0,0 +1,44 @@
+use image::Rgba;
+
+use crate::Map;
+
+pub fn minimap_color(map: &Map, index: usize) -> Rgba<u8> {
+  if map.player_location.array_index() == index { return Rgba([224, 96, 32, 255]) }
+  if map.goal_location.array_index() == index { return Rgba([240, 208, 64, 255]) }
+  if map.is_marked.get(index) == Some(&true) { return Rgba([192, 32, 32, 255]) }
+  if map.is_explored.get(index) == Some(&true) { return Rgba([96, 160, 64, 255]) }
+
+  Rgba([32, 40, 32, 255])
+}
+
+#[cfg(test)]
+mod testing {
+  use image::Rgba;
+
+  use crate::Map;
+
+  use super::minimap_color;
+
+  fn open_map() -> Map {
+    let mut map = Map::new();
+    map.goal_location.set_array_index(15, &map.size);
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_explored[0] = true;
+    map.is_explored[1] = true;
+    map.is_marked[2] = true;
+
+    map
+  }
+
+  #[test]
+  fn colors_tiles_by_what_the_player_knows() {
+    let map = open_map();
+
+    assert_eq!(minimap_color(&map, 0), Rgba([224, 96, 32, 255]));
+    assert_eq!(minimap_color(&map, 1), Rgba([96, 160, 64, 255]));
+    assert_eq!(minimap_color(&map, 2), Rgba([192, 32, 32, 255]));
+    assert_eq!(minimap_color(&map, 3), Rgba([32, 40, 32, 255]));
+    assert_eq!(minimap_color(&map, 15), Rgba([240, 208, 64, 255]));
+  }
+}
@@ -1,10 +1,12 @@
 use crate::{
   Map,
   Direction,
-  Coordinate
+  Coordinate,
+  GameEvent
 };
 
-pub fn move_player(map: &mut Map, direction: Direction) {
+pub fn move_player(map: &mut Map, direction: Direction) -> Vec<GameEvent> {
+  let mut events = Vec::new();
   let mut target = Coordinate::from_index(map.player_location.array_index(), &map.size);
 
   match direction {
@@ -33,14 +35,24 @@ pub fn move_player(map: &mut Map, direction: Direction) {
     }
   }
 
-  if !map.is_marked[target.array_index()] {
+  if !map.is_marked[target.array_index()] && target.array_index() != map.player_location.array_index() {
+    events.push(GameEvent::PlayerMoved(map.player_location.array_index(), target.array_index()));
     map.player_location.set_array_index(target.array_index(), &map.size);
 
     if !map.is_explored[target.array_index()] {
       map.is_explored[target.array_index()] = true;
-      *map.score.mut_current() += map.hint[target.array_index()];
+      events.push(GameEvent::TileRevealed(target.array_index()));
+
+      if map.hint[target.array_index()] > 0 {
+        *map.score.mut_current() += map.hint[target.array_index()];
+        events.push(GameEvent::ScoreChanged(map.score.current()));
+      }
     }
+  } else {
+    events.push(GameEvent::MoveBlocked(map.player_location.array_index(), direction));
   }
+
+  events
 }
 
 #[cfg(test)]
@@ -48,6 +60,7 @@ mod testing {
   use crate::{
     Map,
     Direction,
+    GameEvent
   };
   use super::move_player;
 
@@ -461,4 +474,52 @@ mod testing {
     assert_eq!(map.player_location.array_index(), 1);
     assert_eq!(map.score.current(), 0);
   }
+
+  #[test]
+  fn reports_gameplay_events() {
+    let mut map = Map::new();
+    map.player_location.set_array_index(5, &map.size);
+    map.goal_location.set_array_index(8, &map.size);
+
+    map.is_marked = vec![false; 16];
+
+    map.is_explored = vec![
+      false, false, false, false,
+      false,  true, false, false,
+      false, false, false, false,
+      false, false, false, false
+    ];
+
+    map.hint = vec![
+      0, 4, 0, 0,
+      0, 0, 0, 0,
+      0, 0, 0, 0,
+      0, 0, 0, 0
+    ];
+
+    let events = move_player(&mut map, Direction::North);
+
+    assert_eq!(events, vec![
+      GameEvent::PlayerMoved(5, 1),
+      GameEvent::TileRevealed(1),
+      GameEvent::ScoreChanged(4)
+    ]);
+
+    let events = move_player(&mut map, Direction::South);
+
+    assert_eq!(events, vec![GameEvent::PlayerMoved(1, 5)]);
+  }
+
+  #[test]
+  fn reports_blocked_moves() {
+    let mut map = Map::new();
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.hint = vec![0; 16];
+
+    map.is_marked[1] = true;
+
+    assert_eq!(move_player(&mut map, Direction::North), vec![GameEvent::MoveBlocked(0, Direction::North)]);
+    assert_eq!(move_player(&mut map, Direction::East), vec![GameEvent::MoveBlocked(0, Direction::East)]);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+use crate::{
+  Matrix4,
+  Vector4
+};
+
+pub fn multiply_matrices(left: &Matrix4, right: &Matrix4) -> Matrix4 {
+  let columns = [
+    Vector4::new(right.x.x, right.y.x, right.z.x, right.w.x),
+    Vector4::new(right.x.y, right.y.y, right.z.y, right.w.y),
+    Vector4::new(right.x.z, right.y.z, right.z.z, right.w.z),
+    Vector4::new(right.x.w, right.y.w, right.z.w, right.w.w)
+  ];
+
+  let row = | row: &Vector4 | Vector4::new(
+    dot(row, &columns[0]),
+    dot(row, &columns[1]),
+    dot(row, &columns[2]),
+    dot(row, &columns[3])
+  );
+
+  Matrix4 {
+    x: row(&left.x),
+    y: row(&left.y),
+    z: row(&left.z),
+    w: row(&left.w)
+  }
+}
+
+pub fn multiply_matrix_vector(matrix: &Matrix4, vector: &Vector4) -> Vector4 {
+  Vector4::new(
+    dot(&matrix.x, vector),
+    dot(&matrix.y, vector),
+    dot(&matrix.z, vector),
+    dot(&matrix.w, vector)
+  )
+}
+
+fn dot(left: &Vector4, right: &Vector4) -> f32 {
+  left.x * right.x + left.y * right.y + left.z * right.z + left.w * right.w
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Matrix4,
+    Vector4
+  };
+
+  use super::{
+    multiply_matrices,
+    multiply_matrix_vector
+  };
+
+  #[test]
+  fn identity_is_neutral() {
+    let mut matrix = Matrix4::identity();
+    matrix.x.w = 4.0;
+    matrix.y.x = 2.0;
+
+    assert_eq!(multiply_matrices(&Matrix4::identity(), &matrix), matrix);
+    assert_eq!(multiply_matrices(&matrix, &Matrix4::identity()), matrix);
+  }
+
+  #[test]
+  fn translation_moves_points() {
+    let mut matrix = Matrix4::identity();
+    matrix.x.w = 4.0;
+    matrix.y.w = -2.0;
+
+    assert_eq!(multiply_matrix_vector(&matrix, &Vector4::new(1.0, 1.0, 0.0, 1.0)), Vector4::new(5.0, -1.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn combines_translations() {
+    let mut first = Matrix4::identity();
+    first.x.w = 4.0;
+
+    let mut second = Matrix4::identity();
+    second.x.w = 3.0;
+    second.y.w = 1.0;
+
+    let combined = multiply_matrices(&first, &second);
+
+    assert_eq!(combined.x.w, 7.0);
+    assert_eq!(combined.y.w, 1.0);
+  }
+}
@@ -1,76 +1,80 @@
 use crate::{
   NewGameState,
-  Map,
+  GameSession,
+  GameParameters,
   MessageQueue,
   Message,
   Input,
   NewGameStep,
-  generate_map,
   MapSize,
-  Scenes
+  Scenes,
+  PointerButton,
+  menu_item_at,
+  new_game_options::{
+    MAP_SIZE_OPTIONS,
+    NUM_SNAKES_OPTIONS
+  }
 };
 
-pub fn update_new_game(new_game_state: &mut NewGameState, current_map: &mut Map, message_queue: &mut MessageQueue, rng: &mut rand::rngs::StdRng) -> Result<(), String> {
+pub fn update_new_game(new_game_state: &mut NewGameState, game_session: &mut GameSession, message_queue: &mut MessageQueue) -> Result<(), String> {
   let mut confirmed = false;
   let mut canceled = false;
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = message { match input {
-      Input::Up => if new_game_state.selected_menu_item_index > 0 { new_game_state.selected_menu_item_index -= 1 },
-      Input::Down => if new_game_state.selected_menu_item_index < 3 { new_game_state.selected_menu_item_index += 1 },
-      Input::Confirm => confirmed = true,
-      Input::Cancel => canceled = true,
+    match *message {
+      Message::PointerMoved(x, y) => if let Some(index) = menu_item_at(x, y, 0, &new_game_state.item_widths) { new_game_state.selected_menu_item_index = index },
+
+      Message::PointerPressed(x, y, PointerButton::Left) => if let Some(index) = menu_item_at(x, y, 0, &new_game_state.item_widths) {
+        new_game_state.selected_menu_item_index = index;
+        confirmed = true;
+      },
+
+      Message::PlayerInput(input) => match input {
+        Input::Up => if new_game_state.selected_menu_item_index > 0 { new_game_state.selected_menu_item_index -= 1 },
+        Input::Down => if new_game_state.selected_menu_item_index < 3 { new_game_state.selected_menu_item_index += 1 },
+        Input::Confirm => confirmed = true,
+        Input::Cancel => canceled = true,
+        _ => {}
+      },
+
       _ => {}
-    }}
+    }
   }
 
   if canceled {
     new_game_state.step = NewGameStep::Width;
-    new_game_state.selected_menu_item_index = 0;
+    new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
     message_queue.post(Message::RequestScene(Scenes::MainMenu));
   }
 
   if confirmed {
     match new_game_state.step {
       NewGameStep::Width => {
-        new_game_state.width = match new_game_state.selected_menu_item_index {
-          0 => 8,
-          1 => 16,
-          2 => 32,
-          3 => 64,
-          _ => 0
-        };
-
+        new_game_state.width = MAP_SIZE_OPTIONS.get(new_game_state.selected_menu_item_index).copied().unwrap_or(0);
         new_game_state.step = NewGameStep::Height;
-        new_game_state.selected_menu_item_index = 0;
+        new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
       },
 
       NewGameStep::Height => {
-        new_game_state.height = match new_game_state.selected_menu_item_index {
-          0 => 8,
-          1 => 16,
-          2 => 32,
-          3 => 64,
-          _ => 0
-        };
-
+        new_game_state.height = MAP_SIZE_OPTIONS.get(new_game_state.selected_menu_item_index).copied().unwrap_or(0);
         new_game_state.step = NewGameStep::NumSnakes;
-        new_game_state.selected_menu_item_index = 0;
+        new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
       },
 
       NewGameStep::NumSnakes => {
-        new_game_state.num_snakes = match new_game_state.selected_menu_item_index {
-          0 => 16,
-          1 => 32,
-          2 => 64,
-          3 => 128,
-          _ => 0
-        };
-        
-        *current_map = generate_map(MapSize::from(new_game_state.width, new_game_state.height)?, new_game_state.num_snakes, rng)?;
-        message_queue.post(Message::RequestScene(Scenes::Playfield));
-        new_game_state.step = NewGameStep::Width;
-        new_game_state.selected_menu_item_index = 0;
+        new_game_state.num_snakes = NUM_SNAKES_OPTIONS.get(new_game_state.selected_menu_item_index).copied().unwrap_or(0);
+
+        let parameters = GameParameters::new(MapSize::from(new_game_state.width, new_game_state.height)?, new_game_state.num_snakes);
+        match GameSession::new(parameters, game_session.next_seed()) {
+          Ok(new_game_session) => {
+            *game_session = new_game_session;
+            message_queue.post(Message::RequestScene(Scenes::Playfield));
+            new_game_state.step = NewGameStep::Width;
+            new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
+          },
+
+          Err(error) => log::warn!("{}", error)
+        }
       },
     }
   }
@@ -78,83 +82,50 @@ pub fn update_new_game(new_game_state: &mut NewGameState, current_map: &mut Map,
   Ok(())
 }
 
-pub fn print_new_game(new_game_state: &NewGameState) {
-  println!();
-  println!();
-  println!("Game Setup");
-  println!();
-
-  match new_game_state.step {
-    NewGameStep::Width => {
-      println!("Map width?");
-
-      if new_game_state.selected_menu_item_index == 0 { print!("  * ") } else { print!("    ") }
-      println!("8");
+pub fn print_new_game(new_game_state: &NewGameState) -> String {
+  let mut output = String::new();
 
-      if new_game_state.selected_menu_item_index == 1 { print!("  * ") } else { print!("    ") }
-      println!("16");
+  output.push_str("Game Setup\n\n");
 
-      if new_game_state.selected_menu_item_index == 2 { print!("  * ") } else { print!("    ") }
-      println!("32");
-
-      if new_game_state.selected_menu_item_index == 3 { print!("  * ") } else { print!("    ") }
-      println!("64");
-    },
-
-    NewGameStep::Height => {
-      println!("Map Height?");
-      if new_game_state.selected_menu_item_index == 0 { print!("  * ") } else { print!("    ") }
-      println!("8");
-
-      if new_game_state.selected_menu_item_index == 1 { print!("  * ") } else { print!("    ") }
-      println!("16");
-
-      if new_game_state.selected_menu_item_index == 2 { print!("  * ") } else { print!("    ") }
-      println!("32");
-
-      if new_game_state.selected_menu_item_index == 3 { print!("  * ") } else { print!("    ") }
-      println!("64");
-    },
-
-    NewGameStep::NumSnakes => {
-      println!("Number of snakes?");
-      if new_game_state.selected_menu_item_index == 0 { print!("  * ") } else { print!("    ") }
-      println!("16");
-
-      if new_game_state.selected_menu_item_index == 1 { print!("  * ") } else { print!("    ") }
-      println!("32");
+  let (question, options) = match new_game_state.step {
+    NewGameStep::Width => ("Map width?", MAP_SIZE_OPTIONS),
+    NewGameStep::Height => ("Map height?", MAP_SIZE_OPTIONS),
+    NewGameStep::NumSnakes => ("Number of snakes?", NUM_SNAKES_OPTIONS)
+  };
 
-      if new_game_state.selected_menu_item_index == 2 { print!("  * ") } else { print!("    ") }
-      println!("64");
+  output.push_str(question);
+  output.push('\n');
 
-      if new_game_state.selected_menu_item_index == 3 { print!("  * ") } else { print!("    ") }
-      println!("128");
-    }
+  for (index, option) in options.iter().enumerate() {
+    if new_game_state.selected_menu_item_index == index { output.push_str("  * ") } else { output.push_str("    ") }
+    output.push_str(&format!("{}\n", option));
   }
+
+  output
 }
 
 #[cfg(test)]
 mod testing {
-  use rand::SeedableRng;
   use crate::{
     NewGameState,
+    GameSession,
     Map,
     MessageQueue,
     Message,
     NewGameStep,
     Input,
-    Scenes
+    Scenes,
+    PointerButton
   };
   use super::update_new_game;
 
   #[test]
   fn standard_update_no_change() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -170,14 +141,13 @@ mod testing {
   #[test]
   fn down_input() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     message_queue.post(Message::PlayerInput(Input::Down));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 1);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -193,16 +163,15 @@ mod testing {
   #[test]
   fn avoid_down_input_at_bottom() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.selected_menu_item_index = 3;
 
     message_queue.post(Message::PlayerInput(Input::Down));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 3);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -218,16 +187,15 @@ mod testing {
   #[test]
   fn up_input() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.selected_menu_item_index = 1;
 
     message_queue.post(Message::PlayerInput(Input::Up));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -243,16 +211,15 @@ mod testing {
   #[test]
   fn avoid_up_input_at_top() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.selected_menu_item_index = 0;
 
     message_queue.post(Message::PlayerInput(Input::Up));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -268,14 +235,13 @@ mod testing {
   #[test]
   fn first_step_cancel() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     message_queue.post(Message::PlayerInput(Input::Down));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 1);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -290,7 +256,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Cancel));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -310,9 +276,8 @@ mod testing {
   #[test]
   fn second_step_cancel() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::Height;
     new_game_state.selected_menu_item_index = 1;
@@ -320,7 +285,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Cancel));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         message_queue.swap_buffers();
         assert_eq!(new_game_state.selected_menu_item_index, 0);
@@ -338,7 +303,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Cancel));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -358,14 +323,13 @@ mod testing {
   #[test]
   fn confirmed_width_8() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Height);
@@ -381,14 +345,13 @@ mod testing {
   #[test]
   fn confirmed_width_16() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     message_queue.post(Message::PlayerInput(Input::Down));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 1);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -403,7 +366,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Height);
@@ -419,15 +382,14 @@ mod testing {
   #[test]
   fn confirmed_width_32() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.selected_menu_item_index = 2;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Height);
@@ -443,15 +405,14 @@ mod testing {
   #[test]
   fn confirmed_width_64() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.selected_menu_item_index = 3;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Height);
@@ -467,15 +428,14 @@ mod testing {
   #[test]
   fn confirmed_height_8() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::Height;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
@@ -491,16 +451,15 @@ mod testing {
   #[test]
   fn confirmed_height_16() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::Height;
     new_game_state.selected_menu_item_index = 1;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
@@ -516,16 +475,15 @@ mod testing {
   #[test]
   fn confirmed_height_32() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::Height;
     new_game_state.selected_menu_item_index = 2;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
@@ -541,16 +499,15 @@ mod testing {
   #[test]
   fn confirmed_height_64() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::Height;
     new_game_state.selected_menu_item_index = 3;
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
@@ -566,9 +523,8 @@ mod testing {
   #[test]
   fn confirmed_num_snakes_16() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::NumSnakes;
     new_game_state.selected_menu_item_index = 0;
@@ -577,7 +533,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -588,7 +544,7 @@ mod testing {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
         assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::Playfield));
-        assert_ne!(current_map, Map::new());
+        assert_ne!(game_session.map(), &Map::new());
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -598,9 +554,8 @@ mod testing {
   #[test]
   fn confirmed_num_snakes_32() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::NumSnakes;
     new_game_state.selected_menu_item_index = 1;
@@ -609,7 +564,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -620,7 +575,7 @@ mod testing {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
         assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::Playfield));
-        assert_ne!(current_map, Map::new());
+        assert_ne!(game_session.map(), &Map::new());
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -630,9 +585,8 @@ mod testing {
   #[test]
   fn confirmed_num_snakes_64() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::NumSnakes;
     new_game_state.selected_menu_item_index = 2;
@@ -641,7 +595,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -652,7 +606,7 @@ mod testing {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
         assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::Playfield));
-        assert_ne!(current_map, Map::new());
+        assert_ne!(game_session.map(), &Map::new());
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -662,9 +616,8 @@ mod testing {
   #[test]
   fn confirmed_num_snakes_128() {
     let mut new_game_state = NewGameState::new();
-    let mut current_map = Map::new();
+    let mut game_session = GameSession::empty(1234);
     let mut message_queue = MessageQueue::new();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
 
     new_game_state.step = NewGameStep::NumSnakes;
     new_game_state.selected_menu_item_index = 3;
@@ -673,7 +626,7 @@ mod testing {
     message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    match update_new_game(&mut new_game_state, &mut current_map, &mut message_queue, &mut rng) {
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
       Ok(()) => {
         assert_eq!(new_game_state.selected_menu_item_index, 0);
         assert_eq!(new_game_state.step, NewGameStep::Width);
@@ -684,10 +637,103 @@ mod testing {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
         assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::Playfield));
-        assert_ne!(current_map, Map::new());
+        assert_ne!(game_session.map(), &Map::new());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn too_many_snakes_stays_on_the_snake_count() {
+    let mut new_game_state = NewGameState::new();
+    let mut game_session = GameSession::empty(1234);
+    let mut message_queue = MessageQueue::new();
+
+    new_game_state.step = NewGameStep::NumSnakes;
+    new_game_state.selected_menu_item_index = 3;
+    new_game_state.width = 8;
+    new_game_state.height = 8;
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
+      Ok(()) => {
+        assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
+
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages().len(), 0);
+        assert_eq!(game_session.map(), &Map::new());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn preselects_configured_defaults() {
+    let mut new_game_state = NewGameState::new();
+    let mut game_session = GameSession::empty(1234);
+    let mut message_queue = MessageQueue::new();
+
+    new_game_state.default_width = 32;
+    new_game_state.default_height = 16;
+    new_game_state.default_num_snakes = 64;
+    new_game_state.selected_menu_item_index = new_game_state.default_menu_item_index();
+    assert_eq!(new_game_state.selected_menu_item_index, 2);
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
+      Ok(()) => {
+        assert_eq!(new_game_state.width, 32);
+        assert_eq!(new_game_state.step, NewGameStep::Height);
+        assert_eq!(new_game_state.selected_menu_item_index, 1);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
+      Ok(()) => {
+        assert_eq!(new_game_state.height, 16);
+        assert_eq!(new_game_state.step, NewGameStep::NumSnakes);
+        assert_eq!(new_game_state.selected_menu_item_index, 2);
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
     }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn pointer_hovers_and_selects_options() {
+    let mut new_game_state = NewGameState::new();
+    new_game_state.item_widths = vec![32.0; 4];
+    let mut game_session = GameSession::empty(1234);
+    let mut message_queue = MessageQueue::new();
+
+    message_queue.post(Message::PointerMoved(0, 64));
+    message_queue.swap_buffers();
+
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
+      Ok(()) => assert_eq!(new_game_state.selected_menu_item_index, 2),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+
+    message_queue.post(Message::PointerPressed(0, 96, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    match update_new_game(&mut new_game_state, &mut game_session, &mut message_queue) {
+      Ok(()) => {
+        assert_eq!(new_game_state.width, 64);
+        assert_eq!(new_game_state.step, NewGameStep::Height);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
@@ -0,0 +1,2 @@
+pub const MAP_SIZE_OPTIONS: [usize; 4] = [8, 16, 32, 64];
+pub const NUM_SNAKES_OPTIONS: [usize; 4] = [16, 32, 64, 128];
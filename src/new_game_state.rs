@@ -1,11 +1,21 @@
-use crate::NewGameStep;
+use crate::{
+  NewGameStep,
+  new_game_options::{
+    MAP_SIZE_OPTIONS,
+    NUM_SNAKES_OPTIONS
+  }
+};
 
 pub struct NewGameState {
   pub selected_menu_item_index: usize,
   pub step: NewGameStep,
   pub width: usize,
   pub height: usize,
-  pub num_snakes: usize
+  pub num_snakes: usize,
+  pub default_width: usize,
+  pub default_height: usize,
+  pub default_num_snakes: usize,
+  pub item_widths: Vec<f32>
 }
 
 impl NewGameState {
@@ -15,7 +25,21 @@ impl NewGameState {
       step: NewGameStep::Width,
       width: 0,
       height: 0,
-      num_snakes: 0
+      num_snakes: 0,
+      default_width: MAP_SIZE_OPTIONS[0],
+      default_height: MAP_SIZE_OPTIONS[0],
+      default_num_snakes: NUM_SNAKES_OPTIONS[0],
+      item_widths: Vec::new()
     }
   }
-}
\ No newline at end of file
+
+  pub fn default_menu_item_index(&self) -> usize {
+    let (options, default) = match self.step {
+      NewGameStep::Width => (&MAP_SIZE_OPTIONS, self.default_width),
+      NewGameStep::Height => (&MAP_SIZE_OPTIONS, self.default_height),
+      NewGameStep::NumSnakes => (&NUM_SNAKES_OPTIONS, self.default_num_snakes)
+    };
+
+    options.iter().position(| option | *option == default).unwrap_or(0)
+  }
+}
@@ -0,0 +1,19 @@
+use crate::{
+  GameEvent,
+  MapValidation
+};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Outcome {
+  pub events: Vec<GameEvent>,
+  pub validation: MapValidation
+}
+
+impl Outcome {
+  pub fn new(events: Vec<GameEvent>, validation: MapValidation) -> Self {
+    Self {
+      events,
+      validation
+    }
+  }
+}
@@ -0,0 +1,113 @@
+use crate::{
+  AtlasLayout,
+  AtlasPlacement
+};
+
+pub fn pack_atlas(sizes: &[(u32, u32)], page_size: u32, padding: u32) -> Result<AtlasLayout, String> {
+  let mut order: Vec<usize> = (0..sizes.len()).collect();
+  order.sort_by_key(| index | (std::cmp::Reverse(sizes[*index].1), std::cmp::Reverse(sizes[*index].0)));
+
+  let mut placements = vec![AtlasPlacement { page: 0, x: 0, y: 0, width: 0, height: 0 }; sizes.len()];
+  let mut page_sizes = Vec::new();
+
+  let mut cursor_x = 0;
+  let mut shelf_y = 0;
+  let mut shelf_height = 0;
+
+  for index in order {
+    let (width, height) = sizes[index];
+    let cell_width = width + padding * 2;
+    let cell_height = height + padding * 2;
+
+    if cell_width > page_size || cell_height > page_size {
+      return Err(format!("A {}x{} texture does not fit a {}x{} atlas page", width, height, page_size, page_size));
+    }
+
+    if cursor_x + cell_width > page_size {
+      cursor_x = 0;
+      shelf_y += shelf_height;
+      shelf_height = 0;
+    }
+
+    if page_sizes.is_empty() || shelf_y + cell_height > page_size {
+      page_sizes.push((0, 0));
+      cursor_x = 0;
+      shelf_y = 0;
+      shelf_height = 0;
+    }
+
+    let page = page_sizes.len() - 1;
+    placements[index] = AtlasPlacement {
+      page,
+      x: cursor_x + padding,
+      y: shelf_y + padding,
+      width,
+      height
+    };
+
+    cursor_x += cell_width;
+    shelf_height = shelf_height.max(cell_height);
+    page_sizes[page] = (page_sizes[page].0.max(cursor_x), page_sizes[page].1.max(shelf_y + cell_height));
+  }
+
+  Ok(
+    AtlasLayout {
+      placements,
+      page_sizes
+    }
+  )
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::AtlasPlacement;
+
+  use super::pack_atlas;
+
+  #[test]
+  fn packs_rows_of_tiles() {
+    match pack_atlas(&[(32, 32), (32, 32), (32, 32)], 64, 0) {
+      Ok(layout) => {
+        assert_eq!(layout.page_sizes, vec![(64, 64)]);
+        assert_eq!(layout.placements[0], AtlasPlacement { page: 0, x: 0, y: 0, width: 32, height: 32 });
+        assert_eq!(layout.placements[1], AtlasPlacement { page: 0, x: 32, y: 0, width: 32, height: 32 });
+        assert_eq!(layout.placements[2], AtlasPlacement { page: 0, x: 0, y: 32, width: 32, height: 32 });
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn places_tall_textures_first_and_keeps_input_order() {
+    match pack_atlas(&[(16, 16), (64, 32)], 128, 1) {
+      Ok(layout) => {
+        assert_eq!(layout.placements[1], AtlasPlacement { page: 0, x: 1, y: 1, width: 64, height: 32 });
+        assert_eq!(layout.placements[0], AtlasPlacement { page: 0, x: 67, y: 1, width: 16, height: 16 });
+        assert_eq!(layout.page_sizes, vec![(84, 34)]);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn opens_a_new_page_when_full() {
+    match pack_atlas(&[(32, 32); 5], 64, 0) {
+      Ok(layout) => {
+        assert_eq!(layout.page_sizes, vec![(64, 64), (32, 32)]);
+        assert_eq!(layout.placements[4], AtlasPlacement { page: 1, x: 0, y: 0, width: 32, height: 32 });
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn rejects_oversized_textures() {
+    match pack_atlas(&[(128, 32)], 64, 0) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "A 128x32 texture does not fit a 64x64 atlas page")
+    }
+  }
+}
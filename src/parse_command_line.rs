@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use crate::{
+  CommandLineOptions,
+  Frontend,
+  parse_dimensions,
+  parse_log_filter,
+  parse_usize
+};
+
+pub const USAGE: &str = "Usage: drakes_vs_snakes [options]
+
+Options:
+  --seed N                     Start a new game from seed N
+  --size WxH                   Start a new game on a W by H map
+  --snakes N                   Start a new game with N snakes
+  --load NAME                  Start by loading the named save
+  --windowed                   Open in a window instead of fullscreen
+  --resolution WxH             Override the configured resolution
+  --data-dir PATH              Read and write settings, saves and high scores in PATH
+  --asset-dir PATH             Load textures, shaders and fonts from PATH
+  --frontend sdl|tui|headless  Choose how the game is presented
+  --log LEVEL[,TARGET=LEVEL]   Set log levels, e.g. info,run_terminal=debug
+  --log-file                   Also write the log to a rotating file in the data directory
+  --debug                      Log scene text dumps whenever a scene changes
+  --dev                        Reload shaders and textures when their files change
+  --help                       Show this message";
+
+pub fn parse_command_line(arguments: &[String]) -> Result<CommandLineOptions, String> {
+  let mut options = CommandLineOptions::new();
+  let mut arguments = arguments.iter();
+
+  while let Some(argument) = arguments.next() {
+    let mut value = | name: &str | match arguments.next() {
+      Some(value) => Ok(value.clone()),
+      None => Err(format!("{} needs a value", name))
+    };
+
+    match argument.as_str() {
+      "--seed" => options.seed = Some(value("--seed")?.parse().map_err(| _ | "--seed needs a whole number".to_string())?),
+      "--size" => options.size = Some(parse_dimensions(&value("--size")?)?),
+      "--snakes" => options.num_snakes = Some(parse_usize(&value("--snakes")?)?),
+      "--load" => options.load = Some(value("--load")?),
+      "--windowed" => options.windowed = true,
+      "--resolution" => options.resolution = Some(parse_dimensions(&value("--resolution")?)?),
+      "--data-dir" => options.data_directory = PathBuf::from(value("--data-dir")?),
+      "--asset-dir" => options.asset_directory = PathBuf::from(value("--asset-dir")?),
+
+      "--frontend" => options.frontend = match value("--frontend")?.as_str() {
+        "sdl" => Frontend::Sdl,
+        "tui" => Frontend::Terminal,
+        "headless" => Frontend::Headless,
+        unknown => return Err(format!("Unknown frontend: {}", unknown))
+      },
+
+      "--log" => options.log_filter = parse_log_filter(&value("--log")?)?,
+      "--log-file" => options.log_to_file = true,
+      "--debug" => options.debug = true,
+      "--dev" => options.dev_mode = true,
+      "--help" | "-h" => options.show_help = true,
+      unknown => return Err(format!("Unknown option: {}", unknown))
+    }
+  }
+
+  if options.load.is_some() && (options.size.is_some() || options.num_snakes.is_some()) {
+    return Err("--load cannot be combined with --size or --snakes".to_string());
+  }
+
+  Ok(options)
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::PathBuf;
+
+  use log::LevelFilter;
+
+  use crate::{
+    CommandLineOptions,
+    Frontend
+  };
+
+  use super::parse_command_line;
+
+  fn arguments(line: &str) -> Vec<String> {
+    line.split_whitespace().map(| argument | argument.to_string()).collect()
+  }
+
+  #[test]
+  fn defaults_without_arguments() {
+    match parse_command_line(&[]) {
+      Ok(options) => {
+        assert_eq!(options, CommandLineOptions::new());
+        assert_eq!(options.requests_game(), false);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn parses_every_option() {
+    match parse_command_line(&arguments("--seed 42 --size 16x8 --snakes 12 --windowed --resolution 1280x720 --data-dir ./data --asset-dir ./assets --frontend tui")) {
+      Ok(options) => {
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.size, Some((16, 8)));
+        assert_eq!(options.num_snakes, Some(12));
+        assert_eq!(options.windowed, true);
+        assert_eq!(options.resolution, Some((1280, 720)));
+        assert_eq!(options.data_directory, PathBuf::from("./data"));
+        assert_eq!(options.asset_directory, PathBuf::from("./assets"));
+        assert_eq!(options.frontend, Frontend::Terminal);
+        assert_eq!(options.requests_game(), true);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn parses_load_and_headless() {
+    match parse_command_line(&arguments("--load first.txt --frontend headless")) {
+      Ok(options) => {
+        assert_eq!(options.load, Some("first.txt".to_string()));
+        assert_eq!(options.frontend, Frontend::Headless);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn parses_logging_options() {
+    match parse_command_line(&arguments("--log info,scenes=trace --log-file --debug --dev")) {
+      Ok(options) => {
+        assert_eq!(options.log_filter.default_level, LevelFilter::Info);
+        assert_eq!(options.log_filter.level_for("scenes"), LevelFilter::Trace);
+        assert_eq!(options.log_to_file, true);
+        assert_eq!(options.debug, true);
+        assert_eq!(options.dev_mode, true);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_bad_arguments() {
+    assert_eq!(parse_command_line(&arguments("--fast")), Err("Unknown option: --fast".to_string()));
+    assert_eq!(parse_command_line(&arguments("--seed")), Err("--seed needs a value".to_string()));
+    assert_eq!(parse_command_line(&arguments("--seed many")), Err("--seed needs a whole number".to_string()));
+    assert_eq!(parse_command_line(&arguments("--frontend vulkan")), Err("Unknown frontend: vulkan".to_string()));
+    assert_eq!(parse_command_line(&arguments("--load a --snakes 4")), Err("--load cannot be combined with --size or --snakes".to_string()));
+  }
+}
@@ -0,0 +1,35 @@
+use crate::parse_usize;
+
+pub fn parse_dimensions(unparsed: &str) -> Result<(usize, usize), String> {
+  match unparsed.split_once('x') {
+    Some((width, height)) => Ok((parse_usize(width.trim())?, parse_usize(height.trim())?)),
+    None => Err(format!("Expected dimensions as WIDTHxHEIGHT, found \"{}\"", unparsed))
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::parse_dimensions;
+
+  #[test]
+  fn parses_dimensions() {
+    assert_eq!(parse_dimensions("1600x900"), Ok((1600, 900)));
+    assert_eq!(parse_dimensions("8 x 16"), Ok((8, 16)));
+  }
+
+  #[test]
+  fn fails_without_separator() {
+    match parse_dimensions("1600,900") {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Expected dimensions as WIDTHxHEIGHT, found \"1600,900\"")
+    }
+  }
+
+  #[test]
+  fn fails_on_invalid_numbers() {
+    match parse_dimensions("widexhigh") {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "invalid digit found in string")
+    }
+  }
+}
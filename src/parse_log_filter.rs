@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use log::LevelFilter;
+
+use crate::LogFilter;
+
+pub fn parse_log_filter(unparsed: &str) -> Result<LogFilter, String> {
+  let mut log_filter = LogFilter::new();
+
+  for directive in unparsed.split(',').map(| directive | directive.trim()).filter(| directive | !directive.is_empty()) {
+    match directive.split_once('=') {
+      Some((target, level)) => log_filter.set_target_level(target.trim(), parse_level(level.trim())?),
+      None => log_filter.default_level = parse_level(directive)?
+    }
+  }
+
+  Ok(log_filter)
+}
+
+fn parse_level(unparsed: &str) -> Result<LevelFilter, String> {
+  LevelFilter::from_str(unparsed).map_err(| _ | format!("Invalid log level: {}", unparsed))
+}
+
+#[cfg(test)]
+mod testing {
+  use log::LevelFilter;
+
+  use super::parse_log_filter;
+
+  #[test]
+  fn parses_default_and_target_levels() {
+    match parse_log_filter("info, run_terminal=debug,scenes=TRACE") {
+      Ok(log_filter) => {
+        assert_eq!(log_filter.default_level, LevelFilter::Info);
+        assert_eq!(log_filter.level_for("run_terminal"), LevelFilter::Debug);
+        assert_eq!(log_filter.level_for("scenes"), LevelFilter::Trace);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_levels() {
+    match parse_log_filter("main=loud") {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid log level: loud")
+    }
+  }
+}
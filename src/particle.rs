@@ -0,0 +1,83 @@
+use std::rc::Rc;
+
+use crate::{
+  ParticleEffect,
+  Vector2
+};
+
+pub struct Particle {
+  pub effect: Rc<ParticleEffect>,
+  pub position: Vector2,
+  pub velocity: Vector2,
+  pub age: f32,
+  pub lifetime: f32
+}
+
+impl Particle {
+  pub fn is_alive(&self) -> bool {
+    self.age < self.lifetime
+  }
+
+  pub fn size(&self) -> f32 {
+    let (start, end) = self.effect.size;
+    start + (end - start) * self.progress()
+  }
+
+  pub fn color(&self) -> [f32; 4] {
+    let start = self.effect.start_color;
+    let end = self.effect.end_color;
+    let progress = self.progress();
+    let blend = | from: u8, to: u8 | (from as f32 + (to as f32 - from as f32) * progress) / 255.0;
+
+    [blend(start.r, end.r), blend(start.g, end.g), blend(start.b, end.b), blend(start.a, end.a)]
+  }
+
+  fn progress(&self) -> f32 {
+    (self.age / self.lifetime).clamp(0.0, 1.0)
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::rc::Rc;
+
+  use sdl2::pixels::Color;
+
+  use crate::{
+    ParticleEffect,
+    ParticleMode,
+    Vector2
+  };
+
+  use super::Particle;
+
+  fn particle(age: f32) -> Particle {
+    let mut effect = ParticleEffect::new(ParticleMode::Burst);
+    effect.size = (8.0, 2.0);
+    effect.start_color = Color::RGBA(255, 0, 255, 255);
+    effect.end_color = Color::RGBA(255, 0, 51, 0);
+
+    Particle {
+      effect: Rc::new(effect),
+      position: Vector2::new(),
+      velocity: Vector2::new(),
+      age,
+      lifetime: 2.0
+    }
+  }
+
+  #[test]
+  fn interpolates_over_its_life() {
+    let particle = particle(1.0);
+
+    assert_eq!(particle.size(), 5.0);
+    assert_eq!(particle.color(), [1.0, 0.0, 0.6, 0.5]);
+  }
+
+  #[test]
+  fn dies_at_the_end_of_its_life() {
+    assert_eq!(particle(1.9).is_alive(), true);
+    assert_eq!(particle(2.0).is_alive(), false);
+    assert_eq!(particle(2.5).size(), 2.0);
+  }
+}
@@ -0,0 +1,67 @@
+use crate::{
+  ParticleSystem,
+  TextureRegion,
+  Camera,
+  ShaderProgram,
+  Matrix4,
+  VertexArray,
+  VertexBuffer,
+  ElementBuffer,
+  generate_particle_vertices,
+  generate_quad_indices
+};
+
+pub struct ParticleBatch {
+  region: TextureRegion,
+  vertex_buffer: VertexBuffer,
+  element_buffer: ElementBuffer,
+  vertex_array: VertexArray,
+  quad_capacity: usize
+}
+
+impl ParticleBatch {
+  pub fn new(region: TextureRegion) -> Self {
+    let vertex_buffer = VertexBuffer::new(Vec::new());
+    let element_buffer = ElementBuffer::new(Vec::new());
+    let vertex_array = VertexArray::with_colors(&vertex_buffer, &element_buffer);
+
+    Self {
+      region,
+      vertex_buffer,
+      element_buffer,
+      vertex_array,
+      quad_capacity: 0
+    }
+  }
+
+  pub fn draw(&mut self, particle_system: &ParticleSystem, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
+    let particles = particle_system.particles();
+    if particles.is_empty() { return Ok(()) }
+
+    let uv_rect = self.region.uv_rect();
+    let vertex_data: Vec<f32> = particles.iter()
+      .flat_map(| particle | generate_particle_vertices(particle.position, particle.size(), &uv_rect, particle.color()))
+      .collect();
+
+    self.vertex_buffer.upload(&vertex_data);
+
+    if particles.len() > self.quad_capacity {
+      self.element_buffer.upload(&generate_quad_indices(particles.len()));
+      self.quad_capacity = particles.len();
+    }
+
+    shader_program.activate();
+    shader_program.set_model_matrix(&Matrix4::identity())?;
+    shader_program.set_view_matrix(&camera.view_matrix())?;
+    shader_program.set_projection_matrix(camera.projection_matrix())?;
+
+    unsafe {
+      gl::BindVertexArray(self.vertex_array.id());
+      gl::BindTexture(gl::TEXTURE_2D, self.region.texture().id());
+      gl::DrawElements(gl::TRIANGLES, (particles.len() * 6) as gl::types::GLsizei, gl::UNSIGNED_INT, std::ptr::null());
+      gl::BindVertexArray(0);
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleCue {
+  Dust,
+  Puff,
+  Splash,
+  Bite,
+  Victory
+}
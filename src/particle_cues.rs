@@ -0,0 +1,83 @@
+use crate::{
+  Map,
+  Message,
+  GameEvent,
+  ParticleCue,
+  Subscriber
+};
+
+pub struct ParticleCues {
+  events: Vec<GameEvent>
+}
+
+impl ParticleCues {
+  pub fn new() -> Self {
+    Self {
+      events: Vec::new()
+    }
+  }
+
+  pub fn take(&mut self, map: &Map) -> Vec<(ParticleCue, usize)> {
+    std::mem::take(&mut self.events).iter().filter_map(| event | match *event {
+      GameEvent::TileRevealed(index) => Some((ParticleCue::Dust, index)),
+      GameEvent::MarkToggled(index, true) => Some((ParticleCue::Puff, index)),
+      GameEvent::PlayerMoved(_, to) if map.is_water.get(to) == Some(&true) => Some((ParticleCue::Splash, to)),
+      GameEvent::SnakeBitten(index) => Some((ParticleCue::Bite, index)),
+      GameEvent::GoalReached(index) => Some((ParticleCue::Victory, index)),
+
+      _ => None
+    }).collect()
+  }
+}
+
+impl Subscriber for ParticleCues {
+  fn receive(&mut self, message: &Message) {
+    if let Message::GameEvent(event) = message {
+      self.events.push(*event);
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Map,
+    Message,
+    GameEvent,
+    ParticleCue,
+    Subscriber
+  };
+
+  use super::ParticleCues;
+
+  #[test]
+  fn cues_gameplay_moments() {
+    let mut map = Map::new();
+    map.is_water = vec![false; 16];
+    map.is_water[5] = true;
+
+    let mut particle_cues = ParticleCues::new();
+    for event in [
+      GameEvent::PlayerMoved(0, 1),
+      GameEvent::TileRevealed(1),
+      GameEvent::PlayerMoved(1, 5),
+      GameEvent::MarkToggled(6, true),
+      GameEvent::MarkToggled(6, false),
+      GameEvent::ScoreChanged(3),
+      GameEvent::SnakeBitten(9),
+      GameEvent::GoalReached(15)
+    ] {
+      particle_cues.receive(&Message::GameEvent(event));
+    }
+    particle_cues.receive(&Message::RequestShutdown);
+
+    assert_eq!(particle_cues.take(&map), vec![
+      (ParticleCue::Dust, 1),
+      (ParticleCue::Splash, 5),
+      (ParticleCue::Puff, 6),
+      (ParticleCue::Bite, 9),
+      (ParticleCue::Victory, 15)
+    ]);
+    assert_eq!(particle_cues.take(&map), vec![]);
+  }
+}
@@ -0,0 +1,36 @@
+use sdl2::pixels::Color;
+
+use crate::ParticleMode;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParticleEffect {
+  pub mode: ParticleMode,
+  pub count: usize,
+  pub rate: f32,
+  pub lifetime: (f32, f32),
+  pub speed: (f32, f32),
+  pub direction: f32,
+  pub spread: f32,
+  pub gravity: f32,
+  pub size: (f32, f32),
+  pub start_color: Color,
+  pub end_color: Color
+}
+
+impl ParticleEffect {
+  pub fn new(mode: ParticleMode) -> Self {
+    Self {
+      mode,
+      count: 0,
+      rate: 0.0,
+      lifetime: (1.0, 1.0),
+      speed: (0.0, 0.0),
+      direction: 0.0,
+      spread: 360.0,
+      gravity: 0.0,
+      size: (4.0, 4.0),
+      start_color: Color::RGBA(255, 255, 255, 255),
+      end_color: Color::RGBA(255, 255, 255, 0)
+    }
+  }
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use crate::{
+  ParticleCue,
+  ParticleEffect,
+  Resources,
+  deserialize_particle_effect
+};
+
+pub struct ParticleEffects {
+  pub dust: Rc<ParticleEffect>,
+  pub puff: Rc<ParticleEffect>,
+  pub splash: Rc<ParticleEffect>,
+  pub bite: Rc<ParticleEffect>,
+  pub victory: Rc<ParticleEffect>,
+  pub breath: Rc<ParticleEffect>
+}
+
+impl ParticleEffects {
+  pub fn load(resources: &Resources) -> Result<Self, String> {
+    let load = | name: &str | -> Result<Rc<ParticleEffect>, String> {
+      let asset_id = format!("particles/{}.particle", name);
+      let effect = deserialize_particle_effect(resources.read_to_string(&asset_id)?).map_err(| error | format!("Invalid particle effect \"{}\": {}", asset_id, error))?;
+
+      Ok(Rc::new(effect))
+    };
+
+    Ok(Self {
+      dust: load("dust")?,
+      puff: load("puff")?,
+      splash: load("splash")?,
+      bite: load("bite")?,
+      victory: load("victory")?,
+      breath: load("breath")?
+    })
+  }
+
+  pub fn cue(&self, particle_cue: ParticleCue) -> &Rc<ParticleEffect> {
+    match particle_cue {
+      ParticleCue::Dust => &self.dust,
+      ParticleCue::Puff => &self.puff,
+      ParticleCue::Splash => &self.splash,
+      ParticleCue::Bite => &self.bite,
+      ParticleCue::Victory => &self.victory
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use crate::{
+    ParticleCue,
+    ParticleMode,
+    Resources
+  };
+
+  use super::ParticleEffects;
+
+  #[test]
+  fn loads_the_shipped_effects() {
+    let resources = Resources::new(Path::new("./res"));
+
+    match ParticleEffects::load(&resources) {
+      Ok(particle_effects) => {
+        assert_eq!(particle_effects.cue(ParticleCue::Dust).mode, ParticleMode::Burst);
+        assert_eq!(particle_effects.cue(ParticleCue::Victory).mode, ParticleMode::Burst);
+        assert_eq!(particle_effects.breath.mode, ParticleMode::Continuous);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
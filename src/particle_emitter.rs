@@ -0,0 +1,85 @@
+use std::{
+  rc::Rc,
+  time::Duration
+};
+
+use crate::{
+  ParticleEffect,
+  ParticleSystem,
+  Vector2
+};
+
+pub struct ParticleEmitter {
+  effect: Rc<ParticleEffect>,
+  pending: f32
+}
+
+impl ParticleEmitter {
+  pub fn new(effect: Rc<ParticleEffect>) -> Self {
+    Self {
+      effect,
+      pending: 0.0
+    }
+  }
+
+  pub fn update(&mut self, particle_system: &mut ParticleSystem, position: Vector2, direction: f32, deltatime: &Duration) {
+    self.pending = (self.pending + self.effect.rate * deltatime.as_secs_f32()).min(particle_system.remaining_capacity() as f32 + 1.0);
+
+    while self.pending >= 1.0 {
+      particle_system.spawn(&self.effect, position, direction);
+      self.pending -= 1.0;
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::{
+    rc::Rc,
+    time::Duration
+  };
+
+  use crate::{
+    ParticleEffect,
+    ParticleMode,
+    ParticleSystem,
+    Vector2
+  };
+
+  use super::ParticleEmitter;
+
+  #[test]
+  fn emits_at_the_configured_rate() {
+    let mut effect = ParticleEffect::new(ParticleMode::Continuous);
+    effect.rate = 10.0;
+    effect.lifetime = (5.0, 5.0);
+
+    let mut particle_system = ParticleSystem::new(1);
+    let mut emitter = ParticleEmitter::new(Rc::new(effect));
+
+    emitter.update(&mut particle_system, Vector2::new(), 0.0, &Duration::from_millis(50));
+    assert_eq!(particle_system.particles().len(), 0);
+
+    emitter.update(&mut particle_system, Vector2::new(), 0.0, &Duration::from_millis(60));
+    assert_eq!(particle_system.particles().len(), 1);
+
+    emitter.update(&mut particle_system, Vector2::new(), 0.0, &Duration::from_millis(500));
+    assert_eq!(particle_system.particles().len(), 6);
+  }
+
+  #[test]
+  fn stops_emitting_at_capacity() {
+    let mut effect = ParticleEffect::new(ParticleMode::Continuous);
+    effect.rate = 1.0e9;
+    effect.lifetime = (5.0, 5.0);
+
+    let mut particle_system = ParticleSystem::new(1);
+    let mut emitter = ParticleEmitter::new(Rc::new(effect));
+
+    emitter.update(&mut particle_system, Vector2::new(), 0.0, &Duration::from_secs(10));
+    assert_eq!(particle_system.remaining_capacity(), 0);
+
+    emitter.update(&mut particle_system, Vector2::new(), 0.0, &Duration::from_secs(10));
+    assert_eq!(particle_system.remaining_capacity(), 0);
+  }
+}
@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleMode {
+  Burst,
+  Continuous
+}
@@ -0,0 +1,187 @@
+use std::{
+  rc::Rc,
+  time::Duration
+};
+
+use rand::{
+  Rng,
+  SeedableRng,
+  rngs::StdRng
+};
+
+use crate::{
+  Particle,
+  ParticleEffect,
+  Vector2
+};
+
+pub const MAX_PARTICLES: usize = 4096;
+
+pub struct ParticleSystem {
+  particles: Vec<Particle>,
+  rng: StdRng
+}
+
+impl ParticleSystem {
+  pub fn new(seed: u64) -> Self {
+    Self {
+      particles: Vec::new(),
+      rng: StdRng::seed_from_u64(seed)
+    }
+  }
+
+  pub fn particles(&self) -> &[Particle] {
+    &self.particles
+  }
+
+  pub fn clear(&mut self) {
+    self.particles.clear();
+  }
+
+  pub fn burst(&mut self, effect: &Rc<ParticleEffect>, position: Vector2, direction: f32) {
+    for _ in 0..effect.count.min(self.remaining_capacity()) {
+      self.spawn(effect, position, direction);
+    }
+  }
+
+  pub fn remaining_capacity(&self) -> usize {
+    MAX_PARTICLES - self.particles.len()
+  }
+
+  pub fn spawn(&mut self, effect: &Rc<ParticleEffect>, position: Vector2, direction: f32) {
+    if self.particles.len() >= MAX_PARTICLES { return }
+
+    let angle = (direction + effect.direction + effect.spread * (self.rng.random::<f32>() - 0.5)).to_radians();
+    let speed = effect.speed.0 + (effect.speed.1 - effect.speed.0) * self.rng.random::<f32>();
+    let lifetime = effect.lifetime.0 + (effect.lifetime.1 - effect.lifetime.0) * self.rng.random::<f32>();
+
+    self.particles.push(
+      Particle {
+        effect: Rc::clone(effect),
+        position,
+        velocity: Vector2 { x: angle.sin() * speed, y: -angle.cos() * speed },
+        age: 0.0,
+        lifetime
+      }
+    );
+  }
+
+  pub fn update(&mut self, deltatime: &Duration) {
+    let seconds = deltatime.as_secs_f32();
+
+    for particle in &mut self.particles {
+      particle.age += seconds;
+      particle.velocity.y += particle.effect.gravity * seconds;
+      particle.position.x += particle.velocity.x * seconds;
+      particle.position.y += particle.velocity.y * seconds;
+    }
+
+    self.particles.retain(| particle | particle.is_alive());
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::{
+    rc::Rc,
+    time::Duration
+  };
+
+  use crate::{
+    ParticleEffect,
+    ParticleMode,
+    Vector2
+  };
+
+  use super::{
+    ParticleSystem,
+    MAX_PARTICLES
+  };
+
+  fn effect() -> Rc<ParticleEffect> {
+    let mut effect = ParticleEffect::new(ParticleMode::Burst);
+    effect.count = 5;
+    effect.lifetime = (1.0, 1.0);
+    effect.speed = (10.0, 10.0);
+    effect.spread = 0.0;
+
+    Rc::new(effect)
+  }
+
+  #[test]
+  fn bursts_the_configured_count() {
+    let mut particle_system = ParticleSystem::new(1);
+    particle_system.burst(&effect(), Vector2 { x: 4.0, y: 8.0 }, 0.0);
+
+    assert_eq!(particle_system.particles().len(), 5);
+    let position = particle_system.particles()[0].position;
+    assert_eq!((position.x, position.y), (4.0, 8.0));
+  }
+
+  #[test]
+  fn moves_in_the_emitted_direction() {
+    let mut particle_system = ParticleSystem::new(1);
+    particle_system.spawn(&effect(), Vector2::new(), 90.0);
+    particle_system.update(&Duration::from_millis(500));
+
+    let position = particle_system.particles()[0].position;
+    assert!((position.x - 5.0).abs() < 0.001);
+    assert!(position.y.abs() < 0.001);
+  }
+
+  #[test]
+  fn applies_gravity() {
+    let mut effect = (*effect()).clone();
+    effect.speed = (0.0, 0.0);
+    effect.gravity = 20.0;
+
+    let mut particle_system = ParticleSystem::new(1);
+    particle_system.spawn(&Rc::new(effect), Vector2::new(), 0.0);
+    particle_system.update(&Duration::from_millis(500));
+
+    assert_eq!(particle_system.particles()[0].velocity.y, 10.0);
+    assert_eq!(particle_system.particles()[0].position.y, 5.0);
+  }
+
+  #[test]
+  fn removes_expired_particles() {
+    let mut particle_system = ParticleSystem::new(1);
+    particle_system.burst(&effect(), Vector2::new(), 0.0);
+
+    particle_system.update(&Duration::from_millis(999));
+    assert_eq!(particle_system.particles().len(), 5);
+
+    particle_system.update(&Duration::from_millis(10));
+    assert_eq!(particle_system.particles().len(), 0);
+  }
+
+  #[test]
+  fn stops_at_capacity() {
+    let mut effect = (*effect()).clone();
+    effect.count = usize::MAX;
+
+    let mut particle_system = ParticleSystem::new(1);
+    particle_system.burst(&Rc::new(effect), Vector2::new(), 0.0);
+
+    assert_eq!(particle_system.particles().len(), MAX_PARTICLES);
+    assert_eq!(particle_system.remaining_capacity(), 0);
+  }
+
+  #[test]
+  fn randomises_within_the_configured_ranges() {
+    let mut effect = (*effect()).clone();
+    effect.count = 64;
+    effect.lifetime = (0.5, 1.5);
+    effect.speed = (10.0, 20.0);
+
+    let mut particle_system = ParticleSystem::new(7);
+    particle_system.burst(&Rc::new(effect), Vector2::new(), 0.0);
+
+    for particle in particle_system.particles() {
+      assert!(particle.lifetime >= 0.5 && particle.lifetime <= 1.5);
+
+      let speed = (particle.velocity.x * particle.velocity.x + particle.velocity.y * particle.velocity.y).sqrt();
+      assert!((10.0..=20.001).contains(&speed));
+    }
+  }
+}
@@ -3,7 +3,9 @@ use crate::{
   PauseMenuState,
   Message,
   Input,
-  Scenes
+  Scenes,
+  PointerButton,
+  menu_item_at
 };
 
 pub fn update_pause_menu(message_queue: &mut MessageQueue, pause_menu_state: &mut PauseMenuState) {
@@ -11,14 +13,25 @@ pub fn update_pause_menu(message_queue: &mut MessageQueue, pause_menu_state: &mu
   let mut confirmed = false;
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = message { match input {
-      Input::Up => if pause_menu_state.selected_menu_item_index > 0 { pause_menu_state.selected_menu_item_index -= 1 },
-      Input::Down => if pause_menu_state.selected_menu_item_index < 2 { pause_menu_state.selected_menu_item_index += 1 },
-      Input::Confirm => confirmed = true,
-      Input::Cancel => cancelled = true,
-      
+    match *message {
+      Message::PointerMoved(x, y) => if let Some(index) = menu_item_at(x, y, 0, &pause_menu_state.item_widths) { pause_menu_state.selected_menu_item_index = index },
+
+      Message::PointerPressed(x, y, PointerButton::Left) => if let Some(index) = menu_item_at(x, y, 0, &pause_menu_state.item_widths) {
+        pause_menu_state.selected_menu_item_index = index;
+        confirmed = true;
+      },
+
+      Message::PlayerInput(input) => match input {
+        Input::Up => if pause_menu_state.selected_menu_item_index > 0 { pause_menu_state.selected_menu_item_index -= 1 },
+        Input::Down => if pause_menu_state.selected_menu_item_index < 2 { pause_menu_state.selected_menu_item_index += 1 },
+        Input::Confirm => confirmed = true,
+        Input::Cancel => cancelled = true,
+
+        _ => {}
+      },
+
       _ => {}
-    }}
+    }
   }
 
   if confirmed {
@@ -34,17 +47,16 @@ pub fn update_pause_menu(message_queue: &mut MessageQueue, pause_menu_state: &mu
   if cancelled { message_queue.post(Message::RequestScene(Scenes::Playfield)) }
 }
 
-pub fn print_pause_menu(pause_menu_state: &PauseMenuState) {
-  println!("Paused");
-  
-  if pause_menu_state.selected_menu_item_index == 0 { print!("  * ") } else { print!("    ") }
-  println!("1) Resume");
+pub fn print_pause_menu(pause_menu_state: &PauseMenuState) -> String {
+  let mut output = String::from("Paused\n");
 
-  if pause_menu_state.selected_menu_item_index == 1 { print!("  * ") } else { print!("    ") }
-  println!("2) Save Game");
+  for (index, line) in ["1) Resume", "2) Save Game", "3) Main Menu"].iter().enumerate() {
+    if pause_menu_state.selected_menu_item_index == index { output.push_str("  * ") } else { output.push_str("    ") }
+    output.push_str(line);
+    output.push('\n');
+  }
 
-  if pause_menu_state.selected_menu_item_index == 2 { print!("  * ") } else { print!("    ") }
-  println!("3) Main Menu");
+  output
 }
 
 #[cfg(test)]
@@ -54,7 +66,8 @@ mod testing {
     Message,
     PauseMenuState,
     Input,
-    Scenes
+    Scenes,
+    PointerButton
   };
 
   use super::update_pause_menu;
@@ -186,4 +199,66 @@ mod testing {
     assert_eq!(message_queue.messages().len(), 1);
     assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::Playfield));
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn hover_selects() {
+    let mut message_queue = MessageQueue::new();
+    let mut pause_menu_state = PauseMenuState::new();
+    pause_menu_state.item_widths = vec![96.0; 3];
+
+    message_queue.post(Message::PointerMoved(0, 70));
+    message_queue.swap_buffers();
+
+    update_pause_menu(&mut message_queue, &mut pause_menu_state);
+
+    assert_eq!(pause_menu_state.selected_menu_item_index, 2);
+  }
+
+  #[test]
+  fn click_confirms() {
+    let mut message_queue = MessageQueue::new();
+    let mut pause_menu_state = PauseMenuState::new();
+    pause_menu_state.item_widths = vec![96.0; 3];
+
+    message_queue.post(Message::PointerPressed(0, 30, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    update_pause_menu(&mut message_queue, &mut pause_menu_state);
+    message_queue.swap_buffers();
+
+    assert_eq!(pause_menu_state.selected_menu_item_index, 1);
+    assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::SaveGame));
+  }
+
+  #[test]
+  fn click_outside_menu_is_ignored() {
+    let mut message_queue = MessageQueue::new();
+    let mut pause_menu_state = PauseMenuState::new();
+    pause_menu_state.item_widths = vec![96.0; 3];
+
+    message_queue.post(Message::PointerPressed(0, 200, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    update_pause_menu(&mut message_queue, &mut pause_menu_state);
+    message_queue.swap_buffers();
+
+    assert_eq!(pause_menu_state.selected_menu_item_index, 0);
+    assert_eq!(message_queue.messages().len(), 0);
+  }
+
+  #[test]
+  fn click_beside_a_row_is_ignored() {
+    let mut message_queue = MessageQueue::new();
+    let mut pause_menu_state = PauseMenuState::new();
+    pause_menu_state.item_widths = vec![96.0; 3];
+
+    message_queue.post(Message::PointerPressed(300, 30, PointerButton::Left));
+    message_queue.swap_buffers();
+
+    update_pause_menu(&mut message_queue, &mut pause_menu_state);
+    message_queue.swap_buffers();
+
+    assert_eq!(pause_menu_state.selected_menu_item_index, 0);
+    assert_eq!(message_queue.messages().len(), 0);
+  }
+}
@@ -1,11 +1,13 @@
 pub struct PauseMenuState {
-  pub selected_menu_item_index: usize
+  pub selected_menu_item_index: usize,
+  pub item_widths: Vec<f32>
 }
 
 impl PauseMenuState {
   pub fn new() -> Self {
     Self {
-      selected_menu_item_index: 0
+      selected_menu_item_index: 0,
+      item_widths: Vec::new()
     }
   }
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PlacedGlyph {
+  pub character: char,
+  pub x: f32,
+  pub y: f32
+}
@@ -0,0 +1,70 @@
+use crate::{
+  GameSession,
+  MapValidation,
+  input_bindings::input_from_name
+};
+
+pub fn play_script(game_session: &mut GameSession, script: &str) -> Result<(), String> {
+  for line in script.lines() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') { continue; }
+
+    let input = match input_from_name(line) {
+      Some(input) => input,
+      None => return Err(format!("Unknown input: {}", line))
+    };
+
+    game_session.apply(input)?;
+    if game_session.validation() != MapValidation::Valid { break; }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    GameSession,
+    Map,
+    MapValidation
+  };
+
+  use super::play_script;
+
+  fn open_map() -> Map {
+    let mut map = Map::new();
+    map.goal_location.set_array_index(3, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+
+    map
+  }
+
+  #[test]
+  fn plays_inputs_until_the_game_ends() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    match play_script(&mut game_session, "# walk to the nest\nRight\n\nRight\nRight\nDown\n") {
+      Ok(()) => {
+        assert_eq!(game_session.validation(), MapValidation::Won);
+        assert_eq!(game_session.snapshot().history.len(), 3);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn fails_on_unknown_inputs() {
+    let mut game_session = GameSession::from_map(open_map(), 0).unwrap();
+
+    match play_script(&mut game_session, "Right\nJump\n") {
+      Ok(()) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Unknown input: Jump")
+    }
+  }
+}
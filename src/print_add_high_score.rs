@@ -1,4 +1,5 @@
-pub fn print_add_high_score() {
-  println!("You win!");
-  println!("Enter your name:");
-}
\ No newline at end of file
+use crate::TextField;
+
+pub fn print_add_high_score(text_field: &TextField) -> String {
+  format!("You win!\nEnter your name: {}\n", text_field.display_text())
+}
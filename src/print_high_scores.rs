@@ -1,9 +1,11 @@
 use crate::HighScoresState;
 
-pub fn print_high_scores(high_scores_state: &HighScoresState) {
-  println!("High Scores");
+pub fn print_high_scores(high_scores_state: &HighScoresState) -> String {
+  let mut output = String::from("High Scores\n");
 
   for listing in &high_scores_state.listings {
-    println!("{}: {}", listing.name(), listing.score());
+    output.push_str(&format!("{}: {}\n", listing.name(), listing.score()));
   }
+
+  output
 }
\ No newline at end of file
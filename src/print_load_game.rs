@@ -1,10 +1,12 @@
 use crate::LoadGameState;
 
-pub fn print_load_game(load_game_state: &LoadGameState) {
-  println!("Load Game:");
+pub fn print_load_game(load_game_state: &LoadGameState) -> String {
+  let mut output = String::from("Load Game:\n");
 
   for (index, name) in load_game_state.saves.iter().enumerate() {
-    if load_game_state.selected_menu_item_index == index { print!("  * ") } else { print!("    ") }
-    println!("{}", name);
+    if load_game_state.selected_menu_item_index == index { output.push_str("  * ") } else { output.push_str("    ") }
+    output.push_str(&format!("{}\n", name));
   }
+
+  output
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+use crate::{
+  MainMenuState,
+  MainMenuItem
+};
+
+pub fn print_main_menu(main_menu_state: &MainMenuState) -> String {
+  let mut output = String::from("Drakes VS Snakes\n\n");
+
+  let items = [
+    (MainMenuItem::NewGame, "New Game"),
+    (MainMenuItem::LoadGame, "Load Game"),
+    (MainMenuItem::HighScores, "High Scores"),
+    (MainMenuItem::Settings, "Settings"),
+    (MainMenuItem::Quit, "Quit")
+  ];
+
+  for (item, label) in items {
+    if main_menu_state.selected_menu_item == item { output.push_str("  * ") } else { output.push_str("    ") }
+    output.push_str(label);
+    output.push('\n');
+  }
+
+  output
+}
@@ -1,31 +1,39 @@
-use crate::PlayfieldState;
+use crate::{
+  GameSnapshot,
+  MapValidation
+};
 
-pub fn print_playfield(playfield_state: &PlayfieldState) {
-  if playfield_state.is_interacting { println!("Is Marking"); }
-  println!("Score: {}/{}", playfield_state.map.score.current(), playfield_state.map.score.maximum());
-  for index in 0..playfield_state.map.size.array_length() {
-    if index == playfield_state.map.player_location.array_index() {
-      print!("P");
-    } else if index == playfield_state.map.goal_location.array_index() {
-      print!("G");
-    } else if playfield_state.map.is_marked[index] {
-      print!("X");
-    } else if playfield_state.map.is_explored[index] {
-      if playfield_state.map.is_path[index] {
-        print!("*");
+pub fn print_playfield(snapshot: &GameSnapshot) -> String {
+  let map = &snapshot.map;
+  let mut output = format!("Seed: {} Turn: {} Snakes: {}\n", snapshot.seed, snapshot.history.len(), snapshot.parameters.num_snakes);
+  if snapshot.is_interacting { output.push_str("Is Marking\n"); }
+  if snapshot.validation == MapValidation::Won { output.push_str("Won\n"); }
+  if snapshot.validation == MapValidation::Lost { output.push_str("Lost\n"); }
+  output.push_str(&format!("Score: {}/{}\n", map.score.current(), map.score.maximum()));
+
+  for index in 0..map.size.array_length() {
+    if index == map.player_location.array_index() {
+      output.push('P');
+    } else if index == map.goal_location.array_index() {
+      output.push('G');
+    } else if map.is_marked[index] {
+      output.push('X');
+    } else if map.is_explored[index] {
+      if map.is_path[index] {
+        output.push('*');
       } else {
-        print!("{}", playfield_state.map.hint[index]);
+        output.push_str(&map.hint[index].to_string());
       }
     } else {
-      print!("_");
+      output.push('_');
     }
 
-    if index % playfield_state.map.size.width() == playfield_state.map.size.width() - 1 {
-      println!();
+    if index % map.size.width() == map.size.width() - 1 {
+      output.push('\n');
     } else {
-      print!(" ");
+      output.push(' ');
     }
   }
-  println!();
-  println!();
-}
\ No newline at end of file
+
+  output
+}
@@ -1,4 +1,5 @@
-pub fn print_save_game() {
-  println!("Save Game");
-  println!("File name?");
-}
\ No newline at end of file
+use crate::TextField;
+
+pub fn print_save_game(text_field: &TextField) -> String {
+  format!("Save Game\nFile name? {}\n", text_field.display_text())
+}
@@ -0,0 +1,11 @@
+use crate::SessionStatistics;
+
+pub fn print_session_statistics(session_statistics: &SessionStatistics) -> String {
+  let mut output = String::from("Session Statistics\n");
+  output.push_str(&format!("Tiles revealed: {}\n", session_statistics.tiles_revealed));
+  output.push_str(&format!("Marks placed: {}\n", session_statistics.marks_placed));
+  output.push_str(&format!("Games won: {}\n", session_statistics.games_won));
+  output.push_str(&format!("Games lost: {}\n", session_statistics.games_lost));
+
+  output
+}
@@ -1,3 +1,19 @@
-pub fn print_settings() {
-  println!("Settings");
-}
\ No newline at end of file
+use crate::{
+  SettingsState,
+  settings_menu_lines
+};
+
+pub fn print_settings(settings_state: &SettingsState) -> String {
+  let mut output = String::from("Settings\n\n");
+
+  for (index, line) in settings_menu_lines(settings_state).iter().enumerate() {
+    if settings_state.selected_menu_item_index == index { output.push_str("  * ") } else { output.push_str("    ") }
+    output.push_str(&format!("{}\n", line));
+  }
+
+  if let Some(status) = &settings_state.status {
+    output.push_str(&format!("\n{}\n", status));
+  }
+
+  output
+}
@@ -0,0 +1,114 @@
+use crate::TileLayer;
+
+pub struct QuadRun {
+  layer: TileLayer,
+  texture_id: gl::types::GLuint,
+  vertices: Vec<f32>
+}
+
+impl QuadRun {
+  pub fn texture_id(&self) -> gl::types::GLuint {
+    self.texture_id
+  }
+
+  pub fn vertices(&self) -> &[f32] {
+    &self.vertices
+  }
+
+  pub fn quad_count(&self) -> usize {
+    self.vertices.len() / 16
+  }
+}
+
+pub struct QuadBatch {
+  runs: Vec<QuadRun>
+}
+
+impl QuadBatch {
+  pub fn new() -> Self {
+    Self {
+      runs: Vec::new()
+    }
+  }
+
+  pub fn push(&mut self, layer: TileLayer, texture_id: gl::types::GLuint, quad: &[f32; 16]) {
+    match self.runs.iter_mut().find(| run | run.layer == layer && run.texture_id == texture_id) {
+      Some(run) => run.vertices.extend_from_slice(quad),
+
+      None => self.runs.push(
+        QuadRun {
+          layer,
+          texture_id,
+          vertices: quad.to_vec()
+        }
+      )
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.runs.is_empty()
+  }
+
+  pub fn finish(&mut self) -> Vec<QuadRun> {
+    let mut runs = std::mem::take(&mut self.runs);
+    runs.sort_by_key(| run | run.layer);
+    runs
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::TileLayer;
+
+  use super::QuadBatch;
+
+  fn quad(value: f32) -> [f32; 16] {
+    [value; 16]
+  }
+
+  #[test]
+  fn merges_quads_sharing_a_layer_and_texture() {
+    let mut quad_batch = QuadBatch::new();
+    quad_batch.push(TileLayer::Grass, 1, &quad(0.0));
+    quad_batch.push(TileLayer::Hint, 2, &quad(1.0));
+    quad_batch.push(TileLayer::Grass, 1, &quad(2.0));
+    quad_batch.push(TileLayer::Hint, 3, &quad(3.0));
+
+    let runs = quad_batch.finish();
+
+    assert_eq!(runs.len(), 3);
+    assert_eq!(runs[0].texture_id(), 1);
+    assert_eq!(runs[0].quad_count(), 2);
+    assert_eq!(runs[0].vertices()[16], 2.0);
+    assert_eq!(runs[1].texture_id(), 2);
+    assert_eq!(runs[2].texture_id(), 3);
+  }
+
+  #[test]
+  fn orders_runs_by_layer() {
+    let mut quad_batch = QuadBatch::new();
+    quad_batch.push(TileLayer::Goal, 4, &quad(0.0));
+    quad_batch.push(TileLayer::Shadow, 5, &quad(0.0));
+    quad_batch.push(TileLayer::Grass, 1, &quad(0.0));
+    quad_batch.push(TileLayer::Shadow, 6, &quad(0.0));
+
+    let layers: Vec<(TileLayer, u32)> = quad_batch.finish().iter().map(| run | (run.layer, run.texture_id)).collect();
+
+    assert_eq!(layers, vec![
+      (TileLayer::Grass, 1),
+      (TileLayer::Shadow, 5),
+      (TileLayer::Shadow, 6),
+      (TileLayer::Goal, 4)
+    ]);
+  }
+
+  #[test]
+  fn starts_empty_after_finishing() {
+    let mut quad_batch = QuadBatch::new();
+    quad_batch.push(TileLayer::Mark, 1, &quad(0.0));
+
+    assert_eq!(quad_batch.is_empty(), false);
+    quad_batch.finish();
+    assert_eq!(quad_batch.is_empty(), true);
+  }
+}
@@ -1,58 +1,55 @@
 use crate::{
   sprites::Grass,
-  Camera,
-  ShaderProgram,
-  Vector2,
-  render_sprite
+  SpriteBatch,
+  TileLayer,
+  Vector2
 };
 
-pub fn render_grass(hint_value: usize, grass_sprites: &mut Grass, camera: &Camera, quad_shader_program: &ShaderProgram, location: Vector2) -> Result<(), String> {
+pub fn render_grass(hint_value: usize, grass_sprites: &mut Grass, sprite_batch: &mut SpriteBatch, location: Vector2) {
   grass_sprites.mut_zero().mut_transform().translate_to(location);
-  render_sprite(grass_sprites.zero(), camera, quad_shader_program)?;
+  sprite_batch.push_sprite(TileLayer::Grass, grass_sprites.zero());
   
   match hint_value {
     1 => {
       grass_sprites.mut_one().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.one(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.one())
     },
 
     2 => {
       grass_sprites.mut_two().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.two(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.two())
     },
 
     3 => {
       grass_sprites.mut_three().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.three(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.three())
     },
 
     4 => {
       grass_sprites.mut_four().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.four(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.four())
     },
 
     5 => {
       grass_sprites.mut_five().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.five(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.five())
     },
 
     6 => {
       grass_sprites.mut_six().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.six(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.six())
     },
 
     7 => {
       grass_sprites.mut_seven().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.seven(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.seven())
     },
 
     8 => {
       grass_sprites.mut_eight().mut_transform().translate_to(location);
-      render_sprite(grass_sprites.eight(), camera, quad_shader_program)?
+      sprite_batch.push_sprite(TileLayer::Hint, grass_sprites.eight())
     },
 
     _ => {}
   }
-  
-  Ok(())
 }
\ No newline at end of file
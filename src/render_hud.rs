@@ -0,0 +1,38 @@
+use sdl2::{
+  pixels::Color,
+  ttf::Font
+};
+
+use crate::{
+  HudText,
+  TextRenderer,
+  TextStyle,
+  TextAlignment,
+  Camera,
+  ShaderProgram,
+  Vector2
+};
+
+const HUD_MARGIN: f32 = 16.0;
+const MARKING_COLOR: Color = Color::RGB(255, 224, 96);
+
+pub fn render_hud(hud_text: &HudText, text_renderer: &mut TextRenderer, font: &Font, text_style: &TextStyle, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
+  let left = -camera.half_view_size.x + HUD_MARGIN;
+  let top = -camera.half_view_size.y + HUD_MARGIN + text_style.size / 2.0;
+  let left_style = text_style.aligned(TextAlignment::Left);
+
+  for (index, line) in [&hud_text.score, &hud_text.snakes, &hud_text.timer].iter().enumerate() {
+    text_renderer.queue(font, line, Vector2 { x: left, y: top + index as f32 * text_style.size }, &left_style)?;
+  }
+
+  let seed_style = TextStyle { size: text_style.size / 2.0, ..left_style };
+  let bottom = camera.half_view_size.y - HUD_MARGIN - seed_style.size / 2.0;
+  text_renderer.queue(font, &hud_text.seed, Vector2 { x: left, y: bottom }, &seed_style)?;
+
+  if hud_text.is_marking {
+    let marking_style = TextStyle { color: MARKING_COLOR, ..*text_style };
+    text_renderer.queue(font, "Marking", Vector2 { x: 0.0, y: top }, &marking_style)?;
+  }
+
+  text_renderer.draw(camera, shader_program)
+}
@@ -1,19 +1,48 @@
 use crate::{
   sprites,
+  MainMenuState,
+  MainMenuItem,
+  Vector2,
   Camera,
   ShaderProgram,
   render_sprite
 };
 
-pub fn render_main_menu(main_menu_sprites: &sprites::MainMenu, emblem_sprites: &sprites::Emblems, camera: &Camera, text_shader_program: &ShaderProgram, quad_shader_program: &ShaderProgram) -> Result<(), String> {
+pub fn render_main_menu(main_menu_state: &MainMenuState, main_menu_sprites: &sprites::MainMenu, emblem_sprites: &mut sprites::Emblems, camera: &Camera, text_shader_program: &ShaderProgram, quad_shader_program: &ShaderProgram) -> Result<(), String> {
+  let x_offset = match main_menu_state.selected_menu_item {
+    MainMenuItem::NewGame => main_menu_sprites.new_game().width() / 2 + 32,
+    MainMenuItem::LoadGame => main_menu_sprites.load_game().width() / 2 + 32,
+    MainMenuItem::HighScores => main_menu_sprites.high_scores().width() / 2 + 32,
+    MainMenuItem::Settings => main_menu_sprites.settings().width() / 2 + 32,
+    MainMenuItem::Quit => main_menu_sprites.quit().width() / 2 + 32,
+  };
+
+  let y_offset = match main_menu_state.selected_menu_item {
+    MainMenuItem::NewGame => 0,
+    MainMenuItem::LoadGame => 32,
+    MainMenuItem::HighScores => 64,
+    MainMenuItem::Settings => 96,
+    MainMenuItem::Quit => 128,
+  };
+
+  emblem_sprites.mut_snakes().mut_transform().translate_to(Vector2 {
+    x: x_offset as f32,
+    y: y_offset as f32
+  });
+
+  emblem_sprites.mut_drakes().mut_transform().translate_to(Vector2 {
+    x: -(x_offset as f32),
+    y: y_offset as f32
+  });
+
   render_sprite(main_menu_sprites.new_game(), camera, &text_shader_program)?;
   render_sprite(main_menu_sprites.load_game(), camera, &text_shader_program)?;
   render_sprite(main_menu_sprites.high_scores(), camera, &text_shader_program)?;
   render_sprite(main_menu_sprites.settings(), camera, &text_shader_program)?;
   render_sprite(main_menu_sprites.quit(), camera, &text_shader_program)?;
-  
+
   render_sprite(emblem_sprites.snakes(), camera, &quad_shader_program)?;
   render_sprite(emblem_sprites.drakes(), camera, &quad_shader_program)?;
 
   Ok(())
-}
\ No newline at end of file
+}
@@ -1,108 +1,105 @@
 use crate::{
   sprites::Shadows,
-  Camera,
-  ShaderProgram,
-  render_sprite,
+  SpriteBatch,
+  TileLayer,
   Vector2
 };
 
-pub fn render_shadow(shadow_bits: &[bool; 4], shadow_sprites: &mut Shadows, camera: &Camera, quad_shader_program: &ShaderProgram, location: Vector2) -> Result<(), String> {
+pub fn render_shadow(shadow_bits: &[bool; 4], shadow_sprites: &mut Shadows, sprite_batch: &mut SpriteBatch, location: Vector2) {
   match shadow_bits {
     [false, false, false, false] => {
       shadow_sprites.mut_zero().mut_transform().translate_to(location);
-      render_sprite(shadow_sprites.zero(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.zero());
     },
     
     [false, false, false, true] => {
       shadow_sprites.mut_one().mut_transform().translate_to(location);
       shadow_sprites.mut_one().mut_transform().rotate_to(180.0);
-      render_sprite(shadow_sprites.one(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.one());
     },
     
     [false, false, true, false] => {
       shadow_sprites.mut_one().mut_transform().translate_to(location);
       shadow_sprites.mut_one().mut_transform().rotate_to(90.0);
-      render_sprite(shadow_sprites.one(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.one());
     },
     
     [false, false, true, true] => {
       shadow_sprites.mut_three().mut_transform().translate_to(location);
       shadow_sprites.mut_three().mut_transform().rotate_to(90.0);
-      render_sprite(shadow_sprites.three(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.three());
     },
     
     [false, true, false, false] => {
       shadow_sprites.mut_one().mut_transform().translate_to(location);
       shadow_sprites.mut_one().mut_transform().rotate_to(270.0);
-      render_sprite(shadow_sprites.one(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.one());
     },
     
     [false, true, false, true] => {
       shadow_sprites.mut_three().mut_transform().translate_to(location);
       shadow_sprites.mut_three().mut_transform().rotate_to(180.0);
-      render_sprite(shadow_sprites.three(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.three());
     },
     
     [false, true, true, false] => {
       shadow_sprites.mut_two().mut_transform().translate_to(location);
       shadow_sprites.mut_two().mut_transform().rotate_to(90.0);
-      render_sprite(shadow_sprites.two(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.two());
     },
     
     [false, true, true, true] => {
       shadow_sprites.mut_two().mut_transform().translate_to(location);
       shadow_sprites.mut_two().mut_transform().rotate_to(180.0);
-      render_sprite(shadow_sprites.four(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.four());
     },
     
     [true, false, false, false] => {
       shadow_sprites.mut_one().mut_transform().translate_to(location);
       shadow_sprites.mut_one().mut_transform().rotate_to(0.0);
-      render_sprite(shadow_sprites.one(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.one());
     },
     
     [true, false, false, true] => {
       shadow_sprites.mut_two().mut_transform().translate_to(location);
       shadow_sprites.mut_two().mut_transform().rotate_to(0.0);
-      render_sprite(shadow_sprites.two(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.two());
     },
     
     [true, false, true, false] => {
       shadow_sprites.mut_three().mut_transform().translate_to(location);
       shadow_sprites.mut_three().mut_transform().rotate_to(0.0);
-      render_sprite(shadow_sprites.three(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.three());
     },
     
     [true, false, true, true] => {
       shadow_sprites.mut_four().mut_transform().translate_to(location);
       shadow_sprites.mut_four().mut_transform().rotate_to(90.0);
-      render_sprite(shadow_sprites.four(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.four());
     },
     
     [true, true, false, false] => {
       shadow_sprites.mut_three().mut_transform().translate_to(location);
       shadow_sprites.mut_three().mut_transform().rotate_to(270.0);
-      render_sprite(shadow_sprites.three(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.three());
     },
     
     [true, true, false, true] => {
       shadow_sprites.mut_four().mut_transform().translate_to(location);
       shadow_sprites.mut_four().mut_transform().rotate_to(270.0);
-      render_sprite(shadow_sprites.four(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.four());
     },
     
     [true, true, true, false] => {
       shadow_sprites.mut_four().mut_transform().translate_to(location);
       shadow_sprites.mut_four().mut_transform().rotate_to(0.0);
-      render_sprite(shadow_sprites.four(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.four());
     },
     
     [true, true, true, true] => {
       shadow_sprites.mut_five().mut_transform().translate_to(location);
       shadow_sprites.mut_five().mut_transform().rotate_to(0.0);
-      render_sprite(shadow_sprites.five(), camera, quad_shader_program)?;
+      sprite_batch.push_sprite(TileLayer::Shadow, shadow_sprites.five());
     }
   }
-  
-  Ok(())
 }
\ No newline at end of file
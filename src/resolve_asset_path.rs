@@ -0,0 +1,48 @@
+use std::path::{
+  Component,
+  Path,
+  PathBuf
+};
+
+pub fn resolve_asset_path(root: &Path, asset_id: &str) -> Result<PathBuf, String> {
+  let relative_path = Path::new(asset_id);
+
+  if !relative_path.components().all(| component | matches!(component, Component::Normal(_))) {
+    return Err(format!("Invalid asset id \"{}\": asset ids must be relative paths inside the asset root", asset_id));
+  }
+
+  let asset_path = root.join(relative_path);
+
+  match asset_path.is_file() {
+    true => Ok(asset_path),
+    false => Err(format!("Missing asset \"{}\": expected a file at {}", asset_id, asset_path.display()))
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use super::resolve_asset_path;
+
+  #[test]
+  fn resolves_against_the_root() {
+    assert_eq!(resolve_asset_path(Path::new("./src"), "main.rs"), Ok(Path::new("./src/main.rs").to_path_buf()));
+  }
+
+  #[test]
+  fn reports_missing_assets() {
+    match resolve_asset_path(Path::new("./res"), "textures/missing.png") {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Missing asset \"textures/missing.png\": expected a file at ./res/textures/missing.png")
+    }
+  }
+
+  #[test]
+  fn rejects_ids_outside_the_root() {
+    match resolve_asset_path(Path::new("./res"), "../Cargo.toml") {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid asset id \"../Cargo.toml\": asset ids must be relative paths inside the asset root")
+    }
+  }
+}
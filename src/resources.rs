@@ -0,0 +1,231 @@
+use std::{
+  collections::HashMap,
+  path::{
+    Path,
+    PathBuf
+  },
+  rc::Rc
+};
+
+use crate::{
+  Texture,
+  TextureRegion,
+  Sprite,
+  VertexArray,
+  VertexBuffer,
+  ElementBuffer,
+  AtlasPlacement,
+  AnimationSheet,
+  generate_vertex_data,
+  resolve_asset_path,
+  embedded_asset,
+  pack_atlas,
+  compose_atlas_pages,
+  deserialize_animation_sheet
+};
+
+const ATLAS_PAGE_SIZE: u32 = 1024;
+const ATLAS_PADDING: u32 = 1;
+
+pub struct Resources {
+  root: PathBuf,
+  textures: HashMap<String, Rc<Texture>>,
+  atlas_pages: Vec<Rc<Texture>>,
+  atlas_placements: HashMap<String, AtlasPlacement>,
+  quads: HashMap<(u32, u32, [u32; 4]), Rc<VertexArray>>,
+  animation_sheets: HashMap<String, Rc<AnimationSheet>>
+}
+
+impl Resources {
+  pub fn new(root: &Path) -> Self {
+    Self {
+      root: root.to_path_buf(),
+      textures: HashMap::new(),
+      atlas_pages: Vec::new(),
+      atlas_placements: HashMap::new(),
+      quads: HashMap::new(),
+      animation_sheets: HashMap::new()
+    }
+  }
+
+  pub fn read(&self, asset_id: &str) -> Result<Vec<u8>, String> {
+    match resolve_asset_path(&self.root, asset_id) {
+      Ok(asset_path) => std::fs::read(asset_path).map_err(| error | format!("Could not read asset \"{}\": {}", asset_id, error)),
+
+      Err(error) => match embedded_asset(asset_id) {
+        Some(bytes) => Ok(bytes.to_vec()),
+        None => Err(error)
+      }
+    }
+  }
+
+  pub fn read_to_string(&self, asset_id: &str) -> Result<String, String> {
+    String::from_utf8(self.read(asset_id)?).map_err(| _ | format!("Asset \"{}\" is not valid UTF-8", asset_id))
+  }
+
+  pub fn image_size(&self, asset_id: &str) -> Result<(u32, u32), String> {
+    image::ImageReader::new(std::io::Cursor::new(self.read(asset_id)?))
+      .with_guessed_format()
+      .map_err(| error | error.to_string())?
+      .into_dimensions()
+      .map_err(| error | format!("Could not decode \"{}\": {}", asset_id, error))
+  }
+
+  pub fn texture(&mut self, asset_id: &str) -> Result<Rc<Texture>, String> {
+    if let Some(texture) = self.textures.get(asset_id) { return Ok(Rc::clone(texture)) }
+
+    let texture = Rc::new(Texture::decode(&self.read(asset_id)?)?);
+    self.textures.insert(asset_id.to_string(), Rc::clone(&texture));
+
+    Ok(texture)
+  }
+
+  pub fn animation_sheet(&mut self, asset_id: &str, required_clips: &[&str]) -> Result<Rc<AnimationSheet>, String> {
+    let sheet = match self.animation_sheets.get(asset_id) {
+      Some(sheet) => Rc::clone(sheet),
+      None => {
+        let sheet = deserialize_animation_sheet(self.read_to_string(asset_id)?).map_err(| error | format!("Invalid animation sheet \"{}\": {}", asset_id, error))?;
+        let (width, height) = self.image_size(&sheet.texture)?;
+        sheet.check_bounds(width, height).map_err(| error | format!("Invalid animation sheet \"{}\": {}", asset_id, error))?;
+
+        Rc::new(sheet)
+      }
+    };
+
+    sheet.require_clips(required_clips).map_err(| error | format!("Invalid animation sheet \"{}\": {}", asset_id, error))?;
+    self.animation_sheets.insert(asset_id.to_string(), Rc::clone(&sheet));
+
+    Ok(sheet)
+  }
+
+  pub fn load_atlas(&mut self, asset_ids: &[&str]) -> Result<(), String> {
+    let mut images = Vec::new();
+    for asset_id in asset_ids {
+      let image = image::load_from_memory(&self.read(asset_id)?).map_err(| error | format!("Could not decode \"{}\": {}", asset_id, error))?;
+      images.push(image.to_rgba8());
+    }
+
+    let sizes: Vec<(u32, u32)> = images.iter().map(| image | image.dimensions()).collect();
+    let layout = pack_atlas(&sizes, ATLAS_PAGE_SIZE, ATLAS_PADDING)?;
+
+    let first_page = self.atlas_pages.len();
+    for page in compose_atlas_pages(&images, &layout, ATLAS_PADDING) {
+      self.atlas_pages.push(Rc::new(Texture::atlas_page(&page)));
+    }
+
+    for (asset_id, placement) in asset_ids.iter().zip(layout.placements) {
+      self.atlas_placements.insert(asset_id.to_string(), AtlasPlacement { page: first_page + placement.page, ..placement });
+    }
+
+    log::info!("Packed {} textures into {} atlas pages", asset_ids.len(), layout.page_sizes.len());
+
+    Ok(())
+  }
+
+  pub fn region(&mut self, asset_id: &str) -> Result<TextureRegion, String> {
+    match self.atlas_placements.get(asset_id) {
+      Some(placement) => {
+        let page = &self.atlas_pages[placement.page];
+        Ok(TextureRegion::new(Rc::clone(page), placement.uv_rect(page.width(), page.height()), placement.width, placement.height))
+      },
+
+      None => Ok(TextureRegion::full(self.texture(asset_id)?))
+    }
+  }
+
+  pub fn reload_texture(&mut self, asset_id: &str) -> Result<bool, String> {
+    if let Some(placement) = self.atlas_placements.get(asset_id) {
+      self.atlas_pages[placement.page].reload_region(&self.read(asset_id)?, placement, ATLAS_PADDING)?;
+      return Ok(true);
+    }
+
+    match self.textures.get(asset_id) {
+      Some(texture) => {
+        texture.reload(&self.read(asset_id)?)?;
+        Ok(true)
+      },
+
+      None => Ok(false)
+    }
+  }
+
+  pub fn quad(&mut self, region: &TextureRegion) -> Rc<VertexArray> {
+    let uv_rect = region.uv_rect();
+    let key = (region.width(), region.height(), [uv_rect.left.to_bits(), uv_rect.top.to_bits(), uv_rect.right.to_bits(), uv_rect.bottom.to_bits()]);
+
+    let quad = self.quads.entry(key).or_insert_with(|| {
+      let vertex_buffer = VertexBuffer::new(generate_vertex_data(region.width(), region.height(), &uv_rect));
+      let element_buffer = ElementBuffer::new(vec![0, 1, 2, 0, 2, 3]);
+
+      Rc::new(VertexArray::new(&vertex_buffer, &element_buffer))
+    });
+
+    Rc::clone(quad)
+  }
+
+  pub fn sprite(&mut self, asset_id: &str) -> Result<Sprite, String> {
+    let region = self.region(asset_id)?;
+    let quad = self.quad(&region);
+
+    Ok(Sprite::new(region, quad))
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use super::Resources;
+
+  #[test]
+  fn reads_assets_from_the_root() {
+    let resources = Resources::new(Path::new("./res"));
+
+    match resources.read_to_string("shaders/quad_vertex_shader.glsl") {
+      Ok(source) => assert!(source.contains("void main")),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn loads_the_shipped_animation_sheets() {
+    let mut resources = Resources::new(Path::new("./res"));
+
+    for (asset_id, texture, clips) in [
+      ("animations/drake.anim", "textures/drake.png", vec!["idle", "walk", "hurt"]),
+      ("animations/snake.anim", "textures/snake.png", vec!["idle", "strike"]),
+      ("animations/stars.anim", "textures/stars.png", vec!["idle"]),
+      ("animations/water.anim", "textures/water.png", vec!["idle"])
+    ] {
+      match resources.animation_sheet(asset_id, &clips) {
+        Ok(sheet) => {
+          assert_eq!(sheet.texture, texture);
+          assert_eq!(sheet.clip_index("idle"), Some(0));
+        },
+
+        Err(error) => panic!("Unexpected error: {}", error)
+      }
+    }
+  }
+
+  #[test]
+  fn rejects_sheets_missing_required_clips() {
+    let mut resources = Resources::new(Path::new("./res"));
+
+    match resources.animation_sheet("animations/stars.anim", &["idle", "strike"]) {
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Invalid animation sheet \"animations/stars.anim\": Missing clip: strike")
+    }
+  }
+
+  #[test]
+  fn reports_missing_assets() {
+    let resources = Resources::new(Path::new("./missing_assets"));
+
+    match resources.read("textures/drake.png") {
+      Ok(_) if cfg!(feature = "embedded-assets") => {},
+      Ok(_) => panic!("Expected to fail"),
+      Err(error) => assert_eq!(error, "Missing asset \"textures/drake.png\": expected a file at ./missing_assets/textures/drake.png")
+    }
+  }
+}
@@ -0,0 +1,105 @@
+use std::{
+  fs::{
+    File,
+    OpenOptions
+  },
+  io::Write,
+  path::{
+    Path,
+    PathBuf
+  }
+};
+
+pub struct RotatingLogFile {
+  path: PathBuf,
+  max_size: u64,
+  num_backups: usize,
+  file: File,
+  size: u64
+}
+
+impl RotatingLogFile {
+  pub fn open(path: &Path, max_size: u64, num_backups: usize) -> Result<Self, String> {
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .map_err(| error | error.to_string())?;
+
+    let size = file.metadata().map_err(| error | error.to_string())?.len();
+
+    Ok(
+      Self {
+        path: path.to_path_buf(),
+        max_size,
+        num_backups,
+        file,
+        size
+      }
+    )
+  }
+
+  pub fn write_line(&mut self, line: &str) -> Result<(), String> {
+    let line_size = line.len() as u64 + 1;
+    if self.size > 0 && self.size + line_size > self.max_size { self.rotate()? }
+
+    writeln!(self.file, "{}", line).map_err(| error | error.to_string())?;
+    self.size += line_size;
+
+    Ok(())
+  }
+
+  fn rotate(&mut self) -> Result<(), String> {
+    for index in (1..self.num_backups).rev() {
+      let backup_path = self.backup_path(index);
+      if backup_path.exists() {
+        std::fs::rename(&backup_path, self.backup_path(index + 1)).map_err(| error | error.to_string())?;
+      }
+    }
+
+    if self.num_backups > 0 {
+      std::fs::rename(&self.path, self.backup_path(1)).map_err(| error | error.to_string())?;
+    }
+
+    self.file = File::create(&self.path).map_err(| error | error.to_string())?;
+    self.size = 0;
+
+    Ok(())
+  }
+
+  fn backup_path(&self, index: usize) -> PathBuf {
+    PathBuf::from(format!("{}.{}", self.path.display(), index))
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use super::RotatingLogFile;
+
+  #[test]
+  fn rotates_when_full() {
+    let log_path = Path::new("./rotates_when_full_test_log.txt");
+
+    match RotatingLogFile::open(log_path, 8, 2) {
+      Ok(mut log_file) => {
+        for line in ["first", "second", "third", "fourth"] {
+          if let Err(error) = log_file.write_line(line) { panic!("Unexpected error: {}", error) }
+        }
+
+        let read = | path: &str | std::fs::read_to_string(path).unwrap_or_default();
+
+        assert_eq!(read("./rotates_when_full_test_log.txt"), "fourth\n");
+        assert_eq!(read("./rotates_when_full_test_log.txt.1"), "third\n");
+        assert_eq!(read("./rotates_when_full_test_log.txt.2"), "second\n");
+
+        for path in ["./rotates_when_full_test_log.txt", "./rotates_when_full_test_log.txt.1", "./rotates_when_full_test_log.txt.2"] {
+          if let Err(error) = std::fs::remove_file(path) { panic!("Unexpected error: {}", error) }
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
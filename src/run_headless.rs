@@ -0,0 +1,33 @@
+use std::io::Read;
+
+use crate::{
+  CommandLineOptions,
+  DataPaths,
+  GameSession,
+  GameParameters,
+  MapSize,
+  load_settings,
+  play_script,
+  print_playfield,
+  start_game_session
+};
+
+pub fn run_headless(options: &CommandLineOptions, data_paths: &DataPaths) -> Result<(), String> {
+  let settings = load_settings(&data_paths.settings_file)?;
+
+  let mut game_session = match start_game_session(options, &settings, &data_paths.saves_directory)? {
+    Some(game_session) => game_session,
+    None => {
+      let parameters = GameParameters::new(MapSize::from(settings.default_width, settings.default_height)?, settings.default_num_snakes);
+      GameSession::new(parameters, rand::random())?
+    }
+  };
+
+  let mut script = String::new();
+  std::io::stdin().read_to_string(&mut script).map_err(| error | error.to_string())?;
+
+  play_script(&mut game_session, &script)?;
+  print!("{}", print_playfield(&game_session.snapshot()));
+
+  Ok(())
+}
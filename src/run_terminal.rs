@@ -0,0 +1,276 @@
+use std::{
+  cell::RefCell,
+  rc::Rc,
+  time::{
+    Duration,
+    Instant
+  }
+};
+
+use crossterm::event::{
+  self,
+  Event,
+  KeyCode,
+  KeyEvent,
+  KeyEventKind,
+  KeyModifiers
+};
+
+use crate::{
+  CommandLineOptions,
+  DataPaths,
+  Input,
+  Message,
+  MessageQueue,
+  Scenes,
+  SessionStatistics,
+  MainMenuState,
+  NewGameState,
+  GameSession,
+  PauseMenuState,
+  HighScoresState,
+  LoadGameState,
+  SettingsState,
+  TerminalScreen,
+  TerminalRepeat,
+  TextField,
+  apply_new_game_defaults,
+  load_settings,
+  load_input_bindings,
+  terminal_keycode,
+  update_main_menu,
+  update_new_game,
+  update_playfield,
+  update_pause_menu,
+  update_save_game,
+  update_load_game,
+  update_high_scores,
+  update_add_high_score,
+  update_settings,
+  print_main_menu,
+  print_new_game,
+  print_playfield,
+  print_pause_menu,
+  print_save_game,
+  print_load_game,
+  print_high_scores,
+  print_add_high_score,
+  print_settings,
+  print_session_statistics,
+  start_game_session
+};
+
+pub fn run_terminal(options: &CommandLineOptions, data_paths: &DataPaths) -> Result<(), String> {
+  let settings = load_settings(&data_paths.settings_file)?;
+  let input_bindings = load_input_bindings(&data_paths.bindings_file)?;
+
+  let mut save_name_field = TextField::new(32, "/\\:*?\"<>|");
+  let mut high_score_name_field = TextField::new(16, ",");
+
+  let mut current_scene = Scenes::MainMenu;
+  let mut is_running = true;
+  let mut message_queue = MessageQueue::new();
+  let session_statistics = Rc::new(RefCell::new(SessionStatistics::new()));
+  message_queue.subscribe(&session_statistics);
+
+  let mut main_menu_state = MainMenuState::new();
+  let mut new_game_state = NewGameState::new();
+  apply_new_game_defaults(&mut new_game_state, &settings);
+  let mut game_session = GameSession::empty(1234);
+  let mut pause_menu_state = PauseMenuState::new();
+  let mut high_scores_state = HighScoresState::new();
+  let mut load_game_state = LoadGameState::new();
+  let mut settings_state = SettingsState::new(settings, input_bindings);
+
+  if let Some(started_game_session) = start_game_session(options, &settings, &data_paths.saves_directory)? {
+    game_session = started_game_session;
+    current_scene = Scenes::Playfield;
+  }
+
+  let mut frame_duration_cap = Duration::from_millis(1000 / settings.fps_cap);
+  let mut terminal_screen = TerminalScreen::new()?;
+  let mut terminal_repeat = TerminalRepeat::new(settings.repeat_delay());
+
+  while is_running {
+    let frame_start = Instant::now();
+
+    while event::poll(Duration::ZERO).map_err(| error | error.to_string())? {
+      match event::read().map_err(| error | error.to_string())? {
+        Event::Key(key_event) => {
+          let is_repeat = terminal_repeat.is_repeat(key_event.code, key_event.kind, Instant::now());
+          if key_event.kind == KeyEventKind::Release { continue }
+
+          let text_field = match current_scene {
+            Scenes::SaveGame => Some(&mut save_name_field),
+            Scenes::AddHighScore => Some(&mut high_score_name_field),
+            _ => None
+          };
+
+          handle_terminal_key(key_event, is_repeat, &mut message_queue, &settings_state, current_scene, text_field);
+        },
+
+        Event::Resize( .. ) => terminal_screen.invalidate(),
+
+        _ => {}
+      }
+    }
+
+    message_queue.swap_buffers();
+    for message in message_queue.messages() {
+      match *message {
+        Message::RequestShutdown => is_running = false,
+        Message::RequestScene(new_scene) => {
+          log::debug!("Switching scene from {:?} to {:?}", current_scene, new_scene);
+          current_scene = new_scene;
+        },
+
+        Message::SettingsChanged(new_settings) => {
+          frame_duration_cap = Duration::from_millis(1000 / new_settings.fps_cap);
+          terminal_repeat.set_window(new_settings.repeat_delay());
+          apply_new_game_defaults(&mut new_game_state, &new_settings);
+        },
+
+        _ => {}
+      }
+    }
+
+    let scene_text = match current_scene {
+      Scenes::MainMenu => {
+        update_main_menu(&mut message_queue, &mut main_menu_state);
+        print_main_menu(&main_menu_state)
+      },
+
+      Scenes::NewGame => {
+        update_new_game(&mut new_game_state, &mut game_session, &mut message_queue)?;
+        print_new_game(&new_game_state)
+      },
+
+      Scenes::Playfield => {
+        update_playfield(&mut message_queue, &mut game_session, false)?;
+        print_playfield(&game_session.snapshot())
+      },
+
+      Scenes::Pause => {
+        update_pause_menu(&mut message_queue, &mut pause_menu_state);
+        print_pause_menu(&pause_menu_state)
+      },
+
+      Scenes::SaveGame => {
+        update_save_game(&mut message_queue, game_session.playfield_state(), &mut save_name_field, &data_paths.saves_directory)?;
+        print_save_game(&save_name_field)
+      },
+
+      Scenes::LoadGame => {
+        update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, &data_paths.saves_directory)?;
+        print_load_game(&load_game_state)
+      },
+
+      Scenes::HighScores => {
+        update_high_scores(&mut message_queue, &mut high_scores_state, &data_paths.high_scores_file)?;
+        print_high_scores(&high_scores_state)
+      },
+
+      Scenes::AddHighScore => {
+        update_add_high_score(&mut message_queue, game_session.playfield_state(), &mut high_score_name_field, &data_paths.high_scores_file)?;
+        print_add_high_score(&high_score_name_field)
+      },
+
+      Scenes::Settings => {
+        update_settings(&mut message_queue, &mut settings_state, &data_paths.settings_file, &data_paths.bindings_file)?;
+        print_settings(&settings_state)
+      }
+    };
+
+    terminal_screen.draw(&format!("{}\nCtrl+C to quit\n", scene_text))?;
+
+    let frame_duration = Instant::now() - frame_start;
+
+    if frame_duration < frame_duration_cap {
+      std::thread::sleep(frame_duration_cap - frame_duration);
+    }
+  }
+
+  drop(terminal_screen);
+  print!("{}", print_session_statistics(&session_statistics.borrow()));
+
+  Ok(())
+}
+
+fn handle_terminal_key(key_event: KeyEvent, is_repeat: bool, message_queue: &mut MessageQueue, settings_state: &SettingsState, current_scene: Scenes, text_field: Option<&mut TextField>) {
+  if key_event.modifiers.contains(KeyModifiers::CONTROL) && key_event.code == KeyCode::Char('c') {
+    message_queue.post(Message::RequestShutdown);
+    return;
+  }
+
+  if current_scene == Scenes::Settings && settings_state.is_capturing_key {
+    if let Some(keycode) = terminal_keycode(key_event.code) { message_queue.post(Message::KeyCaptured(keycode)) }
+    return;
+  }
+
+  match text_field {
+    Some(text_field) => match key_event.code {
+      KeyCode::Char(character) => text_field.insert(&character.to_string()),
+      KeyCode::Backspace => text_field.backspace(),
+      KeyCode::Delete => text_field.delete(),
+      KeyCode::Left => text_field.move_left(),
+      KeyCode::Right => text_field.move_right(),
+      KeyCode::Home => text_field.move_home(),
+      KeyCode::End => text_field.move_end(),
+
+      key_code => match terminal_keycode(key_code).and_then(| keycode | settings_state.input_bindings.input_for(keycode)) {
+        Some(Input::Confirm) => message_queue.post(Message::PlayerInput(Input::Confirm)),
+        Some(Input::Cancel) => message_queue.post(Message::PlayerInput(Input::Cancel)),
+        _ => {}
+      }
+    },
+
+    None => match terminal_keycode(key_event.code).and_then(| keycode | settings_state.input_bindings.input_for(keycode)) {
+      Some(input @ (Input::Up | Input::Left | Input::Right | Input::Down)) if is_repeat && current_scene == Scenes::Playfield => message_queue.post(Message::RepeatedInput(input)),
+      Some(input) => message_queue.post(Message::PlayerInput(input)),
+      None => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers
+  };
+
+  use crate::{
+    MessageQueue,
+    Message,
+    Input,
+    Scenes,
+    Settings,
+    SettingsState,
+    InputBindings
+  };
+
+  use super::handle_terminal_key;
+
+  fn handled_messages(key_code: KeyCode, is_repeat: bool, current_scene: Scenes) -> Vec<Message> {
+    let settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    let mut message_queue = MessageQueue::new();
+
+    handle_terminal_key(KeyEvent::new(key_code, KeyModifiers::NONE), is_repeat, &mut message_queue, &settings_state, current_scene, None);
+
+    message_queue.swap_buffers();
+    message_queue.messages().clone()
+  }
+
+  #[test]
+  fn repeats_arrows_only_on_the_playfield() {
+    assert_eq!(handled_messages(KeyCode::Left, true, Scenes::Playfield), vec![Message::RepeatedInput(Input::Left)]);
+    assert_eq!(handled_messages(KeyCode::Left, true, Scenes::MainMenu), vec![Message::PlayerInput(Input::Left)]);
+    assert_eq!(handled_messages(KeyCode::Left, false, Scenes::Playfield), vec![Message::PlayerInput(Input::Left)]);
+  }
+
+  #[test]
+  fn forwards_quick_presses_of_other_keys() {
+    assert_eq!(handled_messages(KeyCode::Char(' '), true, Scenes::Playfield), vec![Message::PlayerInput(Input::Action)]);
+  }
+}
@@ -0,0 +1,10 @@
+use std::path::Path;
+
+use crate::{
+  InputBindings,
+  serialize_input_bindings
+};
+
+pub fn save_input_bindings(file_path: &Path, input_bindings: &InputBindings) -> Result<(), String> {
+  std::fs::write(file_path, serialize_input_bindings(input_bindings)).map_err(| error | error.to_string())
+}
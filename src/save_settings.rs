@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use crate::{
+  Settings,
+  serialize_settings
+};
+
+pub fn save_settings(file_path: &Path, settings: &Settings) -> Result<(), String> {
+  std::fs::write(file_path, serialize_settings(settings)).map_err(| error | error.to_string())
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use crate::{
+    Settings,
+    load_settings
+  };
+
+  use super::save_settings;
+
+  #[test]
+  fn saved_settings_load_back() {
+    let settings_file_path = Path::new("./saved_settings_load_back_test_settings.txt");
+    let mut settings = Settings::new();
+    settings.fps_cap = 30;
+    settings.default_num_snakes = 64;
+
+    match save_settings(settings_file_path, &settings) {
+      Ok(()) => {
+        match load_settings(settings_file_path) {
+          Ok(loaded) => assert_eq!(loaded, settings),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        if let Err(error) = std::fs::remove_file(settings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
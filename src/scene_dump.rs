@@ -0,0 +1,23 @@
+pub struct SceneDump {
+  is_enabled: bool,
+  last_dump: String
+}
+
+impl SceneDump {
+  pub fn new(is_enabled: bool) -> Self {
+    Self {
+      is_enabled,
+      last_dump: String::new()
+    }
+  }
+
+  pub fn dump(&mut self, print_scene: impl FnOnce() -> String) {
+    if !self.is_enabled { return }
+
+    let scene_text = print_scene();
+    if scene_text == self.last_dump { return }
+
+    log::debug!(target: "scenes", "\n{}", scene_text);
+    self.last_dump = scene_text;
+  }
+}
@@ -0,0 +1,35 @@
+use crate::{
+  InputBindings,
+  input_bindings::{
+    BINDABLE_INPUTS,
+    input_name
+  },
+  key_name
+};
+
+pub fn serialize_input_bindings(input_bindings: &InputBindings) -> String {
+  let mut contents = String::new();
+
+  for input in BINDABLE_INPUTS {
+    let key_names: Vec<&str> = input_bindings
+      .keys_for(input)
+      .into_iter()
+      .filter_map(key_name)
+      .collect();
+
+    contents.push_str(&format!("{}={}\n", input_name(input), key_names.join(",")));
+  }
+
+  contents
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::InputBindings;
+  use super::serialize_input_bindings;
+
+  #[test]
+  fn serializes_defaults() {
+    assert_eq!(serialize_input_bindings(&InputBindings::new()), "Up=W,Up\nLeft=A,Left\nRight=D,Right\nDown=S,Down\nConfirm=Return\nCancel=Escape\nAction=Space\nMinimap=Tab\n");
+  }
+}
@@ -0,0 +1,40 @@
+use crate::{
+  Settings,
+  WindowMode
+};
+
+pub fn serialize_settings(settings: &Settings) -> String {
+  let mut contents = String::new();
+
+  contents.push_str(&format!("resolution={}x{}\n", settings.resolution.width(), settings.resolution.height()));
+
+  contents.push_str(match settings.window_mode {
+    WindowMode::Fullscreen => "window_mode=fullscreen\n",
+    WindowMode::Borderless => "window_mode=borderless\n",
+    WindowMode::Windowed => "window_mode=windowed\n"
+  });
+
+  contents.push_str(&format!("letterbox={}\n", settings.letterbox));
+
+  contents.push_str(&format!("fps_cap={}\n", settings.fps_cap));
+  contents.push_str(&format!("ui_scale={}\n", settings.ui_scale_percent));
+  contents.push_str(&format!("text_color={},{},{}\n", settings.text_color.0, settings.text_color.1, settings.text_color.2));
+  contents.push_str(&format!("default_width={}\n", settings.default_width));
+  contents.push_str(&format!("default_height={}\n", settings.default_height));
+  contents.push_str(&format!("default_num_snakes={}\n", settings.default_num_snakes));
+  contents.push_str(&format!("repeat_delay={}\n", settings.repeat_delay_ms));
+  contents.push_str(&format!("repeat_interval={}\n", settings.repeat_interval_ms));
+
+  contents
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::Settings;
+  use super::serialize_settings;
+
+  #[test]
+  fn serializes_defaults() {
+    assert_eq!(serialize_settings(&Settings::new()), "resolution=1600x900\nwindow_mode=fullscreen\nletterbox=true\nfps_cap=60\nui_scale=100\ntext_color=16,32,32\ndefault_width=8\ndefault_height=8\ndefault_num_snakes=16\nrepeat_delay=300\nrepeat_interval=100\n");
+  }
+}
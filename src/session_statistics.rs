@@ -0,0 +1,64 @@
+use crate::{
+  Message,
+  GameEvent,
+  Subscriber
+};
+
+pub struct SessionStatistics {
+  pub tiles_revealed: usize,
+  pub marks_placed: usize,
+  pub games_won: usize,
+  pub games_lost: usize
+}
+
+impl SessionStatistics {
+  pub fn new() -> Self {
+    Self {
+      tiles_revealed: 0,
+      marks_placed: 0,
+      games_won: 0,
+      games_lost: 0
+    }
+  }
+}
+
+impl Subscriber for SessionStatistics {
+  fn receive(&mut self, message: &Message) {
+    if let Message::GameEvent(event) = message { match event {
+      GameEvent::TileRevealed(_) => self.tiles_revealed += 1,
+      GameEvent::MarkToggled(_, true) => self.marks_placed += 1,
+      GameEvent::GoalReached(_) => self.games_won += 1,
+      GameEvent::SnakeBitten(_) => self.games_lost += 1,
+
+      _ => {}
+    }}
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Message,
+    GameEvent,
+    Subscriber
+  };
+
+  use super::SessionStatistics;
+
+  #[test]
+  fn counts_gameplay_events() {
+    let mut statistics = SessionStatistics::new();
+
+    statistics.receive(&Message::GameEvent(GameEvent::TileRevealed(1)));
+    statistics.receive(&Message::GameEvent(GameEvent::TileRevealed(2)));
+    statistics.receive(&Message::GameEvent(GameEvent::MarkToggled(3, true)));
+    statistics.receive(&Message::GameEvent(GameEvent::MarkToggled(3, false)));
+    statistics.receive(&Message::GameEvent(GameEvent::GoalReached(4)));
+    statistics.receive(&Message::RequestShutdown);
+
+    assert_eq!(statistics.tiles_revealed, 2);
+    assert_eq!(statistics.marks_placed, 1);
+    assert_eq!(statistics.games_won, 1);
+    assert_eq!(statistics.games_lost, 0);
+  }
+}
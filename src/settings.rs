@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use crate::{
+  Resolution,
+  WindowMode
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Settings {
+  pub resolution: Resolution,
+  pub window_mode: WindowMode,
+  pub letterbox: bool,
+  pub fps_cap: u64,
+  pub ui_scale_percent: usize,
+  pub text_color: (u8, u8, u8),
+  pub default_width: usize,
+  pub default_height: usize,
+  pub default_num_snakes: usize,
+  pub repeat_delay_ms: u64,
+  pub repeat_interval_ms: u64
+}
+
+impl Settings {
+  pub fn new() -> Self {
+    Self {
+      resolution: Resolution::new(1600, 900),
+      window_mode: WindowMode::Fullscreen,
+      letterbox: true,
+      fps_cap: 60,
+      ui_scale_percent: 100,
+      text_color: (16, 32, 32),
+      default_width: 8,
+      default_height: 8,
+      default_num_snakes: 16,
+      repeat_delay_ms: 300,
+      repeat_interval_ms: 100
+    }
+  }
+
+  pub fn ui_scale(&self) -> f32 {
+    self.ui_scale_percent as f32 / 100.0
+  }
+
+  pub fn repeat_delay(&self) -> Duration {
+    Duration::from_millis(self.repeat_delay_ms)
+  }
+
+  pub fn repeat_interval(&self) -> Duration {
+    Duration::from_millis(self.repeat_interval_ms)
+  }
+}
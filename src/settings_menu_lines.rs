@@ -0,0 +1,84 @@
+use crate::{
+  SettingsState,
+  WindowMode,
+  key_name,
+  input_bindings::{
+    BINDABLE_INPUTS,
+    input_name
+  },
+  settings_options::FIRST_BINDING_MENU_ITEM
+};
+
+pub fn settings_menu_lines(settings_state: &SettingsState) -> Vec<String> {
+  let settings = &settings_state.settings;
+
+  let mut lines = vec![
+    format!("Resolution {}x{}", settings.resolution.width(), settings.resolution.height()),
+
+    match settings.window_mode {
+      WindowMode::Fullscreen => "Window Mode Fullscreen".to_string(),
+      WindowMode::Borderless => "Window Mode Borderless".to_string(),
+      WindowMode::Windowed => "Window Mode Windowed".to_string()
+    },
+
+    format!("FPS Cap {}", settings.fps_cap),
+    format!("UI Scale {}%", settings.ui_scale_percent),
+    format!("Text Color {} {} {}", settings.text_color.0, settings.text_color.1, settings.text_color.2),
+    format!("Default Width {}", settings.default_width),
+    format!("Default Height {}", settings.default_height),
+    format!("Default Snakes {}", settings.default_num_snakes),
+    format!("Repeat Delay {}ms", settings.repeat_delay_ms),
+    format!("Repeat Interval {}ms", settings.repeat_interval_ms),
+    format!("Letterbox {}", if settings.letterbox { "On" } else { "Off" })
+  ];
+
+  for (index, input) in BINDABLE_INPUTS.iter().enumerate() {
+    if settings_state.is_capturing_key && settings_state.selected_menu_item_index == FIRST_BINDING_MENU_ITEM + index {
+      lines.push(format!("{} Press A Key, Escape To Cancel", input_name(*input)));
+    } else {
+      let key_names: Vec<&str> = settings_state.input_bindings
+        .keys_for(*input)
+        .into_iter()
+        .filter_map(key_name)
+        .collect();
+
+      lines.push(format!("{} {}", input_name(*input), key_names.join(" ")));
+    }
+  }
+
+  lines.push("Back".to_string());
+
+  lines
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Settings,
+    SettingsState,
+    InputBindings,
+    settings_options::NUM_SETTINGS_MENU_ITEMS
+  };
+
+  use super::settings_menu_lines;
+
+  #[test]
+  fn lists_settings_and_bindings() {
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    let lines = settings_menu_lines(&settings_state);
+
+    assert_eq!(lines.len(), NUM_SETTINGS_MENU_ITEMS);
+    assert_eq!(NUM_SETTINGS_MENU_ITEMS, 20);
+    assert_eq!(lines[0], "Resolution 1600x900");
+    assert_eq!(lines[8], "Repeat Delay 300ms");
+    assert_eq!(lines[10], "Letterbox On");
+    assert_eq!(lines[11], "Up W Up");
+    assert_eq!(lines[18], "Minimap Tab");
+    assert_eq!(lines[19], "Back");
+
+    settings_state.selected_menu_item_index = 12;
+    settings_state.is_capturing_key = true;
+
+    assert_eq!(settings_menu_lines(&settings_state)[12], "Left Press A Key, Escape To Cancel");
+  }
+}
@@ -0,0 +1,27 @@
+use crate::{
+  WindowMode,
+  input_bindings::BINDABLE_INPUTS
+};
+
+pub const RESOLUTION_OPTIONS: [(usize, usize); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+pub const WINDOW_MODE_OPTIONS: [WindowMode; 3] = [WindowMode::Fullscreen, WindowMode::Borderless, WindowMode::Windowed];
+pub const FPS_CAP_OPTIONS: [u64; 4] = [30, 60, 120, 144];
+pub const UI_SCALE_OPTIONS: [usize; 5] = [75, 100, 125, 150, 200];
+pub const TEXT_COLOR_OPTIONS: [(u8, u8, u8); 4] = [(16, 32, 32), (240, 240, 240), (255, 200, 64), (64, 160, 255)];
+pub const REPEAT_DELAY_OPTIONS: [u64; 5] = [150, 200, 300, 400, 500];
+pub const REPEAT_INTERVAL_OPTIONS: [u64; 5] = [50, 75, 100, 150, 200];
+
+pub const RESOLUTION_MENU_ITEM: usize = 0;
+pub const WINDOW_MODE_MENU_ITEM: usize = 1;
+pub const FPS_CAP_MENU_ITEM: usize = 2;
+pub const UI_SCALE_MENU_ITEM: usize = 3;
+pub const TEXT_COLOR_MENU_ITEM: usize = 4;
+pub const DEFAULT_WIDTH_MENU_ITEM: usize = 5;
+pub const DEFAULT_HEIGHT_MENU_ITEM: usize = 6;
+pub const DEFAULT_NUM_SNAKES_MENU_ITEM: usize = 7;
+pub const REPEAT_DELAY_MENU_ITEM: usize = 8;
+pub const REPEAT_INTERVAL_MENU_ITEM: usize = 9;
+pub const LETTERBOX_MENU_ITEM: usize = 10;
+pub const FIRST_BINDING_MENU_ITEM: usize = LETTERBOX_MENU_ITEM + 1;
+pub const BACK_MENU_ITEM: usize = FIRST_BINDING_MENU_ITEM + BINDABLE_INPUTS.len();
+pub const NUM_SETTINGS_MENU_ITEMS: usize = BACK_MENU_ITEM + 1;
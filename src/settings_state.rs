@@ -0,0 +1,24 @@
+use crate::{
+  Settings,
+  InputBindings
+};
+
+pub struct SettingsState {
+  pub selected_menu_item_index: usize,
+  pub settings: Settings,
+  pub input_bindings: InputBindings,
+  pub is_capturing_key: bool,
+  pub status: Option<String>
+}
+
+impl SettingsState {
+  pub fn new(settings: Settings, input_bindings: InputBindings) -> Self {
+    Self {
+      selected_menu_item_index: 0,
+      settings,
+      input_bindings,
+      is_capturing_key: false,
+      status: None
+    }
+  }
+}
@@ -1,5 +1,7 @@
 use std::ffi::CString;
 
+use sdl2::pixels::Color;
+
 use crate::{
   VertexShader,
   FragmentShader,
@@ -52,6 +54,7 @@ impl ShaderProgram {
 
       error_string += error_message_str.get(..(error_message_length - 2) as usize).unwrap();
 
+      unsafe { gl::DeleteProgram(id); }
       return Err(error_string);
     }
 
@@ -85,16 +88,6 @@ impl ShaderProgram {
     }
   }
 
-  pub fn set_uniform_uint(&self, uniform_name: String, value: &u32) -> Result<(), String> {
-    let uniform_location = self.get_uniform_location(uniform_name)?;
-    
-    unsafe {
-      gl::Uniform1uiv(uniform_location, 1, value);
-    }
-
-    Ok(())
-  }
-
   pub fn set_model_matrix(&self, model_matrix: &Matrix4) -> Result<(), String> {
     let model_matrix_location = self.get_uniform_location("model".to_string())?;
     self.set_uniform_matrix(model_matrix_location, model_matrix);
@@ -115,6 +108,16 @@ impl ShaderProgram {
 
     Ok(())
   }
+
+  pub fn set_tint(&self, tint: &Color) -> Result<(), String> {
+    let tint_location = self.get_uniform_location("tint".to_string())?;
+
+    unsafe {
+      gl::Uniform4f(tint_location, tint.r as f32 / 255.0, tint.g as f32 / 255.0, tint.b as f32 / 255.0, tint.a as f32 / 255.0);
+    }
+
+    Ok(())
+  }
 }
 
 impl Drop for ShaderProgram {
@@ -0,0 +1,64 @@
+pub struct ShelfPacker {
+  page_size: u32,
+  padding: u32,
+  cursor_x: u32,
+  shelf_y: u32,
+  shelf_height: u32
+}
+
+impl ShelfPacker {
+  pub fn new(page_size: u32, padding: u32) -> Self {
+    Self {
+      page_size,
+      padding,
+      cursor_x: 0,
+      shelf_y: 0,
+      shelf_height: 0
+    }
+  }
+
+  pub fn insert(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+    let cell_width = width + self.padding * 2;
+    let cell_height = height + self.padding * 2;
+
+    if cell_width > self.page_size { return None }
+
+    if self.cursor_x + cell_width > self.page_size {
+      self.cursor_x = 0;
+      self.shelf_y += self.shelf_height;
+      self.shelf_height = 0;
+    }
+
+    if self.shelf_y + cell_height > self.page_size { return None }
+
+    let position = (self.cursor_x + self.padding, self.shelf_y + self.padding);
+    self.cursor_x += cell_width;
+    self.shelf_height = self.shelf_height.max(cell_height);
+
+    Some(position)
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::ShelfPacker;
+
+  #[test]
+  fn fills_shelves_left_to_right() {
+    let mut packer = ShelfPacker::new(64, 1);
+
+    assert_eq!(packer.insert(20, 30), Some((1, 1)));
+    assert_eq!(packer.insert(20, 20), Some((23, 1)));
+    assert_eq!(packer.insert(20, 10), Some((1, 33)));
+  }
+
+  #[test]
+  fn reports_a_full_page() {
+    let mut packer = ShelfPacker::new(32, 0);
+
+    assert_eq!(packer.insert(32, 16), Some((0, 0)));
+    assert_eq!(packer.insert(32, 16), Some((0, 16)));
+    assert_eq!(packer.insert(8, 8), None);
+    assert_eq!(ShelfPacker::new(32, 0).insert(33, 8), None);
+  }
+}
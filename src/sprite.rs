@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::rc::Rc;
 
 use sdl2::{
   pixels::Color,
@@ -8,6 +8,9 @@ use sdl2::{
 use crate::{
   VertexArray,
   Texture,
+  TextureRegion,
+  AnimationFrame,
+  UvRect,
   Transform,
   generate_vertex_data,
   VertexBuffer,
@@ -15,39 +18,28 @@ use crate::{
 };
 
 pub struct Sprite {
-  vertex_array: VertexArray,
-  texture: Texture,
+  vertex_array: Rc<VertexArray>,
+  source: TextureRegion,
+  region: TextureRegion,
   transform: Transform
 }
 
 impl Sprite {
-  pub fn print(text: &String, font: &Font, color: &Color) -> Result<Self, String> {
-    let texture = Texture::render_text(text, font, color)?;
-    let vertex_data = generate_vertex_data(texture.width(), texture.height());
-
-    let element_data = vec![
-      0, 1, 2,
-      0, 2, 3
-    ];
-
-    let vertex_buffer = VertexBuffer::new(vertex_data);
-    let element_buffer = ElementBuffer::new(element_data);
-    let vertex_array = VertexArray::new(&vertex_buffer, &element_buffer);
-
-    let transform = Transform::new();
+  pub fn new(region: TextureRegion, vertex_array: Rc<VertexArray>) -> Self {
+    Self {
+      vertex_array,
+      source: region.clone(),
+      region,
+      transform: Transform::new()
+    }
+  }
 
-    Ok(
-      Self {
-        vertex_array,
-        texture,
-        transform
-      }
-    )
+  pub fn print(text: &String, font: &Font, color: &Color) -> Result<Self, String> {
+    Ok(Self::from_texture(Texture::render_text(text, font, color)?))
   }
 
-  pub fn load(file_path: &Path) -> Result<Self, String> {
-    let texture = Texture::load(file_path)?;
-    let vertex_data = generate_vertex_data(texture.width(), texture.height());
+  pub fn from_texture(texture: Texture) -> Self {
+    let vertex_data = generate_vertex_data(texture.width(), texture.height(), &UvRect::full());
 
     let element_data = vec![
       0, 1, 2,
@@ -58,15 +50,7 @@ impl Sprite {
     let element_buffer = ElementBuffer::new(element_data);
     let vertex_array = VertexArray::new(&vertex_buffer, &element_buffer);
 
-    let transform = Transform::new();
-
-    Ok(
-      Self {
-        vertex_array,
-        texture,
-        transform
-      }
-    )
+    Self::new(TextureRegion::full(Rc::new(texture)), Rc::new(vertex_array))
   }
 
   pub fn vertex_array(&self) -> &VertexArray {
@@ -74,7 +58,19 @@ impl Sprite {
   }
   
   pub fn texture(&self) -> &Texture {
-    &self.texture
+    self.region.texture()
+  }
+
+  pub fn region(&self) -> &TextureRegion {
+    &self.region
+  }
+
+  pub fn set_frame(&mut self, frame: &AnimationFrame) {
+    self.region = self.source.sub_region(frame.x, frame.y, frame.width, frame.height);
+  }
+
+  pub fn width(&self) -> u32 {
+    self.region.width()
   }
 
   pub fn transform(&self) -> &Transform {
@@ -84,4 +80,4 @@ impl Sprite {
   pub fn mut_transform(&mut self) -> &mut Transform {
     &mut self.transform
   }
-}
\ No newline at end of file
+}
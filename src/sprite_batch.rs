@@ -0,0 +1,85 @@
+use crate::{
+  Sprite,
+  Camera,
+  ShaderProgram,
+  Matrix4,
+  TileLayer,
+  QuadBatch,
+  VertexArray,
+  VertexBuffer,
+  ElementBuffer,
+  generate_quad_vertices,
+  generate_quad_indices
+};
+
+pub struct SpriteBatch {
+  quad_batch: QuadBatch,
+  vertex_buffer: VertexBuffer,
+  element_buffer: ElementBuffer,
+  vertex_array: VertexArray,
+  quad_capacity: usize
+}
+
+impl SpriteBatch {
+  pub fn new() -> Self {
+    let vertex_buffer = VertexBuffer::new(Vec::new());
+    let element_buffer = ElementBuffer::new(Vec::new());
+    let vertex_array = VertexArray::new(&vertex_buffer, &element_buffer);
+
+    Self {
+      quad_batch: QuadBatch::new(),
+      vertex_buffer,
+      element_buffer,
+      vertex_array,
+      quad_capacity: 0
+    }
+  }
+
+  pub fn push_sprite(&mut self, layer: TileLayer, sprite: &Sprite) {
+    let region = sprite.region();
+    let quad = generate_quad_vertices(&sprite.transform().matrix(), region.width() as f32, region.height() as f32, &region.uv_rect());
+
+    self.quad_batch.push(layer, region.texture().id(), &quad);
+  }
+
+  pub fn draw(&mut self, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
+    if self.quad_batch.is_empty() { return Ok(()) }
+
+    let runs = self.quad_batch.finish();
+    let quad_count = runs.iter().map(| run | run.quad_count()).sum();
+    let vertex_data: Vec<f32> = runs.iter().flat_map(| run | run.vertices().iter().copied()).collect();
+
+    self.vertex_buffer.upload(&vertex_data);
+
+    if quad_count > self.quad_capacity {
+      self.element_buffer.upload(&generate_quad_indices(quad_count));
+      self.quad_capacity = quad_count;
+    }
+
+    shader_program.activate();
+    shader_program.set_model_matrix(&Matrix4::identity())?;
+    shader_program.set_view_matrix(&camera.view_matrix())?;
+    shader_program.set_projection_matrix(camera.projection_matrix())?;
+
+    unsafe {
+      gl::BindVertexArray(self.vertex_array.id());
+
+      let mut first_quad = 0;
+      for run in &runs {
+        gl::BindTexture(gl::TEXTURE_2D, run.texture_id());
+        gl::DrawElements(
+          gl::TRIANGLES,
+          (run.quad_count() * 6) as gl::types::GLsizei,
+          gl::UNSIGNED_INT,
+          (first_quad * 6 * std::mem::size_of::<u32>()) as *const gl::types::GLvoid
+        );
+
+        first_quad += run.quad_count();
+      }
+
+      gl::BindVertexArray(0);
+    }
+
+    Ok(())
+  }
+}
@@ -1,6 +1,7 @@
-use std::path::Path;
-
-use crate::Sprite;
+use crate::{
+  Sprite,
+  Resources
+};
 
 pub struct Emblems {
   drakes: Sprite,
@@ -8,9 +9,9 @@ pub struct Emblems {
 }
 
 impl Emblems {
-  pub fn new() -> Result<Self, String> {
-    let mut snakes = Sprite::load(Path::new("res/textures/emblem_0.png"))?;
-    let mut drakes = Sprite::load(Path::new("res/textures/emblem_1.png"))?;
+  pub fn new(resources: &mut Resources) -> Result<Self, String> {
+    let mut snakes = resources.sprite("textures/emblem_0.png")?;
+    let mut drakes = resources.sprite("textures/emblem_1.png")?;
     
     snakes.mut_transform().translate_x(-128.0);
     drakes.mut_transform().translate_x(128.0);
@@ -1,6 +1,7 @@
-use std::path::Path;
-
-use crate::Sprite;
+use crate::{
+  Sprite,
+  Resources
+};
 
 pub struct Grass {
   zero: Sprite,
@@ -15,16 +16,16 @@ pub struct Grass {
 }
 
 impl Grass {
-  pub fn new() -> Result<Self, String> {
-    let zero = Sprite::load(Path::new("res/textures/grass.png"))?;
-    let one = Sprite::load(Path::new("res/textures/hints/grass_1.png"))?;
-    let two = Sprite::load(Path::new("res/textures/hints/grass_2.png"))?;
-    let three = Sprite::load(Path::new("res/textures/hints/grass_3.png"))?;
-    let four = Sprite::load(Path::new("res/textures/hints/grass_4.png"))?;
-    let five = Sprite::load(Path::new("res/textures/hints/grass_5.png"))?;
-    let six = Sprite::load(Path::new("res/textures/hints/grass_6.png"))?;
-    let seven = Sprite::load(Path::new("res/textures/hints/grass_7.png"))?;
-    let eight = Sprite::load(Path::new("res/textures/hints/grass_8.png"))?;
+  pub fn new(resources: &mut Resources) -> Result<Self, String> {
+    let zero = resources.sprite("textures/grass.png")?;
+    let one = resources.sprite("textures/hints/grass_1.png")?;
+    let two = resources.sprite("textures/hints/grass_2.png")?;
+    let three = resources.sprite("textures/hints/grass_3.png")?;
+    let four = resources.sprite("textures/hints/grass_4.png")?;
+    let five = resources.sprite("textures/hints/grass_5.png")?;
+    let six = resources.sprite("textures/hints/grass_6.png")?;
+    let seven = resources.sprite("textures/hints/grass_7.png")?;
+    let eight = resources.sprite("textures/hints/grass_8.png")?;
 
     Ok(
       Self {
@@ -56,4 +56,8 @@ impl MainMenu {
   pub fn quit(&self) -> &Sprite {
     &self.quit
   }
+
+  pub fn item_widths(&self) -> Vec<f32> {
+    [&self.new_game, &self.load_game, &self.high_scores, &self.settings, &self.quit].iter().map(|sprite| sprite.width() as f32).collect()
+  }
 }
\ No newline at end of file
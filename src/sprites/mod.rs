@@ -7,9 +7,6 @@ pub use emblems::Emblems;
 mod new_game;
 pub use new_game::NewGame;
 
-mod numbers;
-pub use numbers::Numbers;
-
 mod grass;
 pub use grass::Grass;
 
@@ -49,4 +49,8 @@ impl PauseMenu {
   pub fn main_menu(&self) -> &Sprite {
     &self.main_menu
   }
+
+  pub fn item_widths(&self) -> Vec<f32> {
+    [&self.resume, &self.save_game, &self.main_menu].iter().map(|sprite| sprite.width() as f32).collect()
+  }
 }
\ No newline at end of file
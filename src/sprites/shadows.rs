@@ -1,6 +1,7 @@
-use std::path::Path;
-
-use crate::Sprite;
+use crate::{
+  Sprite,
+  Resources
+};
 
 pub struct Shadows {
   zero: Sprite,
@@ -12,13 +13,13 @@ pub struct Shadows {
 }
 
 impl Shadows {
-  pub fn new() -> Result<Self, String> {
-    let zero = Sprite::load(Path::new("res/textures/shadows/shadow_0.png"))?;
-    let one = Sprite::load(Path::new("res/textures/shadows/shadow_1.png"))?;
-    let two = Sprite::load(Path::new("res/textures/shadows/shadow_2.png"))?;
-    let three = Sprite::load(Path::new("res/textures/shadows/shadow_3.png"))?;
-    let four = Sprite::load(Path::new("res/textures/shadows/shadow_4.png"))?;
-    let five = Sprite::load(Path::new("res/textures/shadows/shadow_5.png"))?;
+  pub fn new(resources: &mut Resources) -> Result<Self, String> {
+    let zero = resources.sprite("textures/shadows/shadow_0.png")?;
+    let one = resources.sprite("textures/shadows/shadow_1.png")?;
+    let two = resources.sprite("textures/shadows/shadow_2.png")?;
+    let three = resources.sprite("textures/shadows/shadow_3.png")?;
+    let four = resources.sprite("textures/shadows/shadow_4.png")?;
+    let five = resources.sprite("textures/shadows/shadow_5.png")?;
 
     Ok(
       Self {
@@ -0,0 +1,125 @@
+use std::path::{
+  Component,
+  Path
+};
+
+use crate::{
+  CommandLineOptions,
+  Settings,
+  GameSession,
+  GameParameters,
+  MapSize,
+  deserialize_map
+};
+
+pub fn start_game_session(options: &CommandLineOptions, settings: &Settings, saves_directory_path: &Path) -> Result<Option<GameSession>, String> {
+  if !options.requests_game() { return Ok(None) }
+
+  let seed = options.seed.unwrap_or_else(rand::random);
+
+  if let Some(save_name) = &options.load {
+    if save_name.is_empty() || !Path::new(save_name).components().all(| component | matches!(component, Component::Normal(_))) {
+      return Err(format!("Invalid save name \"{}\": saves must be named files inside the saves directory", save_name));
+    }
+
+    let mut save_path = saves_directory_path.join(save_name);
+    if !save_path.exists() && save_path.extension().is_none() { save_path.set_extension("txt"); }
+
+    let save_string = std::fs::read_to_string(&save_path).map_err(| error | format!("Could not load {}: {}", save_path.display(), error))?;
+    log::info!("Loading {}", save_path.display());
+    return Ok(Some(GameSession::from_map(deserialize_map(save_string)?, seed)?));
+  }
+
+  let (width, height) = options.size.unwrap_or((settings.default_width, settings.default_height));
+  let num_snakes = options.num_snakes.unwrap_or(settings.default_num_snakes);
+  let parameters = GameParameters::new(MapSize::from(width, height)?, num_snakes);
+
+  log::info!("Starting a {}x{} game with {} snakes from seed {}", width, height, num_snakes, seed);
+  Ok(Some(GameSession::new(parameters, seed)?))
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use crate::{
+    CommandLineOptions,
+    Settings,
+    Map,
+    serialize_map
+  };
+
+  use super::start_game_session;
+
+  #[test]
+  fn no_game_without_game_options() {
+    match start_game_session(&CommandLineOptions::new(), &Settings::new(), Path::new("./saves")) {
+      Ok(game_session) => assert!(game_session.is_none()),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn seeded_games_repeat() {
+    let mut options = CommandLineOptions::new();
+    options.seed = Some(42);
+    options.size = Some((16, 8));
+
+    match (start_game_session(&options, &Settings::new(), Path::new("./saves")), start_game_session(&options, &Settings::new(), Path::new("./saves"))) {
+      (Ok(Some(first)), Ok(Some(second))) => {
+        assert_eq!(first.snapshot(), second.snapshot());
+        assert_eq!(first.map().size.width(), 16);
+        assert_eq!(first.snapshot().parameters.num_snakes, 16);
+      },
+
+      _ => panic!("Expected both sessions to start")
+    }
+  }
+
+  #[test]
+  fn rejects_too_many_snakes() {
+    let mut options = CommandLineOptions::new();
+    options.size = Some((8, 8));
+    options.num_snakes = Some(500);
+
+    match start_game_session(&options, &Settings::new(), Path::new("./saves")) {
+      Ok(_) => panic!("Expected too many snakes to be rejected"),
+      Err(error) => assert_eq!(error, "Too many snakes for a 8x8 board")
+    }
+  }
+
+  #[test]
+  fn loads_saves_by_name() {
+    let saves_directory_path = Path::new("./loads_saves_by_name_test_saves");
+    let mut map = Map::new();
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+    map.is_water = vec![false; 16];
+    map.goal_location.set_array_index(15, &map.size);
+
+    if let Err(error) = std::fs::create_dir_all(saves_directory_path) { panic!("Unexpected error: {}", error) }
+    if let Err(error) = std::fs::write(saves_directory_path.join("first.txt"), serialize_map(&map)) { panic!("Unexpected error: {}", error) }
+
+    let mut options = CommandLineOptions::new();
+    options.load = Some("first".to_string());
+
+    match start_game_session(&options, &Settings::new(), saves_directory_path) {
+      Ok(Some(game_session)) => assert_eq!(game_session.map().goal_location.array_index(), 15),
+      Ok(None) => panic!("Expected a game session"),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+
+    options.load = Some("missing".to_string());
+    assert!(start_game_session(&options, &Settings::new(), saves_directory_path).is_err());
+
+    for save_name in ["../first", "/etc/passwd", "./first", ""] {
+      options.load = Some(save_name.to_string());
+      assert!(start_game_session(&options, &Settings::new(), saves_directory_path).is_err());
+    }
+
+    if let Err(error) = std::fs::remove_dir_all(saves_directory_path) { panic!("Unexpected error: {}", error) }
+  }
+}
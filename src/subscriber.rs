@@ -0,0 +1,5 @@
+use crate::Message;
+
+pub trait Subscriber {
+  fn receive(&mut self, message: &Message);
+}
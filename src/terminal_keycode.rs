@@ -0,0 +1,49 @@
+use crossterm::event::KeyCode;
+use sdl2::keyboard::Keycode;
+
+use crate::key_from_name;
+
+pub fn terminal_keycode(key_code: KeyCode) -> Option<Keycode> {
+  match key_code {
+    KeyCode::Char(' ') => Some(Keycode::Space),
+    KeyCode::Char(character) if character.is_ascii_alphanumeric() => key_from_name(&character.to_ascii_uppercase().to_string()),
+    KeyCode::Up => Some(Keycode::Up),
+    KeyCode::Down => Some(Keycode::Down),
+    KeyCode::Left => Some(Keycode::Left),
+    KeyCode::Right => Some(Keycode::Right),
+    KeyCode::Enter => Some(Keycode::Return),
+    KeyCode::Esc => Some(Keycode::Escape),
+    KeyCode::Tab => Some(Keycode::Tab),
+    KeyCode::Backspace => Some(Keycode::Backspace),
+    _ => None
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use crossterm::event::KeyCode;
+  use sdl2::keyboard::Keycode;
+
+  use super::terminal_keycode;
+
+  #[test]
+  fn maps_letters_regardless_of_case() {
+    assert_eq!(terminal_keycode(KeyCode::Char('w')), Some(Keycode::W));
+    assert_eq!(terminal_keycode(KeyCode::Char('W')), Some(Keycode::W));
+    assert_eq!(terminal_keycode(KeyCode::Char('7')), Some(Keycode::Num7));
+  }
+
+  #[test]
+  fn maps_special_keys() {
+    assert_eq!(terminal_keycode(KeyCode::Up), Some(Keycode::Up));
+    assert_eq!(terminal_keycode(KeyCode::Enter), Some(Keycode::Return));
+    assert_eq!(terminal_keycode(KeyCode::Esc), Some(Keycode::Escape));
+    assert_eq!(terminal_keycode(KeyCode::Char(' ')), Some(Keycode::Space));
+  }
+
+  #[test]
+  fn ignores_unbindable_keys() {
+    assert_eq!(terminal_keycode(KeyCode::Char('?')), None);
+    assert_eq!(terminal_keycode(KeyCode::F(1)), None);
+  }
+}
@@ -0,0 +1,96 @@
+use std::time::{
+  Duration,
+  Instant
+};
+
+use crossterm::event::{
+  KeyCode,
+  KeyEventKind
+};
+
+pub struct TerminalRepeat {
+  window: Duration,
+  last_press: Option<(KeyCode, Instant)>
+}
+
+impl TerminalRepeat {
+  pub fn new(window: Duration) -> Self {
+    Self {
+      window,
+      last_press: None
+    }
+  }
+
+  pub fn set_window(&mut self, window: Duration) {
+    self.window = window;
+  }
+
+  pub fn is_repeat(&mut self, key_code: KeyCode, kind: KeyEventKind, now: Instant) -> bool {
+    match kind {
+      KeyEventKind::Release => {
+        self.last_press = None;
+        false
+      },
+
+      KeyEventKind::Repeat => {
+        self.last_press = Some((key_code, now));
+        true
+      },
+
+      KeyEventKind::Press => {
+        let is_repeat = matches!(self.last_press, Some((last_code, last_time)) if last_code == key_code && now - last_time < self.window);
+        self.last_press = Some((key_code, now));
+        is_repeat
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use std::time::{
+    Duration,
+    Instant
+  };
+
+  use crossterm::event::{
+    KeyCode,
+    KeyEventKind
+  };
+
+  use super::TerminalRepeat;
+
+  #[test]
+  fn reports_reported_repeats() {
+    let mut terminal_repeat = TerminalRepeat::new(Duration::from_millis(150));
+    let now = Instant::now();
+
+    assert!(!terminal_repeat.is_repeat(KeyCode::Up, KeyEventKind::Press, now));
+    assert!(terminal_repeat.is_repeat(KeyCode::Up, KeyEventKind::Repeat, now + Duration::from_millis(500)));
+    assert!(!terminal_repeat.is_repeat(KeyCode::Up, KeyEventKind::Release, now + Duration::from_millis(530)));
+    assert!(!terminal_repeat.is_repeat(KeyCode::Up, KeyEventKind::Press, now + Duration::from_millis(560)));
+  }
+
+  #[test]
+  fn treats_rapid_identical_presses_as_repeats() {
+    let mut terminal_repeat = TerminalRepeat::new(Duration::from_millis(150));
+    let now = Instant::now();
+
+    assert!(!terminal_repeat.is_repeat(KeyCode::Right, KeyEventKind::Press, now));
+    assert!(terminal_repeat.is_repeat(KeyCode::Right, KeyEventKind::Press, now + Duration::from_millis(40)));
+    assert!(terminal_repeat.is_repeat(KeyCode::Right, KeyEventKind::Press, now + Duration::from_millis(80)));
+    assert!(!terminal_repeat.is_repeat(KeyCode::Down, KeyEventKind::Press, now + Duration::from_millis(120)));
+    assert!(!terminal_repeat.is_repeat(KeyCode::Down, KeyEventKind::Press, now + Duration::from_millis(400)));
+  }
+  #[test]
+  fn uses_the_configured_window() {
+    let mut terminal_repeat = TerminalRepeat::new(Duration::from_millis(300));
+    let now = Instant::now();
+
+    assert!(!terminal_repeat.is_repeat(KeyCode::Left, KeyEventKind::Press, now));
+    assert!(terminal_repeat.is_repeat(KeyCode::Left, KeyEventKind::Press, now + Duration::from_millis(250)));
+
+    terminal_repeat.set_window(Duration::from_millis(100));
+    assert!(!terminal_repeat.is_repeat(KeyCode::Left, KeyEventKind::Press, now + Duration::from_millis(400)));
+  }
+}
@@ -0,0 +1,73 @@
+use std::io::{
+  Stdout,
+  Write,
+  stdout
+};
+
+use crossterm::{
+  cursor,
+  event::{
+    KeyboardEnhancementFlags,
+    PushKeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags
+  },
+  terminal,
+  style::Print,
+  QueueableCommand,
+  ExecutableCommand
+};
+
+pub struct TerminalScreen {
+  stdout: Stdout,
+  displayed_text: String,
+  reports_key_events: bool
+}
+
+impl TerminalScreen {
+  pub fn new() -> Result<Self, String> {
+    terminal::enable_raw_mode().map_err(| error | error.to_string())?;
+
+    let mut stdout = stdout();
+    stdout.execute(terminal::EnterAlternateScreen).map_err(| error | error.to_string())?;
+    stdout.execute(cursor::Hide).map_err(| error | error.to_string())?;
+
+    let reports_key_events = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if reports_key_events {
+      stdout.execute(PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)).map_err(| error | error.to_string())?;
+    }
+
+    Ok(
+      Self {
+        stdout,
+        displayed_text: String::new(),
+        reports_key_events
+      }
+    )
+  }
+
+  pub fn draw(&mut self, text: &str) -> Result<(), String> {
+    if text == self.displayed_text { return Ok(()) }
+
+    self.stdout.queue(cursor::MoveTo(0, 0)).map_err(| error | error.to_string())?;
+    self.stdout.queue(terminal::Clear(terminal::ClearType::All)).map_err(| error | error.to_string())?;
+    self.stdout.queue(Print(text.replace('\n', "\r\n"))).map_err(| error | error.to_string())?;
+    self.stdout.flush().map_err(| error | error.to_string())?;
+
+    self.displayed_text = text.to_string();
+
+    Ok(())
+  }
+
+  pub fn invalidate(&mut self) {
+    self.displayed_text.clear();
+  }
+}
+
+impl Drop for TerminalScreen {
+  fn drop(&mut self) {
+    if self.reports_key_events { let _ = self.stdout.execute(PopKeyboardEnhancementFlags); }
+    let _ = self.stdout.execute(cursor::Show);
+    let _ = self.stdout.execute(terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+  }
+}
@@ -0,0 +1,165 @@
+pub struct TextField {
+  text: String,
+  cursor: usize,
+  composition: String,
+  max_length: usize,
+  forbidden_characters: &'static str
+}
+
+impl TextField {
+  pub fn new(max_length: usize, forbidden_characters: &'static str) -> Self {
+    Self {
+      text: String::new(),
+      cursor: 0,
+      composition: String::new(),
+      max_length,
+      forbidden_characters
+    }
+  }
+
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  pub fn display_text(&self) -> String {
+    let split = self.byte_offset(self.cursor);
+    format!("{}{}|{}", &self.text[..split], self.composition, &self.text[split..])
+  }
+
+  pub fn insert(&mut self, input: &str) {
+    self.composition.clear();
+
+    for character in input.chars() {
+      if character.is_control() || self.forbidden_characters.contains(character) { continue; }
+      if self.text.chars().count() >= self.max_length { break; }
+
+      let offset = self.byte_offset(self.cursor);
+      self.text.insert(offset, character);
+      self.cursor += 1;
+    }
+  }
+
+  pub fn set_composition(&mut self, composition: &str) {
+    self.composition = composition.to_string();
+  }
+
+  pub fn backspace(&mut self) {
+    if self.cursor == 0 { return }
+
+    self.cursor -= 1;
+    let offset = self.byte_offset(self.cursor);
+    self.text.remove(offset);
+  }
+
+  pub fn delete(&mut self) {
+    if self.cursor >= self.text.chars().count() { return }
+
+    let offset = self.byte_offset(self.cursor);
+    self.text.remove(offset);
+  }
+
+  pub fn move_left(&mut self) {
+    if self.cursor > 0 { self.cursor -= 1 }
+  }
+
+  pub fn move_right(&mut self) {
+    if self.cursor < self.text.chars().count() { self.cursor += 1 }
+  }
+
+  pub fn move_home(&mut self) {
+    self.cursor = 0;
+  }
+
+  pub fn move_end(&mut self) {
+    self.cursor = self.text.chars().count();
+  }
+
+  pub fn clear(&mut self) {
+    self.text.clear();
+    self.composition.clear();
+    self.cursor = 0;
+  }
+
+  fn byte_offset(&self, character_index: usize) -> usize {
+    self.text
+      .char_indices()
+      .nth(character_index)
+      .map(| (offset, _) | offset)
+      .unwrap_or(self.text.len())
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::TextField;
+
+  #[test]
+  fn accepts_unicode_digits_and_spaces() {
+    let mut text_field = TextField::new(32, "");
+    text_field.insert("Drake 42 ");
+    text_field.insert("ドラゴン");
+
+    assert_eq!(text_field.text(), "Drake 42 ドラゴン");
+    assert_eq!(text_field.display_text(), "Drake 42 ドラゴン|");
+  }
+
+  #[test]
+  fn edits_at_the_cursor() {
+    let mut text_field = TextField::new(32, "");
+    text_field.insert("héllo");
+    text_field.move_left();
+    text_field.move_left();
+    text_field.backspace();
+    text_field.insert("L");
+
+    assert_eq!(text_field.text(), "héLlo");
+    assert_eq!(text_field.display_text(), "héL|lo");
+
+    text_field.move_home();
+    text_field.delete();
+    text_field.move_end();
+    text_field.move_right();
+
+    assert_eq!(text_field.display_text(), "éLlo|");
+  }
+
+  #[test]
+  fn enforces_the_length_limit() {
+    let mut text_field = TextField::new(4, "");
+    text_field.insert("abcdef");
+
+    assert_eq!(text_field.text(), "abcd");
+  }
+
+  #[test]
+  fn drops_forbidden_and_control_characters() {
+    let mut text_field = TextField::new(16, ",/");
+    text_field.insert("a,b/c\td\n");
+
+    assert_eq!(text_field.text(), "abcd");
+  }
+
+  #[test]
+  fn shows_composition_until_committed() {
+    let mut text_field = TextField::new(16, "");
+    text_field.insert("ab");
+    text_field.move_left();
+    text_field.set_composition("か");
+
+    assert_eq!(text_field.display_text(), "aか|b");
+
+    text_field.insert("火");
+
+    assert_eq!(text_field.display_text(), "a火|b");
+  }
+
+  #[test]
+  fn clears_everything() {
+    let mut text_field = TextField::new(16, "");
+    text_field.insert("name");
+    text_field.set_composition("x");
+    text_field.clear();
+
+    assert_eq!(text_field.display_text(), "|");
+  }
+}
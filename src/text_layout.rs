@@ -0,0 +1,8 @@
+use crate::PlacedGlyph;
+
+#[derive(PartialEq, Debug)]
+pub struct TextLayout {
+  pub glyphs: Vec<PlacedGlyph>,
+  pub width: f32,
+  pub height: f32
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use image::RgbaImage;
+use sdl2::{
+  pixels::{
+    Color,
+    PixelFormatEnum
+  },
+  ttf::Font
+};
+
+use crate::{
+  Texture,
+  Glyph,
+  AtlasPlacement,
+  ShelfPacker,
+  TextStyle,
+  TextLayout,
+  Camera,
+  ShaderProgram,
+  Matrix4,
+  Vector2,
+  VertexArray,
+  VertexBuffer,
+  ElementBuffer,
+  layout_text,
+  generate_quad_vertices,
+  generate_quad_indices
+};
+
+const GLYPH_PAGE_SIZE: u32 = 512;
+const GLYPH_PADDING: u32 = 1;
+const FALLBACK_CHARACTER: char = '?';
+
+struct TextRun {
+  page: usize,
+  color: Color,
+  vertices: Vec<f32>
+}
+
+pub struct TextRenderer {
+  pages: Vec<Texture>,
+  packer: ShelfPacker,
+  glyphs: HashMap<char, Glyph>,
+  line_height: f32,
+  runs: Vec<TextRun>,
+  vertex_buffer: VertexBuffer,
+  element_buffer: ElementBuffer,
+  vertex_array: VertexArray,
+  quad_capacity: usize
+}
+
+impl TextRenderer {
+  pub fn new(font: &Font) -> Self {
+    let vertex_buffer = VertexBuffer::new(Vec::new());
+    let element_buffer = ElementBuffer::new(Vec::new());
+    let vertex_array = VertexArray::new(&vertex_buffer, &element_buffer);
+
+    Self {
+      pages: Vec::new(),
+      packer: ShelfPacker::new(GLYPH_PAGE_SIZE, GLYPH_PADDING),
+      glyphs: HashMap::new(),
+      line_height: font.height() as f32,
+      runs: Vec::new(),
+      vertex_buffer,
+      element_buffer,
+      vertex_array,
+      quad_capacity: 0
+    }
+  }
+
+  pub fn measure(&mut self, font: &Font, text: &str, style: &TextStyle) -> Result<TextLayout, String> {
+    for character in text.chars() {
+      self.cache_glyph(font, character)?;
+    }
+
+    let scale = style.size / self.line_height;
+    let glyphs = &self.glyphs;
+    let advance = | character: char | glyphs.get(&character).map_or(0.0, | glyph | glyph.advance as f32 * scale);
+
+    Ok(layout_text(text, &advance, self.line_height * scale, style.alignment, style.wrap_width))
+  }
+
+  pub fn queue(&mut self, font: &Font, text: &str, position: Vector2, style: &TextStyle) -> Result<(), String> {
+    let layout = self.measure(font, text, style)?;
+    let scale = style.size / self.line_height;
+
+    for placed_glyph in layout.glyphs {
+      let glyph = self.glyphs[&placed_glyph.character];
+      if glyph.placement.width == 0 { continue }
+
+      let mut model_matrix = Matrix4::identity();
+      model_matrix.x.w = position.x + placed_glyph.x + glyph.advance as f32 * scale / 2.0;
+      model_matrix.y.w = position.y + placed_glyph.y;
+
+      let uv_rect = glyph.placement.uv_rect(GLYPH_PAGE_SIZE, GLYPH_PAGE_SIZE);
+      let quad = generate_quad_vertices(&model_matrix, glyph.placement.width as f32 * scale, glyph.placement.height as f32 * scale, &uv_rect);
+
+      match self.runs.last_mut() {
+        Some(run) if run.page == glyph.placement.page && run.color == style.color => run.vertices.extend_from_slice(&quad),
+        _ => self.runs.push(TextRun { page: glyph.placement.page, color: style.color, vertices: quad.to_vec() })
+      }
+    }
+
+    Ok(())
+  }
+
+  pub fn draw(&mut self, camera: &Camera, shader_program: &ShaderProgram) -> Result<(), String> {
+    if self.runs.is_empty() { return Ok(()) }
+
+    let runs = std::mem::take(&mut self.runs);
+    let quad_count = runs.iter().map(| run | run.vertices.len() / 16).sum();
+    let vertex_data: Vec<f32> = runs.iter().flat_map(| run | run.vertices.iter().copied()).collect();
+
+    self.vertex_buffer.upload(&vertex_data);
+
+    if quad_count > self.quad_capacity {
+      self.element_buffer.upload(&generate_quad_indices(quad_count));
+      self.quad_capacity = quad_count;
+    }
+
+    shader_program.activate();
+    shader_program.set_model_matrix(&Matrix4::identity())?;
+    shader_program.set_view_matrix(&camera.view_matrix())?;
+    shader_program.set_projection_matrix(camera.projection_matrix())?;
+
+    let mut first_quad = 0;
+    for run in &runs {
+      let run_quad_count = run.vertices.len() / 16;
+      shader_program.set_tint(&run.color)?;
+
+      unsafe {
+        gl::BindVertexArray(self.vertex_array.id());
+        gl::BindTexture(gl::TEXTURE_2D, self.pages[run.page].id());
+        gl::DrawElements(
+          gl::TRIANGLES,
+          (run_quad_count * 6) as gl::types::GLsizei,
+          gl::UNSIGNED_INT,
+          (first_quad * 6 * std::mem::size_of::<u32>()) as *const gl::types::GLvoid
+        );
+        gl::BindVertexArray(0);
+      }
+
+      first_quad += run_quad_count;
+    }
+
+    Ok(())
+  }
+
+  fn cache_glyph(&mut self, font: &Font, character: char) -> Result<(), String> {
+    if self.glyphs.contains_key(&character) { return Ok(()) }
+
+    if character != FALLBACK_CHARACTER && font.find_glyph(character).is_none() {
+      self.cache_glyph(font, FALLBACK_CHARACTER)?;
+      self.glyphs.insert(character, self.glyphs[&FALLBACK_CHARACTER]);
+      return Ok(());
+    }
+
+    let image = rasterize_glyph(font, character)?;
+    let advance = image.width();
+
+    let placement = if character.is_whitespace() {
+      AtlasPlacement { page: 0, x: 0, y: 0, width: 0, height: 0 }
+    } else {
+      self.place(&image)?
+    };
+
+    self.glyphs.insert(character, Glyph { placement, advance });
+
+    Ok(())
+  }
+
+  fn place(&mut self, image: &RgbaImage) -> Result<AtlasPlacement, String> {
+    if self.pages.is_empty() { self.add_page() }
+
+    let position = match self.packer.insert(image.width(), image.height()) {
+      Some(position) => position,
+
+      None => {
+        self.add_page();
+
+        match self.packer.insert(image.width(), image.height()) {
+          Some(position) => position,
+          None => return Err(format!("A {}x{} glyph does not fit a {}x{} glyph page", image.width(), image.height(), GLYPH_PAGE_SIZE, GLYPH_PAGE_SIZE))
+        }
+      }
+    };
+
+    let page = self.pages.len() - 1;
+    self.pages[page].update_region(position.0, position.1, image);
+
+    Ok(AtlasPlacement { page, x: position.0, y: position.1, width: image.width(), height: image.height() })
+  }
+
+  fn add_page(&mut self) {
+    self.pages.push(Texture::from_rgba(&RgbaImage::new(GLYPH_PAGE_SIZE, GLYPH_PAGE_SIZE)));
+    self.packer = ShelfPacker::new(GLYPH_PAGE_SIZE, GLYPH_PADDING);
+  }
+}
+
+fn rasterize_glyph(font: &Font, character: char) -> Result<RgbaImage, String> {
+  let surface = font
+    .render_char(character)
+    .blended(Color::WHITE)
+    .map_err(| error | error.to_string())?
+    .convert_format(PixelFormatEnum::ABGR8888)?;
+
+  let width = surface.width();
+  let height = surface.height();
+  let pitch = surface.pitch() as usize;
+
+  let pixels = surface.with_lock(| data | {
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for row in 0..height as usize {
+      pixels.extend_from_slice(&data[row * pitch..row * pitch + width as usize * 4]);
+    }
+
+    pixels
+  });
+
+  RgbaImage::from_raw(width, height, pixels).ok_or_else(|| format!("Could not rasterize glyph {:?}", character))
+}
@@ -0,0 +1,30 @@
+use sdl2::pixels::Color;
+
+use crate::TextAlignment;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextStyle {
+  pub color: Color,
+  pub size: f32,
+  pub alignment: TextAlignment,
+  pub wrap_width: Option<f32>
+}
+
+impl TextStyle {
+  pub fn new(color: Color, size: f32) -> Self {
+    Self {
+      color,
+      size,
+      alignment: TextAlignment::Center,
+      wrap_width: None
+    }
+  }
+
+  pub fn aligned(self, alignment: TextAlignment) -> Self {
+    Self { alignment, ..self }
+  }
+
+  pub fn wrapped(self, wrap_width: f32) -> Self {
+    Self { wrap_width: Some(wrap_width), ..self }
+  }
+}
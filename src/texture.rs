@@ -1,9 +1,18 @@
+use image::RgbaImage;
+
 use sdl2::{
   pixels::Color,
   ttf::Font
 };
 
-use crate::generate_texture;
+use crate::{
+  AtlasPlacement,
+  generate_texture,
+  upload_texture,
+  upload_texture_region,
+  disable_mipmaps,
+  extrude_image
+};
 
 pub struct Texture {
   id: gl::types::GLuint,
@@ -12,8 +21,8 @@ pub struct Texture {
 }
 
 impl Texture {
-  pub fn load(file_path: &std::path::Path) -> Result<Self, String> {
-    let mut texture_image = image::open(file_path).map_err(| error | error.to_string())?;
+  pub fn decode(image_bytes: &[u8]) -> Result<Self, String> {
+    let mut texture_image = image::load_from_memory(image_bytes).map_err(| error | error.to_string())?;
     texture_image = texture_image.flipv();
     let width = texture_image.width();
     let height = texture_image.height();
@@ -29,6 +38,63 @@ impl Texture {
     )
   }
 
+  pub fn from_rgba(image: &RgbaImage) -> Self {
+    let flipped_image = image::imageops::flip_vertical(image);
+    let width = flipped_image.width();
+    let height = flipped_image.height();
+    let id = generate_texture(width as gl::types::GLint, height as gl::types::GLint, flipped_image.as_raw());
+
+    Self {
+      id,
+      width,
+      height
+    }
+  }
+
+  pub fn atlas_page(image: &RgbaImage) -> Self {
+    let texture = Self::from_rgba(image);
+    disable_mipmaps(texture.id);
+
+    texture
+  }
+
+  pub fn reload_region(&self, image_bytes: &[u8], placement: &AtlasPlacement, padding: u32) -> Result<(), String> {
+    let texture_image = image::load_from_memory(image_bytes).map_err(| error | error.to_string())?.to_rgba8();
+
+    if texture_image.width() != placement.width || texture_image.height() != placement.height {
+      return Err(format!("Texture changed size from {}x{} to {}x{}, restart to repack the atlas", placement.width, placement.height, texture_image.width(), texture_image.height()));
+    }
+
+    self.update_region(placement.x - padding, placement.y - padding, &extrude_image(&texture_image, padding));
+
+    Ok(())
+  }
+
+  pub fn update_region(&self, x: u32, y: u32, image: &RgbaImage) {
+    let flipped_image = image::imageops::flip_vertical(image);
+
+    upload_texture_region(
+      self.id,
+      x as gl::types::GLint,
+      (self.height - y - image.height()) as gl::types::GLint,
+      image.width() as gl::types::GLint,
+      image.height() as gl::types::GLint,
+      flipped_image.as_raw()
+    );
+  }
+
+  pub fn reload(&self, image_bytes: &[u8]) -> Result<(), String> {
+    let texture_image = image::load_from_memory(image_bytes).map_err(| error | error.to_string())?.flipv();
+
+    if texture_image.width() != self.width || texture_image.height() != self.height {
+      return Err(format!("Texture changed size from {}x{} to {}x{}, restart to pick it up", self.width, self.height, texture_image.width(), texture_image.height()));
+    }
+
+    upload_texture(self.id, self.width as gl::types::GLint, self.height as gl::types::GLint, texture_image.as_bytes());
+
+    Ok(())
+  }
+
   pub fn render_text(text: &String, font: &Font, color: &Color) -> Result<Self, String> {
     let mut image_surface = font
       .render(&text)
@@ -0,0 +1,54 @@
+use std::rc::Rc;
+
+use crate::{
+  Texture,
+  UvRect
+};
+
+#[derive(Clone)]
+pub struct TextureRegion {
+  texture: Rc<Texture>,
+  uv_rect: UvRect,
+  width: u32,
+  height: u32
+}
+
+impl TextureRegion {
+  pub fn new(texture: Rc<Texture>, uv_rect: UvRect, width: u32, height: u32) -> Self {
+    Self {
+      texture,
+      uv_rect,
+      width,
+      height
+    }
+  }
+
+  pub fn full(texture: Rc<Texture>) -> Self {
+    let width = texture.width();
+    let height = texture.height();
+
+    Self::new(texture, UvRect::full(), width, height)
+  }
+
+  pub fn sub_region(&self, x: u32, y: u32, width: u32, height: u32) -> Self {
+    let uv_rect = self.uv_rect.image_sub_rect(x, y, width, height, self.width, self.height);
+
+    Self::new(Rc::clone(&self.texture), uv_rect, width, height)
+  }
+
+  pub fn texture(&self) -> &Texture {
+    &self.texture
+  }
+
+  pub fn uv_rect(&self) -> UvRect {
+    self.uv_rect
+  }
+
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+}
@@ -0,0 +1,13 @@
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum TileLayer {
+  Grass,
+  Hint,
+  Snake,
+  Path,
+  Water,
+  Drake,
+  Shadow,
+  Mark,
+  Goal,
+  Highlight
+}
@@ -0,0 +1,37 @@
+use crate::{
+  Coordinate,
+  MapSize,
+  Transform,
+  Vector2
+};
+
+pub fn tile_location(coordinate: &Coordinate, map_size: &MapSize, tile_width: usize, tile_height: usize) -> Vector2 {
+  let mut tile_transform = Transform::new();
+  tile_transform.translate_x_to(coordinate.x() as f32 * tile_width as f32);
+  tile_transform.translate_x(-(map_size.width() as f32 * tile_width as f32 / 2.0));
+  tile_transform.translate_y_to(coordinate.y() as f32 * tile_height as f32);
+  tile_transform.translate_y(-(map_size.height() as f32 * tile_height as f32 / 2.0));
+
+  tile_transform.location
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Coordinate,
+    MapSize
+  };
+
+  use super::tile_location;
+
+  #[test]
+  fn centers_the_board_on_the_origin() {
+    let map_size = MapSize::from(8, 8).unwrap();
+
+    let first = tile_location(&Coordinate::from(0, 0, &map_size), &map_size, 32, 32);
+    assert_eq!((first.x, first.y), (-128.0, -128.0));
+
+    let last = tile_location(&Coordinate::from(7, 5, &map_size), &map_size, 32, 32);
+    assert_eq!((last.x, last.y), (96.0, 32.0));
+  }
+}
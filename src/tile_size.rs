@@ -0,0 +1 @@
+pub const TILE_SIZE: usize = 32;
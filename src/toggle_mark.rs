@@ -0,0 +1,55 @@
+use crate::{
+  Map,
+  GameEvent
+};
+
+pub fn toggle_mark(map: &mut Map, index: usize) -> Result<GameEvent, String> {
+  if index >= map.size.array_length() || map.is_marked.len() != map.size.array_length() { return Err("Invalid tile".to_string()) }
+  if map.is_explored[index] { return Err("Explored tiles cannot be marked".to_string()) }
+
+  map.is_marked[index] = !map.is_marked[index];
+
+  Ok(GameEvent::MarkToggled(index, map.is_marked[index]))
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Map,
+    GameEvent
+  };
+
+  use super::toggle_mark;
+
+  fn test_map() -> Map {
+    let mut map = Map::new();
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_explored[0] = true;
+
+    map
+  }
+
+  #[test]
+  fn toggles_unexplored_tiles() {
+    let mut map = test_map();
+
+    assert_eq!(toggle_mark(&mut map, 10), Ok(GameEvent::MarkToggled(10, true)));
+    assert_eq!(toggle_mark(&mut map, 10), Ok(GameEvent::MarkToggled(10, false)));
+  }
+
+  #[test]
+  fn refuses_explored_tiles() {
+    let mut map = test_map();
+
+    assert_eq!(toggle_mark(&mut map, 0), Err("Explored tiles cannot be marked".to_string()));
+    assert_eq!(map.is_marked[0], false);
+  }
+
+  #[test]
+  fn refuses_tiles_outside_the_map() {
+    let mut map = test_map();
+
+    assert_eq!(toggle_mark(&mut map, 16), Err("Invalid tile".to_string()));
+  }
+}
@@ -1,7 +0,0 @@
-#[derive(PartialEq)]
-pub enum TypingStatus {
-  Typing,
-  NotTyping,
-  TypingStarted,
-  TypingEnded
-}
\ No newline at end of file
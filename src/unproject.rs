@@ -0,0 +1,71 @@
+use crate::{
+  Matrix4,
+  Vector2,
+  Vector4,
+  invert_matrix,
+  multiply_matrices,
+  multiply_matrix_vector
+};
+
+pub fn unproject(screen_x: f32, screen_y: f32, viewport_width: f32, viewport_height: f32, view_matrix: &Matrix4, projection_matrix: &Matrix4) -> Option<Vector2> {
+  let inverse = invert_matrix(&multiply_matrices(projection_matrix, view_matrix))?;
+
+  let normalized = Vector4::new(
+    screen_x / viewport_width * 2.0 - 1.0,
+    1.0 - screen_y / viewport_height * 2.0,
+    0.0,
+    1.0
+  );
+
+  let world = multiply_matrix_vector(&inverse, &normalized);
+  if world.w.abs() < f32::EPSILON { return None }
+
+  Some(
+    Vector2 {
+      x: world.x / world.w,
+      y: world.y / world.w
+    }
+  )
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    Matrix4,
+    calculate_projection_matrix
+  };
+
+  use super::unproject;
+
+  #[test]
+  fn screen_center_is_camera_location() {
+    let projection = calculate_projection_matrix(-800.0, 800.0, 450.0, -450.0, 1.0, -1.0);
+    let mut view = Matrix4::identity();
+    view.x.w = -64.0;
+    view.y.w = 32.0;
+
+    match unproject(800.0, 450.0, 1600.0, 900.0, &view, &projection) {
+      Some(world) => {
+        assert!((world.x - 64.0).abs() < 0.01);
+        assert!((world.y + 32.0).abs() < 0.01);
+      },
+
+      None => panic!("Expected a world location")
+    }
+  }
+
+  #[test]
+  fn screen_corners_map_to_projection_bounds() {
+    let projection = calculate_projection_matrix(-800.0, 800.0, 450.0, -450.0, 1.0, -1.0);
+    let view = Matrix4::identity();
+
+    match unproject(0.0, 0.0, 1600.0, 900.0, &view, &projection) {
+      Some(world) => {
+        assert!((world.x + 800.0).abs() < 0.01);
+        assert!((world.y + 450.0).abs() < 0.01);
+      },
+
+      None => panic!("Expected a world location")
+    }
+  }
+}
@@ -0,0 +1,106 @@
+use std::path::Path;
+
+use crate::{
+  MessageQueue,
+  Message,
+  Input,
+  Scenes,
+  PlayfieldState,
+  TextField,
+  HighScoresListing,
+  save_high_score
+};
+
+pub fn update_add_high_score(message_queue: &mut MessageQueue, playfield_state: &PlayfieldState, text_field: &mut TextField, high_scores_file_path: &Path) -> Result<(), String> {
+  let mut confirmed = false;
+  let mut cancelled = false;
+
+  for message in message_queue.messages() {
+    match message {
+      Message::PlayerInput(Input::Confirm) => confirmed = true,
+      Message::PlayerInput(Input::Cancel) => cancelled = true,
+      _ => {}
+    }
+  }
+
+  if confirmed && !text_field.text().trim().is_empty() {
+    let new_score = HighScoresListing::from(text_field.text().trim().to_string(), playfield_state.map.score.current());
+    save_high_score(high_scores_file_path, &new_score)?;
+
+    text_field.clear();
+    message_queue.post(Message::RequestScene(Scenes::MainMenu));
+  } else if cancelled {
+    text_field.clear();
+    message_queue.post(Message::RequestScene(Scenes::MainMenu));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use crate::{
+    MessageQueue,
+    Message,
+    Input,
+    Scenes,
+    PlayfieldState,
+    TextField
+  };
+
+  use super::update_add_high_score;
+
+  #[test]
+  fn saves_the_typed_name() {
+    let high_scores_file_path = Path::new("./saves_the_typed_name_test_high_scores.txt");
+    let mut message_queue = MessageQueue::new();
+    let playfield_state = PlayfieldState::new();
+    let mut text_field = TextField::new(16, ",");
+    text_field.insert("Zoë 2");
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_add_high_score(&mut message_queue, &playfield_state, &mut text_field, high_scores_file_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![Message::RequestScene(Scenes::MainMenu)]);
+
+        match std::fs::read_to_string(high_scores_file_path) {
+          Ok(high_scores_string) => assert_eq!(high_scores_string, "Zoë 2,0,"),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        if let Err(error) = std::fs::remove_file(high_scores_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn waits_for_a_name() {
+    let high_scores_file_path = Path::new("./waits_for_a_name_test_high_scores.txt");
+    let mut message_queue = MessageQueue::new();
+    let playfield_state = PlayfieldState::new();
+    let mut text_field = TextField::new(16, ",");
+    text_field.insert("   ");
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_add_high_score(&mut message_queue, &playfield_state, &mut text_field, high_scores_file_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages().len(), 0);
+        assert!(!high_scores_file_path.exists());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
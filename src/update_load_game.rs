@@ -3,15 +3,17 @@ use std::path::Path;
 use crate::{
   MessageQueue,
   LoadGameState,
-  PlayfieldState,
+  GameSession,
   load_saves_list,
   Message,
   Input,
   Scenes,
-  deserialize_map
+  PointerButton,
+  deserialize_map,
+  menu_item_at
 };
 
-pub fn update_load_game(message_queue: &mut MessageQueue, load_game_state: &mut LoadGameState, playfield_state: &mut PlayfieldState, saves_directory_path: &Path) -> Result<(), String> {
+pub fn update_load_game(message_queue: &mut MessageQueue, load_game_state: &mut LoadGameState, game_session: &mut GameSession, saves_directory_path: &Path) -> Result<(), String> {
   if !load_game_state.saves_list_loaded {
     load_game_state.saves = load_saves_list(saves_directory_path)?;
     load_game_state.saves_list_loaded = true;
@@ -21,14 +23,25 @@ pub fn update_load_game(message_queue: &mut MessageQueue, load_game_state: &mut
   let mut confirmed = false;
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = *message { match input {
-      Input::Up => if load_game_state.selected_menu_item_index > 0 { load_game_state.selected_menu_item_index -= 1 },
-      Input::Down => if load_game_state.selected_menu_item_index < load_game_state.saves.len() - 1 { load_game_state.selected_menu_item_index += 1 },
-      Input::Cancel => cancelled = true,
-      Input::Confirm => confirmed = true,
-      Input::Action => confirmed = true,
+    match *message {
+      Message::PointerMoved(x, y) => if let Some(index) = menu_item_at(x, y, 64, &load_game_state.item_widths) { load_game_state.selected_menu_item_index = index },
+
+      Message::PointerPressed(x, y, PointerButton::Left) => if let Some(index) = menu_item_at(x, y, 64, &load_game_state.item_widths) {
+        load_game_state.selected_menu_item_index = index;
+        confirmed = true;
+      },
+
+      Message::PlayerInput(input) => match input {
+        Input::Up => if load_game_state.selected_menu_item_index > 0 { load_game_state.selected_menu_item_index -= 1 },
+        Input::Down => if load_game_state.selected_menu_item_index < load_game_state.saves.len() - 1 { load_game_state.selected_menu_item_index += 1 },
+        Input::Cancel => cancelled = true,
+        Input::Confirm => confirmed = true,
+        Input::Action => confirmed = true,
+        _ => {}
+      },
+
       _ => {}
-    }}
+    }
   }
 
   if cancelled { message_queue.post(Message::RequestScene(Scenes::MainMenu)) }
@@ -37,7 +50,7 @@ pub fn update_load_game(message_queue: &mut MessageQueue, load_game_state: &mut
     let save_path = saves_directory_path.join(load_game_state.saves[load_game_state.selected_menu_item_index].clone());
     
     let save_string = std::fs::read_to_string(save_path).map_err(| error | error.to_string())?;
-    playfield_state.map = deserialize_map(save_string)?;
+    *game_session = GameSession::from_map(deserialize_map(save_string)?, game_session.next_seed())?;
     message_queue.post(Message::RequestScene(Scenes::Playfield));
   }
 
@@ -51,7 +64,7 @@ mod testing {
 use crate::{
     MessageQueue,
     LoadGameState,
-    PlayfieldState,
+    GameSession,
     Message,
     Input,
     Scenes
@@ -63,7 +76,7 @@ use crate::{
   fn loading_saves_list() {
     let mut message_queue = MessageQueue::new();
     let mut load_game_state = LoadGameState::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut game_session = GameSession::empty(0);
     let saves_directory_path = Path::new("./loading_saves_list_test_saves");
     let test_save_path = Path::new("./loading_saves_list_test_saves/test_save.txt");
 
@@ -76,7 +89,7 @@ use crate::{
             Ok(_) => {
               match std::fs::write(test_save_path, "8,8,1,0,4,4,0,73,0,0,2,3,4,4,2,1,0,0,3,5,7,5,2,1,1,1,3,3,5,4,3,1,2,1,2,2,3,2,1,0,1,3,2,1,0,1,1,1,1,3,1,3,2,3,1,1,0,2,1,3,1,2,2,1,0,1,1,2,2,2,1,0,0,0,0,1,1,0,0,0,0,0,0,1,1,1,1,0,0,0,0,1,1,1,0,0,0,1,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,1,0,0,0,1,0,0,0,1,0,1,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,1,1,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,") {
                 Ok(_) => {
-                  match update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, saves_directory_path) {
+                  match update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, saves_directory_path) {
                     Ok(_) => {
                       assert!(load_game_state.saves_list_loaded);
                       assert_eq!(load_game_state.saves.len(), 1);
@@ -119,13 +132,13 @@ use crate::{
   fn close_loading_scene() {
     let mut message_queue = MessageQueue::new();
     let mut load_game_state = LoadGameState::new();
-    let mut playfield_state = PlayfieldState::new();
+    let mut game_session = GameSession::empty(0);
     let saves_directory_path = Path::new("./close_loading_scene_test_saves");
 
     message_queue.post(Message::PlayerInput(Input::Cancel));
     message_queue.swap_buffers();
 
-    match update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, saves_directory_path) {
+    match update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, saves_directory_path) {
       Ok(_) => {
         message_queue.swap_buffers();
         assert_eq!(message_queue.messages().len(), 1);
@@ -170,9 +183,9 @@ use crate::{
                     Ok(_) => {
                       let mut message_queue = MessageQueue::new();
                       let mut load_game_state = LoadGameState::new();
-                      let mut playfield_state = PlayfieldState::new();
+                      let mut game_session = GameSession::empty(0);
 
-                      match update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, saves_directory_path) {
+                      match update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, saves_directory_path) {
                         Ok(_) => {
                           assert!(load_game_state.saves_list_loaded);
                           assert_eq!(load_game_state.saves.len(), 2);
@@ -184,7 +197,7 @@ use crate::{
 
                           assert_eq!(message_queue.messages().len(), 1);
 
-                          match update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, saves_directory_path) {
+                          match update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, saves_directory_path) {
                             Ok(_) => {
                               assert_eq!(load_game_state.selected_menu_item_index, 1);
 
@@ -192,11 +205,11 @@ use crate::{
                               message_queue.post(Message::PlayerInput(Input::Confirm));
                               message_queue.swap_buffers();
 
-                              assert_eq!(playfield_state.map.size.width(), 4);
+                              assert_eq!(game_session.map().size.width(), 4);
 
-                              match update_load_game(&mut message_queue, &mut load_game_state, &mut playfield_state, saves_directory_path) {
+                              match update_load_game(&mut message_queue, &mut load_game_state, &mut game_session, saves_directory_path) {
                                 Ok(_) => {
-                                  assert_eq!(playfield_state.map.size.width(), 8);
+                                  assert_eq!(game_session.map().size.width(), 8);
 
                                   message_queue.swap_buffers();
                                   assert_eq!(message_queue.messages().len(), 1);
@@ -1,17 +1,19 @@
 use crate::{
   MessageQueue,
-  PlayfieldState,
+  GameSession,
   handle_playfield_input,
-  validate_map,
   MapValidation,
   Message,
   Scenes
 };
 
-pub fn update_playfield(message_queue: &mut MessageQueue, playfield_state: &mut PlayfieldState) -> Result<(), String> {
-  handle_playfield_input(message_queue, playfield_state)?;
+pub fn update_playfield(message_queue: &mut MessageQueue, game_session: &mut GameSession, is_animating: bool) -> Result<(), String> {
+  let previous_validation = game_session.validation();
+  handle_playfield_input(message_queue, game_session)?;
 
-  match validate_map(&playfield_state.map)? {
+  if is_animating || game_session.validation() != previous_validation { return Ok(()) }
+
+  match game_session.validation() {
     MapValidation::Valid => {},
     MapValidation::Won => message_queue.post(Message::RequestScene(Scenes::AddHighScore)),
     MapValidation::Lost => message_queue.post(Message::RequestScene(Scenes::MainMenu))
@@ -24,58 +26,64 @@ pub fn update_playfield(message_queue: &mut MessageQueue, playfield_state: &mut
 mod testing {
   use crate::{
     MessageQueue,
-    PlayfieldState,
+    GameSession,
+    Map,
     Message,
     Input,
-    Scenes
+    Scenes,
+    GameEvent
   };
 
   use super::update_playfield;
 
   #[test]
   fn valid_movement() {
-    let mut playfield_state = PlayfieldState::new();
-    playfield_state.map.goal_location.set_array_index(4, &playfield_state.map.size);
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
+    let mut map = Map::new();
+    map.goal_location.set_array_index(4, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     let mut message_queue = MessageQueue::new();
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    assert_eq!(playfield_state.map.player_location.array_index(), 0);
+    assert_eq!(game_session.map().player_location.array_index(), 0);
 
-    match update_playfield(&mut message_queue, &mut playfield_state) {
-      Ok(_) => assert_eq!(playfield_state.map.player_location.array_index(), 1),
+    match update_playfield(&mut message_queue, &mut game_session, false) {
+      Ok(_) => assert_eq!(game_session.map().player_location.array_index(), 1),
       Err(error) => panic!("Unexpected error: {}", error)
     }
   }
 
   #[test]
   fn valid_interaction() {
-    let mut playfield_state = PlayfieldState::new();
-    playfield_state.map.goal_location.set_array_index(4, &playfield_state.map.size);
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
-    playfield_state.is_interacting = true;
+    let mut map = Map::new();
+    map.goal_location.set_array_index(4, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     let mut message_queue = MessageQueue::new();
+    message_queue.post(Message::PlayerInput(Input::Action));
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    assert_eq!(playfield_state.map.player_location.array_index(), 0);
-    assert_eq!(playfield_state.map.is_marked[1], false);
+    assert_eq!(game_session.map().player_location.array_index(), 0);
+    assert_eq!(game_session.map().is_marked[1], false);
     
-    match update_playfield(&mut message_queue, &mut playfield_state) {
+    match update_playfield(&mut message_queue, &mut game_session, false) {
       Ok(_) => {
-        assert_eq!(playfield_state.map.player_location.array_index(), 0);
-        assert_eq!(playfield_state.map.is_marked[1], true);
+        assert_eq!(game_session.map().player_location.array_index(), 0);
+        assert_eq!(game_session.map().is_marked[1], true);
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -84,28 +92,39 @@ mod testing {
 
   #[test]
   fn player_wins() {
-    let mut playfield_state = PlayfieldState::new();
-    playfield_state.map.goal_location.set_array_index(1, &playfield_state.map.size);
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
+    let mut map = Map::new();
+    map.goal_location.set_array_index(1, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     let mut message_queue = MessageQueue::new();
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    assert_eq!(playfield_state.map.player_location.array_index(), 0);
-    assert_eq!(playfield_state.map.goal_location.array_index(), 1);
+    assert_eq!(game_session.map().player_location.array_index(), 0);
+    assert_eq!(game_session.map().goal_location.array_index(), 1);
     
-    match update_playfield(&mut message_queue, &mut playfield_state) {
+    match update_playfield(&mut message_queue, &mut game_session, false) {
       Ok(_) => {
-        assert_eq!(playfield_state.map.player_location.array_index(), 1);
+        assert_eq!(game_session.map().player_location.array_index(), 1);
+
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![
+          Message::GameEvent(GameEvent::PlayerMoved(0, 1)),
+          Message::GameEvent(GameEvent::TileRevealed(1)),
+          Message::GameEvent(GameEvent::GoalReached(1))
+        ]);
+
+        message_queue.swap_buffers();
+        update_playfield(&mut message_queue, &mut game_session, false).unwrap();
 
         message_queue.swap_buffers();
-        assert_eq!(message_queue.messages().len(), 1);
-        assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::AddHighScore));
+        assert_eq!(message_queue.messages(), &vec![Message::RequestScene(Scenes::AddHighScore)]);
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
@@ -114,30 +133,46 @@ mod testing {
 
   #[test]
   fn player_loses() {
-    let mut playfield_state = PlayfieldState::new();
-    playfield_state.map.goal_location.set_array_index(4, &playfield_state.map.size);
-    playfield_state.map.hint = vec![0; 16];
-    playfield_state.map.is_snake = vec![false; 16];
-    playfield_state.map.is_marked = vec![false; 16];
-    playfield_state.map.is_explored = vec![false; 16];
-    playfield_state.map.is_path = vec![false; 16];
+    let mut map = Map::new();
+    map.goal_location.set_array_index(4, &map.size);
+    map.hint = vec![0; 16];
+    map.is_snake = vec![false; 16];
+    map.is_marked = vec![false; 16];
+    map.is_explored = vec![false; 16];
+    map.is_path = vec![false; 16];
 
-    playfield_state.map.is_snake[1] = true;
+    map.is_snake[1] = true;
+
+    let mut game_session = GameSession::from_map(map, 0).unwrap();
 
     let mut message_queue = MessageQueue::new();
     message_queue.post(Message::PlayerInput(Input::Right));
     message_queue.swap_buffers();
 
-    assert_eq!(playfield_state.map.player_location.array_index(), 0);
-    assert_eq!(playfield_state.map.is_snake[1], true);
+    assert_eq!(game_session.map().player_location.array_index(), 0);
+    assert_eq!(game_session.map().is_snake[1], true);
     
-    match update_playfield(&mut message_queue, &mut playfield_state) {
+    match update_playfield(&mut message_queue, &mut game_session, false) {
       Ok(_) => {
-        assert_eq!(playfield_state.map.player_location.array_index(), 1);
+        assert_eq!(game_session.map().player_location.array_index(), 1);
+
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![
+          Message::GameEvent(GameEvent::PlayerMoved(0, 1)),
+          Message::GameEvent(GameEvent::TileRevealed(1)),
+          Message::GameEvent(GameEvent::SnakeBitten(1))
+        ]);
+
+        message_queue.swap_buffers();
+        update_playfield(&mut message_queue, &mut game_session, true).unwrap();
+
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages().len(), 0);
+
+        update_playfield(&mut message_queue, &mut game_session, false).unwrap();
 
         message_queue.swap_buffers();
-        assert_eq!(message_queue.messages().len(), 1);
-        assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::MainMenu));
+        assert_eq!(message_queue.messages(), &vec![Message::RequestScene(Scenes::MainMenu)]);
       },
 
       Err(error) => panic!("Unexpected error: {}", error)
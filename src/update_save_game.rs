@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use crate::{
+  MessageQueue,
+  Message,
+  Input,
+  Scenes,
+  PlayfieldState,
+  TextField,
+  serialize_map,
+  validate_saves_directory
+};
+
+pub fn update_save_game(message_queue: &mut MessageQueue, playfield_state: &PlayfieldState, text_field: &mut TextField, saves_directory_path: &Path) -> Result<(), String> {
+  let mut confirmed = false;
+  let mut cancelled = false;
+
+  for message in message_queue.messages() {
+    match message {
+      Message::PlayerInput(Input::Confirm) => confirmed = true,
+      Message::PlayerInput(Input::Cancel) => cancelled = true,
+      _ => {}
+    }
+  }
+
+  if confirmed && !text_field.text().trim().is_empty() {
+    validate_saves_directory(saves_directory_path)?;
+
+    let save_path = saves_directory_path.join(format!("{}.txt", text_field.text().trim()));
+    std::fs::write(save_path, serialize_map(&playfield_state.map)).map_err(| error | error.to_string())?;
+
+    text_field.clear();
+    message_queue.post(Message::RequestScene(Scenes::Pause));
+  } else if cancelled {
+    text_field.clear();
+    message_queue.post(Message::RequestScene(Scenes::Pause));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+  use std::path::Path;
+
+  use crate::{
+    MessageQueue,
+    Message,
+    Input,
+    Scenes,
+    PlayfieldState,
+    TextField
+  };
+
+  use super::update_save_game;
+
+  #[test]
+  fn saves_under_the_typed_name() {
+    let saves_directory_path = Path::new("./saves_under_the_typed_name_test_saves");
+    let mut message_queue = MessageQueue::new();
+    let playfield_state = PlayfieldState::new();
+    let mut text_field = TextField::new(32, "/\\");
+    text_field.insert("Save 1 é");
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_save_game(&mut message_queue, &playfield_state, &mut text_field, saves_directory_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![Message::RequestScene(Scenes::Pause)]);
+        assert_eq!(text_field.text(), "");
+        assert!(saves_directory_path.join("Save 1 é.txt").exists());
+
+        if let Err(error) = std::fs::remove_dir_all(saves_directory_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn ignores_confirm_without_a_name() {
+    let saves_directory_path = Path::new("./ignores_confirm_without_a_name_test_saves");
+    let mut message_queue = MessageQueue::new();
+    let playfield_state = PlayfieldState::new();
+    let mut text_field = TextField::new(32, "/\\");
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
+    message_queue.swap_buffers();
+
+    match update_save_game(&mut message_queue, &playfield_state, &mut text_field, saves_directory_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages().len(), 0);
+        assert!(!saves_directory_path.exists());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn cancel_returns_to_pause_menu() {
+    let saves_directory_path = Path::new("./cancel_returns_to_pause_menu_test_saves");
+    let mut message_queue = MessageQueue::new();
+    let playfield_state = PlayfieldState::new();
+    let mut text_field = TextField::new(32, "/\\");
+    text_field.insert("unsaved");
+
+    message_queue.post(Message::PlayerInput(Input::Cancel));
+    message_queue.swap_buffers();
+
+    match update_save_game(&mut message_queue, &playfield_state, &mut text_field, saves_directory_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages(), &vec![Message::RequestScene(Scenes::Pause)]);
+        assert_eq!(text_field.text(), "");
+        assert!(!saves_directory_path.exists());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
@@ -1,41 +1,354 @@
+use std::path::Path;
+
+use sdl2::keyboard::Keycode;
+
 use crate::{
   MessageQueue,
   Message,
-  Scenes
+  Input,
+  Scenes,
+  Resolution,
+  SettingsState,
+  cycle_option,
+  save_settings,
+  save_input_bindings,
+  input_bindings::BINDABLE_INPUTS,
+  settings_options::{
+    RESOLUTION_OPTIONS,
+    WINDOW_MODE_OPTIONS,
+    FPS_CAP_OPTIONS,
+    UI_SCALE_OPTIONS,
+    TEXT_COLOR_OPTIONS,
+    REPEAT_DELAY_OPTIONS,
+    REPEAT_INTERVAL_OPTIONS,
+    RESOLUTION_MENU_ITEM,
+    WINDOW_MODE_MENU_ITEM,
+    FPS_CAP_MENU_ITEM,
+    UI_SCALE_MENU_ITEM,
+    TEXT_COLOR_MENU_ITEM,
+    DEFAULT_WIDTH_MENU_ITEM,
+    DEFAULT_HEIGHT_MENU_ITEM,
+    DEFAULT_NUM_SNAKES_MENU_ITEM,
+    REPEAT_DELAY_MENU_ITEM,
+    REPEAT_INTERVAL_MENU_ITEM,
+    LETTERBOX_MENU_ITEM,
+    FIRST_BINDING_MENU_ITEM,
+    BACK_MENU_ITEM,
+    NUM_SETTINGS_MENU_ITEMS
+  },
+  new_game_options::{
+    MAP_SIZE_OPTIONS,
+    NUM_SNAKES_OPTIONS
+  }
 };
 
-pub fn update_settings(message_queue: &mut MessageQueue) {
-  let mut cancelled = false;
+pub fn update_settings(message_queue: &mut MessageQueue, settings_state: &mut SettingsState, settings_file_path: &Path, bindings_file_path: &Path) -> Result<(), String> {
+  let mut changed = false;
+  let mut exited = false;
 
   for message in message_queue.messages() {
-    if let Message::PlayerInput(input) = message { match input {
-      _ => cancelled = true
-    }}
+    match message {
+      Message::KeyCaptured(Keycode::Escape) if settings_state.is_capturing_key => {
+        settings_state.is_capturing_key = false;
+        settings_state.status = None;
+      },
+
+      Message::KeyCaptured(keycode) if settings_state.is_capturing_key => {
+        settings_state.is_capturing_key = false;
+        let input = BINDABLE_INPUTS[settings_state.selected_menu_item_index - FIRST_BINDING_MENU_ITEM];
+
+        let result = if settings_state.input_bindings.keys_for(input).contains(keycode) {
+          settings_state.input_bindings.unbind(*keycode, input)
+        } else {
+          settings_state.input_bindings.bind(*keycode, input)
+        };
+
+        settings_state.status = result.err();
+      },
+
+      Message::PlayerInput(input) if !settings_state.is_capturing_key => {
+        settings_state.status = None;
+
+        match input {
+          Input::Up if settings_state.selected_menu_item_index > 0 => settings_state.selected_menu_item_index -= 1,
+          Input::Down if settings_state.selected_menu_item_index < NUM_SETTINGS_MENU_ITEMS - 1 => settings_state.selected_menu_item_index += 1,
+          Input::Left => changed |= adjust_setting(settings_state, false),
+          Input::Right => changed |= adjust_setting(settings_state, true),
+
+          Input::Confirm => match settings_state.selected_menu_item_index {
+            BACK_MENU_ITEM => exited = true,
+            index if index >= FIRST_BINDING_MENU_ITEM => settings_state.is_capturing_key = true,
+            _ => changed |= adjust_setting(settings_state, true)
+          },
+
+          Input::Cancel => exited = true,
+          _ => {}
+        }
+      },
+
+      _ => {}
+    }
   }
 
-  if cancelled { message_queue.post(Message::RequestScene(Scenes::MainMenu)) }
+  if changed { message_queue.post(Message::SettingsChanged(settings_state.settings)) }
+
+  if exited {
+    save_settings(settings_file_path, &settings_state.settings)?;
+    save_input_bindings(bindings_file_path, &settings_state.input_bindings)?;
+    settings_state.selected_menu_item_index = 0;
+    message_queue.post(Message::RequestScene(Scenes::MainMenu));
+  }
+
+  Ok(())
+}
+
+fn adjust_setting(settings_state: &mut SettingsState, forward: bool) -> bool {
+  let settings = &mut settings_state.settings;
+
+  match settings_state.selected_menu_item_index {
+    RESOLUTION_MENU_ITEM => {
+      let (width, height) = cycle_option(&RESOLUTION_OPTIONS, (settings.resolution.width(), settings.resolution.height()), forward);
+      settings.resolution = Resolution::new(width, height);
+    },
+
+    WINDOW_MODE_MENU_ITEM => settings.window_mode = cycle_option(&WINDOW_MODE_OPTIONS, settings.window_mode, forward),
+
+    FPS_CAP_MENU_ITEM => settings.fps_cap = cycle_option(&FPS_CAP_OPTIONS, settings.fps_cap, forward),
+    UI_SCALE_MENU_ITEM => settings.ui_scale_percent = cycle_option(&UI_SCALE_OPTIONS, settings.ui_scale_percent, forward),
+    TEXT_COLOR_MENU_ITEM => settings.text_color = cycle_option(&TEXT_COLOR_OPTIONS, settings.text_color, forward),
+    DEFAULT_WIDTH_MENU_ITEM => settings.default_width = cycle_option(&MAP_SIZE_OPTIONS, settings.default_width, forward),
+    DEFAULT_HEIGHT_MENU_ITEM => settings.default_height = cycle_option(&MAP_SIZE_OPTIONS, settings.default_height, forward),
+    DEFAULT_NUM_SNAKES_MENU_ITEM => settings.default_num_snakes = cycle_option(&NUM_SNAKES_OPTIONS, settings.default_num_snakes, forward),
+    REPEAT_DELAY_MENU_ITEM => settings.repeat_delay_ms = cycle_option(&REPEAT_DELAY_OPTIONS, settings.repeat_delay_ms, forward),
+    REPEAT_INTERVAL_MENU_ITEM => settings.repeat_interval_ms = cycle_option(&REPEAT_INTERVAL_OPTIONS, settings.repeat_interval_ms, forward),
+    LETTERBOX_MENU_ITEM => settings.letterbox = !settings.letterbox,
+    _ => return false
+  }
+
+  true
 }
 
 #[cfg(test)]
 mod testing {
+  use std::path::Path;
+
+  use sdl2::keyboard::Keycode;
+
   use crate::{
     MessageQueue,
     Message,
-    Scenes
+    Input,
+    Scenes,
+    Settings,
+    SettingsState,
+    WindowMode,
+    InputBindings,
+    load_settings,
+    load_input_bindings
   };
 
   use super::update_settings;
-  
+
+  #[test]
+  fn navigates_without_changing_settings() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+
+    message_queue.post(Message::PlayerInput(Input::Down));
+    message_queue.post(Message::PlayerInput(Input::Down));
+    message_queue.post(Message::PlayerInput(Input::Up));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./navigates_without_changing_settings_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(settings_state.selected_menu_item_index, 1);
+        assert_eq!(settings_state.settings, Settings::new());
+        assert_eq!(message_queue.messages().len(), 0);
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn adjusts_selected_setting() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+
+    message_queue.post(Message::PlayerInput(Input::Down));
+    message_queue.post(Message::PlayerInput(Input::Right));
+    message_queue.post(Message::PlayerInput(Input::Down));
+    message_queue.post(Message::PlayerInput(Input::Left));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./adjusts_selected_setting_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(settings_state.settings.window_mode, WindowMode::Borderless);
+        assert_eq!(settings_state.settings.fps_cap, 30);
+        assert_eq!(message_queue.messages().len(), 1);
+        assert_eq!(message_queue.messages()[0], Message::SettingsChanged(settings_state.settings));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn saves_and_exits_to_main_menu_on_cancel() {
+    let settings_file_path = Path::new("./saves_and_exits_to_main_menu_on_cancel_test_settings.txt");
+    let bindings_file_path = Path::new("./saves_and_exits_to_main_menu_on_cancel_test_bindings.txt");
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.settings.default_width = 32;
+    settings_state.input_bindings.bind(Keycode::Kp8, Input::Up).unwrap();
+
+    message_queue.post(Message::PlayerInput(Input::Cancel));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, settings_file_path, bindings_file_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(message_queue.messages().len(), 1);
+        assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::MainMenu));
+
+        match load_settings(settings_file_path) {
+          Ok(settings) => assert_eq!(settings.default_width, 32),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        match load_input_bindings(bindings_file_path) {
+          Ok(input_bindings) => assert_eq!(input_bindings.input_for(Keycode::Kp8), Some(Input::Up)),
+          Err(error) => panic!("Unexpected error: {}", error)
+        }
+
+        if let Err(error) = std::fs::remove_file(settings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+
+        if let Err(error) = std::fs::remove_file(bindings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
   #[test]
-  fn exits_to_main_menu_on_input() {
+  fn back_item_exits_to_main_menu() {
+    let settings_file_path = Path::new("./back_item_exits_to_main_menu_test_settings.txt");
+    let bindings_file_path = Path::new("./back_item_exits_to_main_menu_test_bindings.txt");
     let mut message_queue = MessageQueue::new();
-    message_queue.post(Message::PlayerInput(crate::input::Input::Action));
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.selected_menu_item_index = 19;
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
-    
-    update_settings(&mut message_queue);
+
+    match update_settings(&mut message_queue, &mut settings_state, settings_file_path, bindings_file_path) {
+      Ok(()) => {
+        message_queue.swap_buffers();
+        assert_eq!(settings_state.selected_menu_item_index, 0);
+        assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::MainMenu));
+
+        if let Err(error) = std::fs::remove_file(settings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+
+        if let Err(error) = std::fs::remove_file(bindings_file_path) {
+          panic!("Unexpected error: {}", error);
+        }
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn captures_a_new_binding() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.selected_menu_item_index = 17;
+
+    message_queue.post(Message::PlayerInput(Input::Confirm));
     message_queue.swap_buffers();
 
-    assert_eq!(message_queue.messages().len(), 1);
-    assert_eq!(message_queue.messages()[0], Message::RequestScene(Scenes::MainMenu));
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./unused_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => assert_eq!(settings_state.is_capturing_key, true),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+
+    message_queue.post(Message::KeyCaptured(Keycode::E));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./unused_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => {
+        assert_eq!(settings_state.is_capturing_key, false);
+        assert_eq!(settings_state.status, None);
+        assert_eq!(settings_state.input_bindings.input_for(Keycode::E), Some(Input::Action));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn reports_binding_conflicts() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.selected_menu_item_index = 17;
+    settings_state.is_capturing_key = true;
+
+    message_queue.post(Message::KeyCaptured(Keycode::Return));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./unused_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => {
+        assert_eq!(settings_state.is_capturing_key, false);
+        assert_eq!(settings_state.status, Some("Return is already bound to Confirm".to_string()));
+        assert_eq!(settings_state.input_bindings.input_for(Keycode::Return), Some(Input::Confirm));
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn escape_cancels_capturing() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.selected_menu_item_index = 17;
+    settings_state.is_capturing_key = true;
+
+    message_queue.post(Message::KeyCaptured(Keycode::Escape));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./unused_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => {
+        assert!(!settings_state.is_capturing_key);
+        assert_eq!(settings_state.status, None);
+        assert_eq!(settings_state.input_bindings, InputBindings::new());
+      },
+
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+
+  #[test]
+  fn capturing_a_bound_key_removes_it() {
+    let mut message_queue = MessageQueue::new();
+    let mut settings_state = SettingsState::new(Settings::new(), InputBindings::new());
+    settings_state.selected_menu_item_index = 11;
+    settings_state.is_capturing_key = true;
+
+    message_queue.post(Message::KeyCaptured(Keycode::W));
+    message_queue.swap_buffers();
+
+    match update_settings(&mut message_queue, &mut settings_state, Path::new("./unused_test_settings.txt"), Path::new("./unused_test_bindings.txt")) {
+      Ok(()) => assert_eq!(settings_state.input_bindings.keys_for(Input::Up), vec![Keycode::Up]),
+      Err(error) => panic!("Unexpected error: {}", error)
+    }
+  }
+}
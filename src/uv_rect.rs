@@ -0,0 +1,69 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct UvRect {
+  pub left: f32,
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32
+}
+
+impl UvRect {
+  pub fn full() -> Self {
+    Self {
+      left: 0.0,
+      top: 0.0,
+      right: 1.0,
+      bottom: 1.0
+    }
+  }
+
+  pub fn sub_rect(&self, x: u32, y: u32, width: u32, height: u32, full_width: u32, full_height: u32) -> Self {
+    let horizontal = | offset: u32 | self.left + (self.right - self.left) * offset as f32 / full_width.max(1) as f32;
+    let vertical = | offset: u32 | self.top + (self.bottom - self.top) * offset as f32 / full_height.max(1) as f32;
+
+    Self {
+      left: horizontal(x),
+      top: vertical(y),
+      right: horizontal(x + width),
+      bottom: vertical(y + height)
+    }
+  }
+
+  pub fn image_sub_rect(&self, x: u32, y: u32, width: u32, height: u32, full_width: u32, full_height: u32) -> Self {
+    self.sub_rect(x, full_height.saturating_sub(y + height), width, height, full_width, full_height)
+  }
+}
+
+#[cfg(test)]
+mod testing {
+  use super::UvRect;
+
+  #[test]
+  fn selects_a_horizontal_frame() {
+    assert_eq!(UvRect::full().sub_rect(0, 0, 32, 32, 128, 32), UvRect { left: 0.0, top: 0.0, right: 0.25, bottom: 1.0 });
+    assert_eq!(UvRect::full().sub_rect(96, 0, 32, 32, 128, 32), UvRect { left: 0.75, top: 0.0, right: 1.0, bottom: 1.0 });
+    assert_eq!(UvRect::full().sub_rect(0, 0, 32, 32, 32, 32), UvRect::full());
+  }
+
+  #[test]
+  fn selects_a_frame_inside_a_sub_rectangle() {
+    let strip = UvRect { left: 0.5, top: 0.25, right: 1.0, bottom: 0.5 };
+    assert_eq!(strip.sub_rect(32, 0, 32, 32, 64, 32), UvRect { left: 0.75, top: 0.25, right: 1.0, bottom: 0.5 });
+  }
+
+  #[test]
+  fn selects_a_row() {
+    let flipped = UvRect { left: 0.0, top: 1.0, right: 1.0, bottom: 0.5 };
+    assert_eq!(flipped.sub_rect(0, 16, 32, 16, 32, 32), UvRect { left: 0.0, top: 0.75, right: 1.0, bottom: 0.5 });
+  }
+
+  #[test]
+  fn measures_image_rows_from_the_top() {
+    let sheet = UvRect::full();
+    assert_eq!(sheet.image_sub_rect(32, 0, 32, 32, 64, 64), UvRect { left: 0.5, top: 0.5, right: 1.0, bottom: 1.0 });
+    assert_eq!(sheet.image_sub_rect(0, 32, 32, 32, 64, 64), UvRect { left: 0.0, top: 0.0, right: 0.5, bottom: 0.5 });
+
+    let atlas_entry = UvRect { left: 0.5, top: 0.0, right: 1.0, bottom: 0.5 };
+    assert_eq!(atlas_entry.image_sub_rect(0, 0, 32, 32, 32, 64), UvRect { left: 0.5, top: 0.25, right: 1.0, bottom: 0.5 });
+    assert_eq!(atlas_entry.image_sub_rect(0, 32, 32, 32, 32, 64), UvRect { left: 0.5, top: 0.0, right: 1.0, bottom: 0.25 });
+  }
+}
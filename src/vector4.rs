@@ -1,3 +1,4 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Vector4 {
   pub x: f32,
   pub y: f32,
@@ -46,6 +46,27 @@ impl VertexArray {
     }
   }
 
+  pub fn with_colors(vertex_buffer: &VertexBuffer, element_buffer: &ElementBuffer) -> Self {
+    let vertex_array = Self::new(vertex_buffer, element_buffer);
+    let stride = (8 * std::mem::size_of::<f32>()) as gl::types::GLint;
+
+    unsafe {
+      gl::BindVertexArray(vertex_array.id);
+      gl::BindBuffer(gl::ARRAY_BUFFER, vertex_buffer.id());
+
+      gl::VertexAttribPointer(0, 2, gl::FLOAT, gl::FALSE, stride, std::ptr::null());
+      gl::VertexAttribPointer(1, 2, gl::FLOAT, gl::FALSE, stride, (2 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid);
+
+      gl::EnableVertexAttribArray(2);
+      gl::VertexAttribPointer(2, 4, gl::FLOAT, gl::FALSE, stride, (4 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid);
+
+      gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+      gl::BindVertexArray(0);
+    }
+
+    vertex_array
+  }
+
   pub fn id(&self) -> gl::types::GLuint {
     self.id
   }
@@ -25,6 +25,21 @@ impl VertexBuffer {
     }
   }
 
+  pub fn upload(&self, vertex_data: &[f32]) {
+    unsafe {
+      gl::BindBuffer(gl::ARRAY_BUFFER, self.id);
+
+      gl::BufferData(
+        gl::ARRAY_BUFFER,
+        std::mem::size_of_val(vertex_data) as gl::types::GLsizeiptr,
+        vertex_data.as_ptr() as *const gl::types::GLvoid,
+        gl::STREAM_DRAW
+      );
+
+      gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+    }
+  }
+
   pub fn id(&self) -> gl::types::GLuint {
     self.id
   }
@@ -1,17 +1,13 @@
-use std::{
-  ffi::CString,
-  path::Path
-};
+use std::ffi::CString;
 
 pub struct VertexShader {
   id: gl::types::GLuint
 }
 
 impl VertexShader {
-  pub fn load(shader_path: &Path) -> Result<Self, String> {
+  pub fn compile(shader_source: &str, shader_name: &str) -> Result<Self, String> {
     let id = unsafe { gl::CreateShader(gl::VERTEX_SHADER) };
     
-    let shader_source = std::fs::read_to_string(shader_path).map_err(| error | error.to_string())?;
     let shader_source = CString::new(shader_source).map_err(| error | error.to_string())?;
   
     unsafe {
@@ -24,8 +20,7 @@ impl VertexShader {
 
     if compile_success == 0 {
       let mut error_string = "Failed to compile shader: ".to_owned();
-      let shader_path_string = shader_path.to_str().unwrap();
-      error_string += shader_path_string;
+      error_string += shader_name;
 
       let mut error_message_length: gl::types::GLint = 0;
       unsafe { gl::GetShaderiv(id, gl::INFO_LOG_LENGTH, &mut error_message_length); }
@@ -48,6 +43,7 @@ impl VertexShader {
 
       error_string += error_message_str.get(..(error_message_length - 2) as usize).unwrap();
 
+      unsafe { gl::DeleteShader(id); }
       return Err(error_string);
     }
     
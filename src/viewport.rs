@@ -0,0 +1,22 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Viewport {
+  pub x: i32,
+  pub y: i32,
+  pub width: i32,
+  pub height: i32
+}
+
+impl Viewport {
+  pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+    Self {
+      x,
+      y,
+      width,
+      height
+    }
+  }
+
+  pub fn contains(&self, x: f32, y: f32) -> bool {
+    x >= self.x as f32 && y >= self.y as f32 && x < (self.x + self.width) as f32 && y < (self.y + self.height) as f32
+  }
+}
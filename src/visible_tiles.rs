@@ -0,0 +1,67 @@
+use std::ops::Range;
+
+use crate::{
+  MapSize,
+  Vector2
+};
+
+pub fn visible_tiles(view_min: Vector2, view_max: Vector2, map_size: &MapSize, tile_width: usize, tile_height: usize) -> (Range<usize>, Range<usize>) {
+  let columns = visible_span(view_min.x, view_max.x, map_size.width(), tile_width as f32);
+  let rows = visible_span(view_min.y, view_max.y, map_size.height(), tile_height as f32);
+
+  (columns, rows)
+}
+
+fn visible_span(view_min: f32, view_max: f32, tile_count: usize, tile_size: f32) -> Range<usize> {
+  let board_start = -(tile_count as f32 * tile_size / 2.0) - tile_size / 2.0;
+
+  let first = ((view_min - board_start) / tile_size).floor().clamp(0.0, tile_count as f32) as usize;
+  let end = ((view_max - board_start) / tile_size).floor().clamp(-1.0, tile_count as f32 - 1.0) as i64 + 1;
+
+  first..(end.max(first as i64) as usize)
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::{
+    MapSize,
+    Vector2
+  };
+
+  use super::visible_tiles;
+
+  #[test]
+  fn covers_the_whole_board_when_zoomed_out() {
+    let map_size = MapSize::from(8, 8).unwrap();
+    let (columns, rows) = visible_tiles(Vector2 { x: -800.0, y: -450.0 }, Vector2 { x: 800.0, y: 450.0 }, &map_size, 32, 32);
+
+    assert_eq!(columns, 0..8);
+    assert_eq!(rows, 0..8);
+  }
+
+  #[test]
+  fn limits_large_boards_to_the_view() {
+    let map_size = MapSize::from(64, 64).unwrap();
+    let (columns, rows) = visible_tiles(Vector2 { x: -64.0, y: -48.0 }, Vector2 { x: 64.0, y: 48.0 }, &map_size, 32, 32);
+
+    assert_eq!(columns, 30..35);
+    assert_eq!(rows, 31..35);
+  }
+
+  #[test]
+  fn clamps_to_the_board_edges() {
+    let map_size = MapSize::from(16, 8).unwrap();
+    let (columns, rows) = visible_tiles(Vector2 { x: -400.0, y: 100.0 }, Vector2 { x: -200.0, y: 400.0 }, &map_size, 32, 32);
+
+    assert_eq!(columns, 0..3);
+    assert_eq!(rows, 7..8);
+  }
+
+  #[test]
+  fn is_empty_when_looking_away_from_the_board() {
+    let map_size = MapSize::from(8, 8).unwrap();
+    let (columns, _) = visible_tiles(Vector2 { x: 1000.0, y: 0.0 }, Vector2 { x: 1200.0, y: 10.0 }, &map_size, 32, 32);
+
+    assert_eq!(columns.is_empty(), true);
+  }
+}
@@ -0,0 +1,55 @@
+use crate::{
+  Coordinate,
+  MapSize
+};
+
+pub fn world_to_coordinate(world_x: i32, world_y: i32, map_size: &MapSize, tile_width: usize, tile_height: usize) -> Option<Coordinate> {
+  let left = -((map_size.width() * tile_width) as i32 / 2) - tile_width as i32 / 2;
+  let top = -((map_size.height() * tile_height) as i32 / 2) - tile_height as i32 / 2;
+
+  let x = (world_x - left).div_euclid(tile_width as i32);
+  let y = (world_y - top).div_euclid(tile_height as i32);
+
+  if x < 0 || y < 0 || x as usize >= map_size.width() || y as usize >= map_size.height() { return None }
+
+  Some(Coordinate::from(x as usize, y as usize, map_size))
+}
+
+#[cfg(test)]
+mod testing {
+  use crate::MapSize;
+  use super::world_to_coordinate;
+
+  #[test]
+  fn maps_tile_centers() {
+    let map_size = MapSize::from(8, 8).unwrap();
+
+    match world_to_coordinate(-128, -128, &map_size, 32, 32) {
+      Some(coordinate) => assert_eq!((coordinate.x(), coordinate.y()), (0, 0)),
+      None => panic!("Expected a coordinate")
+    }
+
+    match world_to_coordinate(96, 0, &map_size, 32, 32) {
+      Some(coordinate) => assert_eq!((coordinate.x(), coordinate.y()), (7, 4)),
+      None => panic!("Expected a coordinate")
+    }
+  }
+
+  #[test]
+  fn maps_tile_edges() {
+    let map_size = MapSize::from(8, 8).unwrap();
+
+    match world_to_coordinate(-144, -113, &map_size, 32, 32) {
+      Some(coordinate) => assert_eq!((coordinate.x(), coordinate.y()), (0, 0)),
+      None => panic!("Expected a coordinate")
+    }
+  }
+
+  #[test]
+  fn outside_the_board_is_none() {
+    let map_size = MapSize::from(8, 8).unwrap();
+
+    assert_eq!(world_to_coordinate(-145, 0, &map_size, 32, 32), None);
+    assert_eq!(world_to_coordinate(0, 112, &map_size, 32, 32), None);
+  }
+}